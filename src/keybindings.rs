@@ -0,0 +1,641 @@
+use std::{collections::BTreeMap, fs, io};
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::GameRunningSet;
+
+const KEYBINDINGS_PATH: &str = "assets/keybindings.ron";
+
+pub struct KeybindingsPlugin;
+
+impl Plugin for KeybindingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Keybindings>()
+            .add_event::<OnKeybindingChangeRequested>()
+            .add_event::<OnKeybindingChanged>()
+            .add_event::<OnKeybindingsResetRequested>()
+            .add_systems(Startup, load_keybindings_on_startup)
+            .add_systems(
+                Update,
+                (handle_keybinding_change_requests, handle_reset_requests)
+                    .in_set(GameRunningSet::HandleCommands),
+            );
+    }
+}
+
+/// Everything the user can do with a key or mouse button, the buttons are looked up in [Keybindings].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum InputAction {
+    MoveCameraForward,
+    MoveCameraBack,
+    MoveCameraLeft,
+    MoveCameraRight,
+    /// Held to move and rotate the camera.
+    ActivateCamera,
+    /// Places road points with the drawer, or removes road sections with the bulldozer.
+    UseDrawerTool,
+    CancelDrawing,
+    ToggleBulldozer,
+    /// Selects a component in the road editor's preview, or drags its edge to resize it.
+    EditPreview,
+    /// Shows the road editor's cross-section next to the preview, instead of it, or hides it.
+    CycleCrossSectionView,
+    ToggleHelp,
+    Quit,
+}
+
+impl InputAction {
+    pub const ALL: [InputAction; 12] = [
+        InputAction::MoveCameraForward,
+        InputAction::MoveCameraBack,
+        InputAction::MoveCameraLeft,
+        InputAction::MoveCameraRight,
+        InputAction::ActivateCamera,
+        InputAction::UseDrawerTool,
+        InputAction::CancelDrawing,
+        InputAction::ToggleBulldozer,
+        InputAction::EditPreview,
+        InputAction::CycleCrossSectionView,
+        InputAction::ToggleHelp,
+        InputAction::Quit,
+    ];
+
+    pub fn default_binding(&self) -> InputBinding {
+        match self {
+            InputAction::MoveCameraForward => InputBinding::key(KeyCode::KeyW),
+            InputAction::MoveCameraBack => InputBinding::key(KeyCode::KeyS),
+            InputAction::MoveCameraLeft => InputBinding::key(KeyCode::KeyA),
+            InputAction::MoveCameraRight => InputBinding::key(KeyCode::KeyD),
+            InputAction::ActivateCamera => InputBinding::mouse(MouseButton::Right),
+            InputAction::UseDrawerTool => InputBinding::mouse(MouseButton::Left),
+            InputAction::CancelDrawing => InputBinding::mouse(MouseButton::Right),
+            InputAction::ToggleBulldozer => InputBinding::key(KeyCode::KeyB),
+            InputAction::EditPreview => InputBinding::mouse(MouseButton::Left),
+            InputAction::CycleCrossSectionView => InputBinding::key(KeyCode::KeyX),
+            InputAction::ToggleHelp => InputBinding::key(KeyCode::F1),
+            InputAction::Quit => InputBinding::key(KeyCode::KeyQ).with_control(),
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            InputAction::MoveCameraForward => "Move the camera forward",
+            InputAction::MoveCameraBack => "Move the camera back",
+            InputAction::MoveCameraLeft => "Move the camera left",
+            InputAction::MoveCameraRight => "Move the camera right",
+            InputAction::ActivateCamera => "Hold to move or rotate the camera",
+            InputAction::UseDrawerTool => "Use the drawer tool",
+            InputAction::CancelDrawing => "Cancel the section being drawn",
+            InputAction::ToggleBulldozer => "Switch between drawing and the bulldozer",
+            InputAction::EditPreview => "Select or resize components in the preview",
+            InputAction::CycleCrossSectionView => "Switch how the cross-section is shown",
+            InputAction::ToggleHelp => "Show or hide the shortcuts",
+            InputAction::Quit => "Quit",
+        }
+    }
+
+    /// Actions that act on the point that is clicked in the world can only be bound to a mouse button.
+    pub fn accepts(&self, button: InputButton) -> bool {
+        match self {
+            InputAction::UseDrawerTool | InputAction::EditPreview => {
+                matches!(button, InputButton::Mouse(_))
+            }
+            _ => true,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputButton {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl InputButton {
+    pub fn label(&self) -> String {
+        match self {
+            InputButton::Key(key_code) => key_label(*key_code),
+            InputButton::Mouse(mouse_button) => mouse_button_label(*mouse_button),
+        }
+    }
+}
+
+/// A key or mouse button, optionally combined with holding Ctrl.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InputBinding {
+    pub button: InputButton,
+    pub with_control: bool,
+}
+
+impl InputBinding {
+    pub fn new(button: InputButton) -> Self {
+        Self {
+            button,
+            with_control: false,
+        }
+    }
+
+    pub fn key(key_code: KeyCode) -> Self {
+        Self::new(InputButton::Key(key_code))
+    }
+
+    pub fn mouse(mouse_button: MouseButton) -> Self {
+        Self::new(InputButton::Mouse(mouse_button))
+    }
+
+    pub fn with_control(mut self) -> Self {
+        self.with_control = true;
+        self
+    }
+
+    /// Returns a short description for in the UI, like "W", "Ctrl+Q" or "Right mouse".
+    pub fn label(&self) -> String {
+        match self.with_control {
+            true => format!("Ctrl+{}", self.button.label()),
+            false => self.button.label(),
+        }
+    }
+}
+
+/// The binding of every [InputAction], actions without a saved binding use their default.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Keybindings {
+    bindings: BTreeMap<InputAction, InputBinding>,
+}
+
+impl Keybindings {
+    pub fn binding(&self, action: InputAction) -> InputBinding {
+        self.bindings
+            .get(&action)
+            .copied()
+            .unwrap_or_else(|| action.default_binding())
+    }
+
+    pub fn label(&self, action: InputAction) -> String {
+        self.binding(action).label()
+    }
+
+    /// Returns true if the action is bound to the given mouse button, for actions that are read from mouse events.
+    pub fn is_mouse_button(&self, action: InputAction, mouse_button: MouseButton) -> bool {
+        self.binding(action).button == InputButton::Mouse(mouse_button)
+    }
+
+    /// Returns true if any action is bound to the given binding.
+    pub fn is_bound(&self, binding: InputBinding) -> bool {
+        InputAction::ALL
+            .iter()
+            .any(|action| self.binding(*action) == binding)
+    }
+
+    /// Returns the other actions that are bound to the given binding.
+    ///
+    /// Actions that share their default binding don't conflict, they're used in different game modes
+    /// or together on purpose, like the right mouse button for moving the camera and for cancelling drawing.
+    fn conflicting_actions(&self, action: InputAction, binding: InputBinding) -> Vec<InputAction> {
+        InputAction::ALL
+            .into_iter()
+            .filter(|other_action| {
+                *other_action != action
+                    && self.binding(*other_action) == binding
+                    && other_action.default_binding() != action.default_binding()
+            })
+            .collect()
+    }
+
+    /// Binds the action to the given binding, the binding is swapped with an action that already uses it.
+    ///
+    /// The change is rejected when the bindings can't be swapped.
+    fn change_binding(&mut self, action: InputAction, binding: InputBinding) -> KeybindingChange {
+        let conflicting_actions = self.conflicting_actions(action, binding);
+
+        let [conflicting_action] = conflicting_actions[..] else {
+            return match conflicting_actions.first() {
+                Some(conflicting_action) => KeybindingChange::Rejected(*conflicting_action),
+                None => {
+                    self.set_binding(action, binding);
+                    KeybindingChange::Changed
+                }
+            };
+        };
+
+        let previous_binding = self.binding(action);
+        let can_swap = conflicting_action.accepts(previous_binding.button)
+            && self
+                .conflicting_actions(conflicting_action, previous_binding)
+                .iter()
+                .all(|other_action| *other_action == action);
+
+        if !can_swap {
+            return KeybindingChange::Rejected(conflicting_action);
+        }
+
+        self.set_binding(action, binding);
+        self.set_binding(conflicting_action, previous_binding);
+
+        KeybindingChange::Swapped(conflicting_action)
+    }
+
+    fn set_binding(&mut self, action: InputAction, binding: InputBinding) {
+        if binding == action.default_binding() {
+            self.bindings.remove(&action);
+        } else {
+            self.bindings.insert(action, binding);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.bindings.clear();
+    }
+}
+
+/// Reads the state of the buttons that are bound to an [InputAction].
+#[derive(SystemParam)]
+pub struct ActionInput<'w> {
+    keybindings: Res<'w, Keybindings>,
+    keyboard_input: Res<'w, ButtonInput<KeyCode>>,
+    mouse_input: Res<'w, ButtonInput<MouseButton>>,
+}
+
+impl ActionInput<'_> {
+    pub fn pressed(&self, action: InputAction) -> bool {
+        binding_pressed(
+            self.keybindings.binding(action),
+            &self.keybindings,
+            &self.keyboard_input,
+            &self.mouse_input,
+        )
+    }
+
+    pub fn just_pressed(&self, action: InputAction) -> bool {
+        binding_just_pressed(
+            self.keybindings.binding(action),
+            &self.keybindings,
+            &self.keyboard_input,
+            &self.mouse_input,
+        )
+    }
+
+    pub fn just_released(&self, action: InputAction) -> bool {
+        match self.keybindings.binding(action).button {
+            InputButton::Key(key_code) => self.keyboard_input.just_released(key_code),
+            InputButton::Mouse(mouse_button) => self.mouse_input.just_released(mouse_button),
+        }
+    }
+}
+
+/// Changes the binding of an action and saves all keybindings.
+#[derive(Event)]
+pub struct OnKeybindingChangeRequested {
+    action: InputAction,
+    binding: InputBinding,
+}
+
+impl OnKeybindingChangeRequested {
+    pub fn new(action: InputAction, binding: InputBinding) -> Self {
+        Self { action, binding }
+    }
+}
+
+/// What happened to a requested binding, when it was already used by another action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeybindingChange {
+    Changed,
+    /// The other action got the previous binding of the changed action.
+    Swapped(InputAction),
+    /// The binding stays with the other action, since it can't use the previous binding of the changed action.
+    Rejected(InputAction),
+}
+
+/// Sent after a binding change was requested, also when the change was rejected.
+#[derive(Event)]
+pub struct OnKeybindingChanged {
+    action: InputAction,
+    binding: InputBinding,
+    change: KeybindingChange,
+}
+
+impl OnKeybindingChanged {
+    pub fn new(action: InputAction, binding: InputBinding, change: KeybindingChange) -> Self {
+        Self {
+            action,
+            binding,
+            change,
+        }
+    }
+
+    pub fn action(&self) -> InputAction {
+        self.action
+    }
+
+    pub fn binding(&self) -> InputBinding {
+        self.binding
+    }
+
+    pub fn change(&self) -> KeybindingChange {
+        self.change
+    }
+}
+
+/// Restores the default binding of every action.
+#[derive(Event)]
+pub struct OnKeybindingsResetRequested;
+
+pub fn control_is_pressed(keyboard_input: &ButtonInput<KeyCode>) -> bool {
+    keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+}
+
+fn binding_pressed(
+    binding: InputBinding,
+    keybindings: &Keybindings,
+    keyboard_input: &ButtonInput<KeyCode>,
+    mouse_input: &ButtonInput<MouseButton>,
+) -> bool {
+    control_matches(binding, keybindings, keyboard_input)
+        && match binding.button {
+            InputButton::Key(key_code) => keyboard_input.pressed(key_code),
+            InputButton::Mouse(mouse_button) => mouse_input.pressed(mouse_button),
+        }
+}
+
+fn binding_just_pressed(
+    binding: InputBinding,
+    keybindings: &Keybindings,
+    keyboard_input: &ButtonInput<KeyCode>,
+    mouse_input: &ButtonInput<MouseButton>,
+) -> bool {
+    control_matches(binding, keybindings, keyboard_input)
+        && match binding.button {
+            InputButton::Key(key_code) => keyboard_input.just_pressed(key_code),
+            InputButton::Mouse(mouse_button) => mouse_input.just_pressed(mouse_button),
+        }
+}
+
+/// Bindings without Ctrl also work while Ctrl is held, so moving the camera isn't interrupted by it.
+///
+/// That is, unless Ctrl with the same button is bound too, like Q for moving the camera and Ctrl+Q for quitting.
+fn control_matches(
+    binding: InputBinding,
+    keybindings: &Keybindings,
+    keyboard_input: &ButtonInput<KeyCode>,
+) -> bool {
+    match binding.with_control {
+        true => control_is_pressed(keyboard_input),
+        false => {
+            !control_is_pressed(keyboard_input) || !keybindings.is_bound(binding.with_control())
+        }
+    }
+}
+
+fn key_label(key_code: KeyCode) -> String {
+    let name = format!("{key_code:?}");
+
+    // KeyW -> W, Digit1 -> 1, the other names are readable as they are
+    ["Key", "Digit"]
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .map(str::to_string)
+        .unwrap_or(name)
+}
+
+fn mouse_button_label(mouse_button: MouseButton) -> String {
+    match mouse_button {
+        MouseButton::Left => "Left mouse".to_string(),
+        MouseButton::Right => "Right mouse".to_string(),
+        MouseButton::Middle => "Middle mouse".to_string(),
+        MouseButton::Back => "Back mouse".to_string(),
+        MouseButton::Forward => "Forward mouse".to_string(),
+        MouseButton::Other(index) => format!("Mouse {index}"),
+    }
+}
+
+fn load_keybindings_on_startup(mut keybindings: ResMut<Keybindings>) {
+    match load_keybindings() {
+        Ok(Some(loaded_keybindings)) => *keybindings = loaded_keybindings,
+        Ok(None) => (),
+        Err(error) => warn!("Failed to load keybindings: {error}"),
+    }
+}
+
+fn handle_keybinding_change_requests(
+    mut requests: EventReader<OnKeybindingChangeRequested>,
+    mut on_changed: EventWriter<OnKeybindingChanged>,
+    mut keybindings: ResMut<Keybindings>,
+) {
+    if requests.is_empty() {
+        return;
+    }
+
+    for request in requests.read() {
+        let change = keybindings.change_binding(request.action, request.binding);
+
+        on_changed.send(OnKeybindingChanged::new(
+            request.action,
+            request.binding,
+            change,
+        ));
+    }
+
+    save_keybindings_or_warn(&keybindings);
+}
+
+fn handle_reset_requests(
+    mut requests: EventReader<OnKeybindingsResetRequested>,
+    mut keybindings: ResMut<Keybindings>,
+) {
+    if requests.is_empty() {
+        return;
+    }
+
+    requests.clear();
+    keybindings.reset();
+
+    save_keybindings_or_warn(&keybindings);
+}
+
+fn save_keybindings_or_warn(keybindings: &Keybindings) {
+    if let Err(error) = save_keybindings(keybindings) {
+        warn!("Failed to save keybindings: {error}");
+    }
+}
+
+fn save_keybindings(keybindings: &Keybindings) -> Result<(), ron::Error> {
+    Ok(fs::write(
+        KEYBINDINGS_PATH,
+        serialize_keybindings(keybindings)?,
+    )?)
+}
+
+/// Returns the saved keybindings, or `None` if the defaults have never been changed.
+fn load_keybindings() -> Result<Option<Keybindings>, ron::Error> {
+    match fs::read_to_string(KEYBINDINGS_PATH) {
+        Ok(serialized_keybindings) => Ok(Some(deserialize_keybindings(&serialized_keybindings)?)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn serialize_keybindings(keybindings: &Keybindings) -> Result<String, ron::Error> {
+    ron::ser::to_string_pretty(keybindings, PrettyConfig::new().struct_names(true))
+}
+
+fn deserialize_keybindings(serialized_keybindings: &str) -> Result<Keybindings, ron::Error> {
+    Ok(ron::from_str::<Keybindings>(serialized_keybindings)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keybindings_survive_ron_round_trip() {
+        let mut keybindings = Keybindings::default();
+        keybindings.set_binding(
+            InputAction::MoveCameraForward,
+            InputBinding::key(KeyCode::KeyZ),
+        );
+        keybindings.set_binding(
+            InputAction::ActivateCamera,
+            InputBinding::mouse(MouseButton::Middle),
+        );
+
+        let serialized = serialize_keybindings(&keybindings).unwrap();
+
+        assert_eq!(deserialize_keybindings(&serialized).unwrap(), keybindings);
+    }
+
+    #[test]
+    fn actions_without_a_saved_binding_use_their_default() {
+        let keybindings = deserialize_keybindings(
+            "Keybindings(bindings: { MoveCameraLeft: InputBinding(button: Key(KeyQ), with_control: false) })",
+        )
+        .unwrap();
+
+        assert_eq!(
+            keybindings.binding(InputAction::MoveCameraLeft),
+            InputBinding::key(KeyCode::KeyQ)
+        );
+        assert_eq!(
+            keybindings.binding(InputAction::MoveCameraRight),
+            InputBinding::key(KeyCode::KeyD)
+        );
+    }
+
+    #[test]
+    fn control_bindings_need_control_to_be_held() {
+        let quit_binding = InputAction::Quit.default_binding();
+        let mouse_input = ButtonInput::<MouseButton>::default();
+        let mut keyboard_input = ButtonInput::<KeyCode>::default();
+
+        let keybindings = Keybindings::default();
+
+        keyboard_input.press(KeyCode::KeyQ);
+        assert!(!binding_just_pressed(
+            quit_binding,
+            &keybindings,
+            &keyboard_input,
+            &mouse_input
+        ));
+
+        keyboard_input.press(KeyCode::ControlRight);
+        assert!(binding_just_pressed(
+            quit_binding,
+            &keybindings,
+            &keyboard_input,
+            &mouse_input
+        ));
+    }
+
+    #[test]
+    fn bindings_without_control_dont_fire_with_a_bound_control_combination() {
+        let mut keybindings = Keybindings::default();
+        keybindings.change_binding(
+            InputAction::MoveCameraLeft,
+            InputBinding::key(KeyCode::KeyQ),
+        );
+
+        let move_left_binding = keybindings.binding(InputAction::MoveCameraLeft);
+        let mouse_input = ButtonInput::<MouseButton>::default();
+        let mut keyboard_input = ButtonInput::<KeyCode>::default();
+
+        keyboard_input.press(KeyCode::KeyQ);
+        assert!(binding_pressed(
+            move_left_binding,
+            &keybindings,
+            &keyboard_input,
+            &mouse_input
+        ));
+
+        // Ctrl+Q quits, so it doesn't move the camera too
+        keyboard_input.press(KeyCode::ControlLeft);
+        assert!(!binding_pressed(
+            move_left_binding,
+            &keybindings,
+            &keyboard_input,
+            &mouse_input
+        ));
+
+        // Ctrl+W isn't bound, so moving forward isn't interrupted by Ctrl
+        keyboard_input.press(KeyCode::KeyW);
+        assert!(binding_pressed(
+            keybindings.binding(InputAction::MoveCameraForward),
+            &keybindings,
+            &keyboard_input,
+            &mouse_input
+        ));
+    }
+
+    #[test]
+    fn bindings_that_are_in_use_are_swapped_or_rejected() {
+        let mut keybindings = Keybindings::default();
+
+        assert_eq!(
+            keybindings.change_binding(
+                InputAction::MoveCameraLeft,
+                InputBinding::key(KeyCode::KeyD)
+            ),
+            KeybindingChange::Swapped(InputAction::MoveCameraRight)
+        );
+        assert_eq!(
+            keybindings.binding(InputAction::MoveCameraRight),
+            InputBinding::key(KeyCode::KeyA)
+        );
+
+        // Keys can't be used for clicking in the world, so the binding stays with the drawer tool
+        assert_eq!(
+            keybindings.change_binding(
+                InputAction::ToggleBulldozer,
+                InputBinding::mouse(MouseButton::Left)
+            ),
+            KeybindingChange::Rejected(InputAction::UseDrawerTool)
+        );
+        assert_eq!(
+            keybindings.binding(InputAction::ToggleBulldozer),
+            InputBinding::key(KeyCode::KeyB)
+        );
+
+        // Actions that share their default binding keep sharing it
+        assert_eq!(
+            keybindings.change_binding(
+                InputAction::CancelDrawing,
+                InputBinding::mouse(MouseButton::Right)
+            ),
+            KeybindingChange::Changed
+        );
+    }
+
+    #[test]
+    fn bindings_have_readable_labels() {
+        assert_eq!(
+            InputAction::MoveCameraForward.default_binding().label(),
+            "W"
+        );
+        assert_eq!(InputAction::Quit.default_binding().label(), "Ctrl+Q");
+        assert_eq!(InputAction::ToggleHelp.default_binding().label(), "F1");
+        assert_eq!(
+            InputAction::ActivateCamera.default_binding().label(),
+            "Right mouse"
+        );
+    }
+}
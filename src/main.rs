@@ -1,7 +1,9 @@
 mod game_modes;
+mod keybindings;
 mod road;
 mod road_drawer;
 mod road_editor;
+mod settings;
 mod system_sets;
 mod ui;
 mod utility;
@@ -11,9 +13,11 @@ use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
 use game_modes::GameModesPlugin;
+use keybindings::KeybindingsPlugin;
 use road::RoadPlugin;
 use road_drawer::RoadDrawerPlugin;
 use road_editor::RoadEditorPlugin;
+use settings::SettingsPlugin;
 pub use system_sets::GameRunningSet;
 use system_sets::SystemSetPlugin;
 use ui::UiPlugin;
@@ -165,11 +169,17 @@ use world::WorldPlugin;
 fn main() {
     App::new()
         .add_plugins((
-            DefaultPlugins,
+            DefaultPlugins.set(WindowPlugin {
+                // Closing the window is handled by the exit protection, which asks about unsaved changes first
+                close_when_requested: false,
+                ..default()
+            }),
             RapierPhysicsPlugin::<NoUserData>::default(),
             // RapierDebugRenderPlugin::default(),
             UtilityPlugin,
             SystemSetPlugin,
+            KeybindingsPlugin,
+            SettingsPlugin,
             WorldPlugin,
             RoadPlugin,
             UiPlugin,
@@ -1,22 +1,31 @@
 pub mod active_road;
 pub mod load;
 pub mod persistance;
-mod road_builder;
+pub mod recovery;
+pub mod road_builder;
 pub mod road_component;
+pub mod road_component_group;
 pub mod road_data;
-mod road_marking;
+pub mod road_marking;
 pub mod road_node;
-mod road_preview;
+pub mod road_preview;
+pub mod road_reference_line;
 pub mod road_section;
+pub mod road_structure;
+pub mod road_validation;
 pub mod save;
 
 use active_road::ActiveRoadPlugin;
 use bevy::prelude::*;
 use load::LoadRoadPlugin;
+use recovery::RecoveryPlugin;
+use road_component_group::RoadComponentGroupPlugin;
 use road_marking::RoadMarkingPlugin;
 use road_node::RoadNodePlugin;
 use road_preview::RoadPreviewPlugin;
 use road_section::RoadSectionPlugin;
+use road_structure::RoadStructurePlugin;
+use road_validation::RoadValidationPlugin;
 use save::SaveRoadPlugin;
 
 pub struct RoadPlugin;
@@ -29,8 +38,12 @@ impl Plugin for RoadPlugin {
             ActiveRoadPlugin,
             RoadPreviewPlugin,
             RoadMarkingPlugin,
+            RoadStructurePlugin,
+            RoadComponentGroupPlugin,
             RoadSectionPlugin,
             RoadNodePlugin,
+            RoadValidationPlugin,
+            RecoveryPlugin,
         ));
     }
 }
@@ -2,14 +2,22 @@ pub mod active_road_events;
 pub mod changed_component_indices;
 
 use active_road_events::{
-    road_component_change::RoadComponentFieldChange, ActiveRoadEventsPlugin, OnActiveRoadSet,
+    road_component_change::RoadComponentFieldChange,
+    road_marking_change::RoadMarkingFieldChange,
+    road_structure_change::{RoadStructureChange, RoadStructureFieldChange},
+    ActiveRoadEventsPlugin, OnActiveRoadSet,
 };
 use bevy::{color::palettes::tailwind::*, prelude::*};
 use changed_component_indices::ChangedComponentIndices;
 
-use crate::{game_modes::GameMode, ui::list::list_events::list_reorder::ReorderIndices};
+use crate::{
+    game_modes::GameMode, ui::list::list_events::list_reorder::ReorderIndices, GameRunningSet,
+};
 
-use super::{road_component::RoadComponent, road_data::RoadData, road_marking::RoadMarking};
+use super::{
+    road_component::RoadComponent, road_data::RoadData, road_marking::RoadMarking,
+    road_reference_line::RoadReferenceLine, save::OnRoadSaved,
+};
 
 pub struct ActiveRoadPlugin;
 
@@ -17,7 +25,13 @@ impl Plugin for ActiveRoadPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((ActiveRoadEventsPlugin,))
             .add_systems(OnEnter(GameMode::RoadEditor), setup_example_road)
-            .add_systems(OnExit(GameMode::RoadEditor), despawn_active_road);
+            .add_systems(OnExit(GameMode::RoadEditor), despawn_active_road)
+            .add_systems(
+                Update,
+                mark_active_road_as_saved_on_road_saved
+                    .in_set(GameRunningSet::UpdateEntities)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
     }
 }
 
@@ -36,12 +50,7 @@ fn setup_example_road(mut commands: Commands, mut on_road_set: EventWriter<OnAct
 
     let road = RoadData::new(String::from("Example road"), road_components, road_markings);
 
-    let active_road = ActiveRoad {
-        road_data: road.clone(),
-        road_preview_entity: None,
-    };
-
-    commands.insert_resource(active_road);
+    commands.insert_resource(ActiveRoad::new(road.clone()));
 
     on_road_set.send(OnActiveRoadSet::new(road));
 }
@@ -54,17 +63,47 @@ fn despawn_active_road(mut commands: Commands, active_road: Res<ActiveRoad>) {
     commands.remove_resource::<ActiveRoad>();
 }
 
+fn mark_active_road_as_saved_on_road_saved(
+    mut on_saved: EventReader<OnRoadSaved>,
+    mut active_road: ResMut<ActiveRoad>,
+) {
+    for event in on_saved.read() {
+        active_road.mark_as_saved(event.road().clone());
+    }
+}
+
 #[derive(Resource)]
 pub struct ActiveRoad {
     road_data: RoadData,
+    /// The road data as it was last saved or loaded, used to check for unsaved changes.
+    saved_road_data: RoadData,
     road_preview_entity: Option<Entity>,
 }
 
 impl ActiveRoad {
+    pub fn new(road_data: RoadData) -> Self {
+        Self {
+            saved_road_data: road_data.clone(),
+            road_data,
+            road_preview_entity: None,
+        }
+    }
+
     pub fn road_data(&self) -> &RoadData {
         &self.road_data
     }
 
+    /// Returns true if the road data has changed since it was last saved or loaded.
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.road_data != self.saved_road_data
+    }
+
+    /// Marks the given road data as saved, this also applies its name to the active road.
+    pub fn mark_as_saved(&mut self, saved_road_data: RoadData) {
+        self.road_data.set_name(saved_road_data.name().to_string());
+        self.saved_road_data = saved_road_data;
+    }
+
     pub fn component_at_index(&self, index: usize) -> &RoadComponent {
         &self.road_data().components()[index]
     }
@@ -73,7 +112,19 @@ impl ActiveRoad {
         self.road_data.components().len()
     }
 
+    /// Replaces the road data without marking it as saved, used when restoring unsaved work.
+    pub fn recover_road_data(&mut self, road: RoadData) {
+        self.road_data = road;
+    }
+
+    /// Replaces the road data and marks it as saved, used when a road is loaded from file.
     pub fn set_road_data(&mut self, road: RoadData) {
+        self.saved_road_data = road.clone();
+        self.road_data = road;
+    }
+
+    /// Replaces the road data without marking it as saved, used when a change affects many components at once.
+    pub fn change_road_data(&mut self, road: RoadData) {
         self.road_data = road;
     }
 
@@ -86,6 +137,8 @@ impl ActiveRoad {
         self.road_data
             .components_mut()
             .swap(reorder.previous_index, reorder.new_index);
+        self.road_data
+            .swap_components_in_reference_line(reorder.previous_index, reorder.new_index);
         self.road_data.recalculate_road_component_positions();
     }
 
@@ -106,16 +159,87 @@ impl ActiveRoad {
             RoadComponentFieldChange::Width(width) => road_component.with_width(width),
             RoadComponentFieldChange::Height(height) => road_component.with_height(height),
             RoadComponentFieldChange::Color(color) => road_component.with_color(color),
+            RoadComponentFieldChange::MinWidth(min_width) => {
+                road_component.with_min_width(min_width)
+            }
+            RoadComponentFieldChange::MaxWidth(max_width) => {
+                road_component.with_max_width(max_width)
+            }
+            RoadComponentFieldChange::Flex(flex) => road_component.with_flex(flex),
+            RoadComponentFieldChange::Track(track) => road_component.with_track(track),
         };
 
         self.set_road_component(component_index, new_component.clone());
     }
 
+    pub fn set_target_width(&mut self, target_width: Option<f32>) {
+        self.road_data.set_target_width(target_width);
+    }
+
+    pub fn set_reference_line(&mut self, reference_line: RoadReferenceLine) {
+        self.road_data.set_reference_line(reference_line);
+    }
+
     pub fn delete_road_component(&mut self, component_index: usize) {
         self.road_data.components_mut().remove(component_index);
+        self.road_data.remove_component_from_groups(component_index);
+        self.road_data
+            .remove_component_from_reference_line(component_index);
         self.road_data.recalculate_road_component_positions();
     }
 
+    pub fn change_road_marking_at_index(
+        &mut self,
+        marking_index: usize,
+        field_to_change: RoadMarkingFieldChange,
+    ) {
+        let road_marking = &mut self.road_data.markings_mut()[marking_index];
+
+        *road_marking = match field_to_change {
+            RoadMarkingFieldChange::XPosition(x_position) => {
+                road_marking.with_x_position(x_position)
+            }
+            RoadMarkingFieldChange::SegmentWidth(segment_width) => {
+                road_marking.with_segment_width(segment_width)
+            }
+            RoadMarkingFieldChange::Color(color) => road_marking.with_color(color),
+        };
+    }
+
+    pub fn change_road_structures(&mut self, change: &RoadStructureChange) {
+        let structures = self.road_data.structures_mut();
+
+        match change {
+            RoadStructureChange::Add(structure) => structures.push(structure.clone()),
+            RoadStructureChange::Field {
+                road_structure_index,
+                field,
+            } => {
+                let structure = &mut structures[*road_structure_index];
+
+                *structure = match *field {
+                    RoadStructureFieldChange::XPosition(x_position) => {
+                        structure.clone().with_x_position(x_position)
+                    }
+                    RoadStructureFieldChange::Height(height) => {
+                        structure.clone().with_height(height)
+                    }
+                    RoadStructureFieldChange::Color(color) => structure.clone().with_color(color),
+                    RoadStructureFieldChange::HasCollider(has_collider) => {
+                        structure.clone().with_collider(has_collider)
+                    }
+                };
+            }
+            RoadStructureChange::Delete(road_structure_index) => {
+                structures.remove(*road_structure_index);
+            }
+        }
+    }
+
+    pub fn road_preview_entity(&self) -> Option<Entity> {
+        self.road_preview_entity
+    }
+
     pub fn set_road_preview_entity(&mut self, road_preview_entity: Option<Entity>) {
         self.road_preview_entity = road_preview_entity;
     }
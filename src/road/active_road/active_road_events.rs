@@ -1,13 +1,23 @@
 pub mod new_road_component;
 pub mod road_component_change;
 pub mod road_component_deletion;
+pub mod road_component_group_change;
 pub mod road_component_reorder;
+pub mod road_marking_change;
+pub mod road_reference_line_change;
+pub mod road_structure_change;
+pub mod road_target_width_change;
 
 use bevy::prelude::*;
 use new_road_component::NewRoadComponentPlugin;
 use road_component_change::RoadComponentChangePlugin;
 use road_component_deletion::RoadComponentDeletionPlugin;
+use road_component_group_change::RoadComponentGroupChangePlugin;
 use road_component_reorder::RoadComponentReorderPlugin;
+use road_marking_change::RoadMarkingChangePlugin;
+use road_reference_line_change::RoadReferenceLineChangePlugin;
+use road_structure_change::RoadStructureChangePlugin;
+use road_target_width_change::RoadTargetWidthChangePlugin;
 
 use crate::road::road_data::RoadData;
 
@@ -20,6 +30,11 @@ impl Plugin for ActiveRoadEventsPlugin {
             RoadComponentChangePlugin,
             RoadComponentReorderPlugin,
             RoadComponentDeletionPlugin,
+            RoadComponentGroupChangePlugin,
+            RoadMarkingChangePlugin,
+            RoadTargetWidthChangePlugin,
+            RoadReferenceLineChangePlugin,
+            RoadStructureChangePlugin,
         ));
     }
 }
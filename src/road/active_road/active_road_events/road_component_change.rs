@@ -4,6 +4,7 @@ use crate::{
     game_modes::GameMode,
     road::{
         active_road::{changed_component_indices::ChangedComponentIndices, ActiveRoad},
+        road_component::rail_track::RailTrack,
         road_data::RoadData,
     },
     utility::changed_value::ChangedValue,
@@ -31,6 +32,10 @@ pub enum RoadComponentFieldChange {
     Width(f32),
     Height(f32),
     Color(Color),
+    MinWidth(Option<f32>),
+    MaxWidth(Option<f32>),
+    Flex(f32),
+    Track(Option<RailTrack>),
 }
 
 #[derive(Clone, PartialEq, Debug)]
@@ -60,6 +60,7 @@ impl OnRoadComponentDeleted {
         }
     }
 
+    #[expect(unused)]
     pub fn new_road_data(&self) -> &RoadData {
         &self.changed_road_data.new_value
     }
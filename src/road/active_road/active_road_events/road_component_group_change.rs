@@ -0,0 +1,697 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{
+        active_road::ActiveRoad,
+        road_component::RoadComponent,
+        road_component_group::{RoadComponentGroup, RoadComponentGroupPreset},
+        road_data::RoadData,
+        road_reference_line::RoadReferenceLine,
+    },
+    ui::list::reorder_button::ReorderDirection,
+    utility::changed_value::ChangedValue,
+    GameRunningSet,
+};
+
+pub struct RoadComponentGroupChangePlugin;
+
+impl Plugin for RoadComponentGroupChangePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnRoadComponentGroupChangeRequested>()
+            .add_event::<OnRoadComponentGroupChanged>()
+            .add_systems(
+                Update,
+                handle_component_group_change_requests
+                    .in_set(GameRunningSet::HandleCommands)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+/// A change to the component groups of the active road.
+///
+/// The components of a group are moved, copied and removed as a unit, together with the markings on them.
+#[derive(Clone, PartialEq, Debug)]
+pub enum RoadComponentGroupChange {
+    /// Groups the component at the given index, or its group, with the component or group right after it.
+    GroupWithNext(usize),
+    Rename {
+        group_index: usize,
+        name: String,
+    },
+    /// Removes the group, but keeps its components.
+    Ungroup(usize),
+    /// Moves the group past the neighbouring component or group.
+    Move {
+        group_index: usize,
+        direction: ReorderDirection,
+    },
+    /// Adds a copy of the group right after it.
+    Duplicate(usize),
+    /// Reverses the order of the group's components, and mirrors the markings on them.
+    Mirror(usize),
+    /// Removes the group with its components.
+    Delete(usize),
+    /// Adds the preset as a new group at the end of the road.
+    InsertPreset(RoadComponentGroupPreset),
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadComponentGroupChangeRequested {
+    pub change: RoadComponentGroupChange,
+}
+
+impl OnRoadComponentGroupChangeRequested {
+    pub fn new(change: RoadComponentGroupChange) -> Self {
+        Self { change }
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadComponentGroupChanged {
+    pub change: RoadComponentGroupChange,
+    pub changed_road_data: ChangedValue<RoadData>,
+}
+
+impl OnRoadComponentGroupChanged {
+    pub fn new(
+        change: RoadComponentGroupChange,
+        changed_road_data: ChangedValue<RoadData>,
+    ) -> Self {
+        Self {
+            change,
+            changed_road_data,
+        }
+    }
+
+    pub fn new_road_data(&self) -> &RoadData {
+        &self.changed_road_data.new_value
+    }
+
+    /// Returns true if components were added, removed or moved, instead of only the groups themselves changing.
+    pub fn changed_components(&self) -> bool {
+        self.changed_road_data.previous_value.components()
+            != self.changed_road_data.new_value.components()
+    }
+}
+
+fn handle_component_group_change_requests(
+    mut requests: EventReader<OnRoadComponentGroupChangeRequested>,
+    mut on_changed: EventWriter<OnRoadComponentGroupChanged>,
+    mut active_road: ResMut<ActiveRoad>,
+) {
+    for request in requests.read() {
+        let previous_road_data = active_road.road_data().clone();
+
+        let Some(new_road_data) =
+            apply_component_group_change(&previous_road_data, &request.change)
+        else {
+            continue;
+        };
+
+        active_road.change_road_data(new_road_data.clone());
+
+        on_changed.send(OnRoadComponentGroupChanged::new(
+            request.change.clone(),
+            ChangedValue::new(previous_road_data, new_road_data),
+        ));
+    }
+}
+
+/// Returns the road data with the given change applied, or None if the change isn't possible.
+pub fn apply_component_group_change(
+    road_data: &RoadData,
+    change: &RoadComponentGroupChange,
+) -> Option<RoadData> {
+    let mut new_road_data = road_data.clone();
+
+    match change {
+        RoadComponentGroupChange::GroupWithNext(component_index) => {
+            let first_unit = road_data.component_unit_range(*component_index);
+
+            if first_unit.end >= road_data.component_count() {
+                return None;
+            }
+
+            let second_unit = road_data.component_unit_range(first_unit.end);
+
+            // The merged group keeps the name of the group it extends
+            let name = road_data
+                .component_group_index_of(first_unit.start)
+                .or(road_data.component_group_index_of(second_unit.start))
+                .map(|group_index| road_data.component_groups()[group_index].name.clone())
+                .unwrap_or(RoadComponentGroup::default().name);
+
+            let component_groups = new_road_data.component_groups_mut();
+
+            component_groups.retain(|group| {
+                !group.contains(first_unit.start) && !group.contains(second_unit.start)
+            });
+            component_groups.push(RoadComponentGroup::new(
+                name,
+                first_unit.start,
+                second_unit.end - first_unit.start,
+            ));
+        }
+        RoadComponentGroupChange::Rename { group_index, name } => {
+            new_road_data
+                .component_groups_mut()
+                .get_mut(*group_index)?
+                .name = name.clone();
+        }
+        RoadComponentGroupChange::Ungroup(group_index) => {
+            road_data.component_groups().get(*group_index)?;
+
+            new_road_data.component_groups_mut().remove(*group_index);
+        }
+        RoadComponentGroupChange::Move {
+            group_index,
+            direction,
+        } => {
+            let group_range = road_data
+                .component_groups()
+                .get(*group_index)?
+                .component_range();
+
+            // The group swaps places with the neighbouring unit
+            let (left_unit, right_unit) = match direction {
+                ReorderDirection::Previous => {
+                    if group_range.start == 0 {
+                        return None;
+                    }
+
+                    (
+                        road_data.component_unit_range(group_range.start - 1),
+                        group_range,
+                    )
+                }
+                ReorderDirection::Next => {
+                    if group_range.end >= road_data.component_count() {
+                        return None;
+                    }
+
+                    let next_unit = road_data.component_unit_range(group_range.end);
+                    (group_range, next_unit)
+                }
+            };
+
+            let targets = collect_component_targets(road_data, |index| {
+                if left_unit.contains(&index) {
+                    vec![ComponentTarget::moved(index + right_unit.len())]
+                } else if right_unit.contains(&index) {
+                    vec![ComponentTarget::moved(index - left_unit.len())]
+                } else {
+                    vec![ComponentTarget::moved(index)]
+                }
+            });
+
+            rearrange_components(road_data, &mut new_road_data, &targets);
+
+            for group in new_road_data.component_groups_mut() {
+                group.first_index = targets[group.first_index][0].index;
+            }
+        }
+        RoadComponentGroupChange::Duplicate(group_index) => {
+            let group = road_data.component_groups().get(*group_index)?.clone();
+            let group_range = group.component_range();
+
+            let targets = collect_component_targets(road_data, |index| {
+                if index < group_range.start {
+                    vec![ComponentTarget::moved(index)]
+                } else if group_range.contains(&index) {
+                    vec![
+                        ComponentTarget::moved(index),
+                        ComponentTarget::moved(index + group_range.len()),
+                    ]
+                } else {
+                    vec![ComponentTarget::moved(index + group_range.len())]
+                }
+            });
+
+            rearrange_components(road_data, &mut new_road_data, &targets);
+
+            let component_groups = new_road_data.component_groups_mut();
+
+            for later_group in component_groups
+                .iter_mut()
+                .filter(|later_group| later_group.first_index >= group_range.end)
+            {
+                later_group.first_index += group_range.len();
+            }
+
+            component_groups.push(RoadComponentGroup {
+                name: format!("{} copy", group.name),
+                first_index: group_range.end,
+                ..group
+            });
+        }
+        RoadComponentGroupChange::Mirror(group_index) => {
+            let group_range = road_data
+                .component_groups()
+                .get(*group_index)?
+                .component_range();
+
+            let targets =
+                collect_component_targets(road_data, |index| match group_range.contains(&index) {
+                    true => vec![ComponentTarget::mirrored(
+                        group_range.start + group_range.end - 1 - index,
+                    )],
+                    false => vec![ComponentTarget::moved(index)],
+                });
+
+            rearrange_components(road_data, &mut new_road_data, &targets);
+        }
+        RoadComponentGroupChange::Delete(group_index) => {
+            let group_range = road_data
+                .component_groups()
+                .get(*group_index)?
+                .component_range();
+
+            let targets = collect_component_targets(road_data, |index| {
+                if group_range.contains(&index) {
+                    Vec::new()
+                } else if index >= group_range.end {
+                    vec![ComponentTarget::moved(index - group_range.len())]
+                } else {
+                    vec![ComponentTarget::moved(index)]
+                }
+            });
+
+            rearrange_components(road_data, &mut new_road_data, &targets);
+
+            let component_groups = new_road_data.component_groups_mut();
+            component_groups.remove(*group_index);
+
+            for later_group in component_groups
+                .iter_mut()
+                .filter(|later_group| later_group.first_index >= group_range.end)
+            {
+                later_group.first_index -= group_range.len();
+            }
+        }
+        RoadComponentGroupChange::InsertPreset(preset) => {
+            if preset.components.is_empty() {
+                return None;
+            }
+
+            let first_index = road_data.component_count();
+
+            new_road_data
+                .components_mut()
+                .extend(preset.components.iter().cloned());
+            new_road_data.recalculate_road_component_positions();
+
+            let targets =
+                collect_component_targets(road_data, |index| vec![ComponentTarget::moved(index)]);
+            move_markings_with_components(road_data, &mut new_road_data, &targets);
+
+            let left_edge = new_road_data.component_positions()[first_index].left;
+
+            new_road_data
+                .markings_mut()
+                .extend(preset.markings.iter().map(|road_marking| {
+                    road_marking.with_x_position(left_edge + road_marking.x_position)
+                }));
+
+            new_road_data
+                .component_groups_mut()
+                .push(RoadComponentGroup::new(
+                    preset.name.clone(),
+                    first_index,
+                    preset.components.len(),
+                ));
+        }
+    }
+
+    new_road_data
+        .component_groups_mut()
+        .sort_by_key(|group| group.first_index);
+
+    Some(new_road_data)
+}
+
+/// Where a road component ends up after a group change.
+#[derive(Clone, Copy, Debug)]
+struct ComponentTarget {
+    index: usize,
+    /// Whether the component is flipped, so markings on its left side end up on its right side.
+    is_mirrored: bool,
+}
+
+impl ComponentTarget {
+    fn moved(index: usize) -> Self {
+        Self {
+            index,
+            is_mirrored: false,
+        }
+    }
+
+    fn mirrored(index: usize) -> Self {
+        Self {
+            index,
+            is_mirrored: true,
+        }
+    }
+}
+
+/// Returns the targets of each component of the road, components without targets are removed and components with multiple targets are copied.
+fn collect_component_targets(
+    road_data: &RoadData,
+    targets_of_component: impl Fn(usize) -> Vec<ComponentTarget>,
+) -> Vec<Vec<ComponentTarget>> {
+    (0..road_data.component_count())
+        .map(targets_of_component)
+        .collect()
+}
+
+/// Replaces the components of `new_road_data` with the components of `road_data` placed at their targets.
+fn rearrange_components(
+    road_data: &RoadData,
+    new_road_data: &mut RoadData,
+    targets: &[Vec<ComponentTarget>],
+) {
+    let new_component_count = targets.iter().map(Vec::len).sum();
+    let mut new_components = vec![RoadComponent::default(); new_component_count];
+
+    for (index, road_component) in road_data.enumerate_components() {
+        for target in targets[index].iter() {
+            new_components[target.index] = road_component.clone();
+        }
+    }
+
+    *new_road_data.components_mut() = new_components;
+
+    // The road is drawn along its reference line, so a boundary has to stay between the same components
+    if let RoadReferenceLine::ComponentBoundary(boundary_index) = road_data.reference_line() {
+        new_road_data.set_reference_line(RoadReferenceLine::ComponentBoundary(
+            calculate_boundary_target(boundary_index, targets),
+        ));
+    }
+
+    new_road_data.recalculate_road_component_positions();
+
+    move_markings_with_components(road_data, new_road_data, targets);
+}
+
+/// Returns where the boundary between the component at `boundary_index` and the one before it ends up after a group change.
+///
+/// - The boundary follows the component on its left, unless only that component is mirrored.
+/// - Boundaries between removed components end up at the nearest remaining component on their left.
+fn calculate_boundary_target(boundary_index: usize, targets: &[Vec<ComponentTarget>]) -> usize {
+    let right_edge_of = |target: &ComponentTarget| match target.is_mirrored {
+        true => target.index,
+        false => target.index + 1,
+    };
+    let left_edge_of = |target: &ComponentTarget| match target.is_mirrored {
+        true => target.index + 1,
+        false => target.index,
+    };
+
+    let left_neighbour = boundary_index
+        .checked_sub(1)
+        .and_then(|index| targets.get(index))
+        .and_then(|component_targets| component_targets.first());
+    let right_neighbour = targets
+        .get(boundary_index)
+        .and_then(|component_targets| component_targets.first());
+
+    match (left_neighbour, right_neighbour) {
+        (Some(left), Some(right)) if left.is_mirrored && !right.is_mirrored => left_edge_of(right),
+        (Some(left), _) => right_edge_of(left),
+        (None, Some(right)) => left_edge_of(right),
+        (None, None) => targets[..boundary_index.min(targets.len())]
+            .iter()
+            .rev()
+            .find_map(|component_targets| component_targets.first())
+            .map_or(0, right_edge_of),
+    }
+}
+
+/// Moves each marking to the targets of the road component it's on, keeping its distance to the component's edge.
+///
+/// Markings on removed components are removed too, markings that aren't on a component stay where they are.
+fn move_markings_with_components(
+    road_data: &RoadData,
+    new_road_data: &mut RoadData,
+    targets: &[Vec<ComponentTarget>],
+) {
+    let new_component_positions = new_road_data.component_positions().to_vec();
+
+    *new_road_data.markings_mut() = road_data
+        .markings()
+        .iter()
+        .flat_map(|road_marking| {
+            let Some(road_component_under_point) =
+                road_data.find_road_component_under_point(road_marking.x_position)
+            else {
+                return vec![*road_marking];
+            };
+
+            let component_index = road_component_under_point.road_component_index;
+            let distance_to_left_edge =
+                road_marking.x_position - road_data.component_positions()[component_index].left;
+
+            targets[component_index]
+                .iter()
+                .map(|target| {
+                    let target_position = new_component_positions[target.index];
+
+                    road_marking.with_x_position(match target.is_mirrored {
+                        true => target_position.right - distance_to_left_edge,
+                        false => target_position.left + distance_to_left_edge,
+                    })
+                })
+                .collect()
+        })
+        .collect();
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::color::palettes::tailwind::*;
+
+    use crate::road::road_marking::RoadMarking;
+
+    use super::*;
+
+    /// A road from -5 to 5 meters, where "Curb" and "Verge" are grouped.
+    ///
+    /// It has a marking on the curb and a marking on the lane.
+    fn road_with_group() -> RoadData {
+        let mut road_data = RoadData::new(
+            String::from("Grouped road"),
+            vec![
+                RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), GRAY_600),
+                RoadComponent::new("Curb", Vec2::new(1.0, 0.2), GRAY_400),
+                RoadComponent::new("Verge", Vec2::new(3.0, 0.1), GREEN_700),
+                RoadComponent::new("Lane", Vec2::new(4.0, 0.1), GRAY_800),
+            ],
+            vec![
+                RoadMarking::new(-2.5, 0.1, Color::WHITE),
+                RoadMarking::new(3.0, 0.1, Color::WHITE),
+            ],
+        );
+
+        road_data
+            .component_groups_mut()
+            .push(RoadComponentGroup::new("Roadside", 1, 2));
+
+        road_data
+    }
+
+    fn component_names(road_data: &RoadData) -> Vec<&str> {
+        road_data
+            .components()
+            .iter()
+            .map(|road_component| road_component.name.as_str())
+            .collect()
+    }
+
+    fn marking_positions(road_data: &RoadData) -> Vec<f32> {
+        road_data
+            .markings()
+            .iter()
+            .map(|road_marking| road_marking.x_position)
+            .collect()
+    }
+
+    #[test]
+    fn group_with_next_extends_the_group() {
+        let road_data = apply_component_group_change(
+            &road_with_group(),
+            &RoadComponentGroupChange::GroupWithNext(0),
+        )
+        .unwrap();
+
+        assert_eq!(
+            road_data.component_groups(),
+            &[RoadComponentGroup::new("Roadside", 0, 3)]
+        );
+        assert!(apply_component_group_change(
+            &road_data,
+            &RoadComponentGroupChange::GroupWithNext(3)
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn moving_a_group_takes_its_markings_along() {
+        let road_data = apply_component_group_change(
+            &road_with_group(),
+            &RoadComponentGroupChange::Move {
+                group_index: 0,
+                direction: ReorderDirection::Previous,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            component_names(&road_data),
+            ["Curb", "Verge", "Sidewalk", "Lane"]
+        );
+        assert_eq!(road_data.component_groups()[0].component_range(), 0..2);
+        assert_eq!(marking_positions(&road_data), [-4.5, 3.0]);
+    }
+
+    #[test]
+    fn duplicating_a_group_copies_its_markings() {
+        let road_data = apply_component_group_change(
+            &road_with_group(),
+            &RoadComponentGroupChange::Duplicate(0),
+        )
+        .unwrap();
+
+        assert_eq!(
+            component_names(&road_data),
+            ["Sidewalk", "Curb", "Verge", "Curb", "Verge", "Lane"]
+        );
+        assert_eq!(
+            road_data
+                .component_groups()
+                .iter()
+                .map(RoadComponentGroup::component_range)
+                .collect::<Vec<_>>(),
+            [1..3, 3..5]
+        );
+        assert_eq!(marking_positions(&road_data), [-4.5, -0.5, 5.0]);
+    }
+
+    #[test]
+    fn mirroring_a_group_mirrors_its_markings() {
+        let road_data =
+            apply_component_group_change(&road_with_group(), &RoadComponentGroupChange::Mirror(0))
+                .unwrap();
+
+        assert_eq!(
+            component_names(&road_data),
+            ["Sidewalk", "Verge", "Curb", "Lane"]
+        );
+        assert_eq!(marking_positions(&road_data), [0.5, 3.0]);
+    }
+
+    #[test]
+    fn deleting_a_group_removes_its_components_and_markings() {
+        let road_data =
+            apply_component_group_change(&road_with_group(), &RoadComponentGroupChange::Delete(0))
+                .unwrap();
+
+        assert_eq!(component_names(&road_data), ["Sidewalk", "Lane"]);
+        assert!(road_data.component_groups().is_empty());
+        assert_eq!(marking_positions(&road_data), [1.0]);
+    }
+
+    #[test]
+    fn group_changes_keep_the_reference_line_between_the_same_components() {
+        let mut road_data = road_with_group();
+        // Between "Verge" and "Lane"
+        road_data.set_reference_line(RoadReferenceLine::ComponentBoundary(3));
+
+        let deleted =
+            apply_component_group_change(&road_data, &RoadComponentGroupChange::Delete(0)).unwrap();
+
+        // The group is gone, so the road is drawn between the sidewalk and the lane
+        assert_eq!(
+            deleted.reference_line(),
+            RoadReferenceLine::ComponentBoundary(1)
+        );
+        assert_eq!(deleted.component_positions()[1].left, 0.0);
+
+        // Between "Curb" and "Verge", which are both removed
+        road_data.set_reference_line(RoadReferenceLine::ComponentBoundary(2));
+
+        let deleted =
+            apply_component_group_change(&road_data, &RoadComponentGroupChange::Delete(0)).unwrap();
+
+        assert_eq!(
+            deleted.reference_line(),
+            RoadReferenceLine::ComponentBoundary(1)
+        );
+
+        // Between "Sidewalk" and the group, which stays in place when mirroring or duplicating the group
+        road_data.set_reference_line(RoadReferenceLine::ComponentBoundary(1));
+
+        for change in [
+            RoadComponentGroupChange::Mirror(0),
+            RoadComponentGroupChange::Duplicate(0),
+        ] {
+            let changed_road_data = apply_component_group_change(&road_data, &change).unwrap();
+
+            assert_eq!(
+                changed_road_data.reference_line(),
+                RoadReferenceLine::ComponentBoundary(1),
+                "{change:?}"
+            );
+        }
+
+        let moved = apply_component_group_change(
+            &road_data,
+            &RoadComponentGroupChange::Move {
+                group_index: 0,
+                direction: ReorderDirection::Next,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            component_names(&moved),
+            ["Sidewalk", "Lane", "Curb", "Verge"]
+        );
+        assert_eq!(
+            moved.reference_line(),
+            RoadReferenceLine::ComponentBoundary(1)
+        );
+    }
+
+    #[test]
+    fn inserted_preset_matches_the_saved_group() {
+        let road_data = road_with_group();
+        let preset = RoadComponentGroupPreset::from_group(&road_data, 0);
+
+        let new_road_data = apply_component_group_change(
+            &road_data,
+            &RoadComponentGroupChange::InsertPreset(preset),
+        )
+        .unwrap();
+
+        assert_eq!(
+            component_names(&new_road_data),
+            ["Sidewalk", "Curb", "Verge", "Lane", "Curb", "Verge"]
+        );
+        assert_eq!(new_road_data.component_groups()[1].component_range(), 4..6);
+        // The road grew 4 meters, so existing markings shift 2 meters to the left
+        assert_eq!(marking_positions(&new_road_data), [-4.5, 1.0, 3.5]);
+    }
+
+    #[test]
+    fn deleting_a_grouped_component_shrinks_its_group() {
+        let mut road_data = road_with_group();
+
+        road_data.remove_component_from_groups(0);
+        assert_eq!(road_data.component_groups()[0].component_range(), 0..2);
+
+        road_data.remove_component_from_groups(0);
+        road_data.remove_component_from_groups(0);
+        assert!(road_data.component_groups().is_empty());
+    }
+}
@@ -8,6 +8,7 @@ use crate::{
     },
     ui::{
         list::list_events::list_reorder::{OnListReorderRequested, ReorderIndices},
+        notifications::OnNotificationRequested,
         toolbar::RoadComponentsList,
     },
     utility::changed_value::ChangedValue,
@@ -57,6 +58,7 @@ impl OnRoadComponentReordered {
         }
     }
 
+    #[expect(unused)]
     pub fn new_road_data(&self) -> &RoadData {
         &self.changed_road_data.new_value
     }
@@ -66,10 +68,22 @@ fn handle_component_reorder_requests(
     mut requests: EventReader<OnRoadComponentReorderRequested>,
     mut on_reordered: EventWriter<OnRoadComponentReordered>,
     mut on_list_reorder: EventWriter<OnListReorderRequested>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
     mut active_road: ResMut<ActiveRoad>,
     road_components_list_query: Query<Entity, With<RoadComponentsList>>,
 ) {
     for request in requests.read() {
+        // Groups cover a fixed range of components, so single components stay on their side of a group's edge
+        if !active_road
+            .road_data()
+            .can_swap_components(request.reorder.previous_index, request.reorder.new_index)
+        {
+            on_notification.send(OnNotificationRequested::warning(
+                "Components can't be moved into or out of a group",
+            ));
+            continue;
+        }
+
         let changed_component_indices = ChangedComponentIndices::from_reorder(request.reorder);
         let previous_road_data = active_road.road_data().clone();
 
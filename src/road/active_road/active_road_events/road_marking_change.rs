@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{active_road::ActiveRoad, road_data::RoadData},
+    utility::changed_value::ChangedValue,
+    GameRunningSet,
+};
+
+pub struct RoadMarkingChangePlugin;
+
+impl Plugin for RoadMarkingChangePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnRoadMarkingChangeRequested>()
+            .add_event::<OnRoadMarkingChanged>()
+            .add_systems(
+                Update,
+                handle_marking_change_requests
+                    .in_set(GameRunningSet::HandleCommands)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RoadMarkingFieldChange {
+    XPosition(f32),
+    SegmentWidth(f32),
+    Color(Color),
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RoadMarkingChange {
+    pub field: RoadMarkingFieldChange,
+    pub road_marking_index: usize,
+}
+
+impl RoadMarkingChange {
+    pub fn new(field: RoadMarkingFieldChange, road_marking_index: usize) -> Self {
+        Self {
+            field,
+            road_marking_index,
+        }
+    }
+}
+
+#[derive(Event, Clone, Copy, PartialEq, Debug)]
+pub struct OnRoadMarkingChangeRequested {
+    pub requested_change: RoadMarkingChange,
+}
+
+impl OnRoadMarkingChangeRequested {
+    pub fn new(requested_change: RoadMarkingChange) -> Self {
+        Self { requested_change }
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadMarkingChanged {
+    pub change: RoadMarkingChange,
+    pub changed_road_data: ChangedValue<RoadData>,
+}
+
+impl OnRoadMarkingChanged {
+    pub fn new(change: RoadMarkingChange, changed_road_data: ChangedValue<RoadData>) -> Self {
+        Self {
+            change,
+            changed_road_data,
+        }
+    }
+
+    #[expect(unused)]
+    pub fn new_road_data(&self) -> &RoadData {
+        &self.changed_road_data.new_value
+    }
+}
+
+fn handle_marking_change_requests(
+    mut requests: EventReader<OnRoadMarkingChangeRequested>,
+    mut on_changed: EventWriter<OnRoadMarkingChanged>,
+    mut active_road: ResMut<ActiveRoad>,
+) {
+    for request in requests.read() {
+        let previous_road_data = active_road.road_data().clone();
+
+        active_road.change_road_marking_at_index(
+            request.requested_change.road_marking_index,
+            request.requested_change.field,
+        );
+
+        let new_road_data = active_road.road_data().clone();
+
+        on_changed.send(OnRoadMarkingChanged::new(
+            request.requested_change,
+            ChangedValue::new(previous_road_data, new_road_data),
+        ));
+    }
+}
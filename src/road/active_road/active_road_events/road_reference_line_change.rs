@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{
+        active_road::{changed_component_indices::ChangedComponentIndices, ActiveRoad},
+        road_data::RoadData,
+        road_reference_line::RoadReferenceLine,
+    },
+    utility::changed_value::ChangedValue,
+    GameRunningSet,
+};
+
+pub struct RoadReferenceLineChangePlugin;
+
+impl Plugin for RoadReferenceLineChangePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnRoadReferenceLineChangeRequested>()
+            .add_event::<OnRoadReferenceLineChanged>()
+            .add_systems(
+                Update,
+                handle_reference_line_change_requests
+                    .in_set(GameRunningSet::HandleCommands)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadReferenceLineChangeRequested {
+    pub reference_line: RoadReferenceLine,
+}
+
+impl OnRoadReferenceLineChangeRequested {
+    pub fn new(reference_line: RoadReferenceLine) -> Self {
+        Self { reference_line }
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadReferenceLineChanged {
+    pub changed_road_data: ChangedValue<RoadData>,
+}
+
+impl OnRoadReferenceLineChanged {
+    pub fn new(changed_road_data: ChangedValue<RoadData>) -> Self {
+        Self { changed_road_data }
+    }
+
+    #[expect(unused)]
+    pub fn new_road_data(&self) -> &RoadData {
+        &self.changed_road_data.new_value
+    }
+}
+
+fn handle_reference_line_change_requests(
+    mut requests: EventReader<OnRoadReferenceLineChangeRequested>,
+    mut on_changed: EventWriter<OnRoadReferenceLineChanged>,
+    mut active_road: ResMut<ActiveRoad>,
+) {
+    for request in requests.read() {
+        let previous_road_data = active_road.road_data().clone();
+
+        active_road.set_reference_line(request.reference_line);
+
+        active_road.update_road_marking_positions(
+            &previous_road_data,
+            &ChangedComponentIndices::default(),
+        );
+
+        let new_road_data = active_road.road_data().clone();
+
+        on_changed.send(OnRoadReferenceLineChanged::new(ChangedValue::new(
+            previous_road_data,
+            new_road_data,
+        )));
+    }
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{active_road::ActiveRoad, road_data::RoadData, road_structure::RoadStructure},
+    utility::changed_value::ChangedValue,
+    GameRunningSet,
+};
+
+pub struct RoadStructureChangePlugin;
+
+impl Plugin for RoadStructureChangePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnRoadStructureChangeRequested>()
+            .add_event::<OnRoadStructureChanged>()
+            .add_systems(
+                Update,
+                handle_structure_change_requests
+                    .in_set(GameRunningSet::HandleCommands)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RoadStructureFieldChange {
+    XPosition(f32),
+    Height(f32),
+    Color(Color),
+    HasCollider(bool),
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum RoadStructureChange {
+    Add(RoadStructure),
+    Field {
+        road_structure_index: usize,
+        field: RoadStructureFieldChange,
+    },
+    Delete(usize),
+}
+
+impl RoadStructureChange {
+    /// Returns true if this change adds or removes a structure, instead of changing one.
+    pub fn changes_structure_count(&self) -> bool {
+        matches!(
+            self,
+            RoadStructureChange::Add(_) | RoadStructureChange::Delete(_)
+        )
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadStructureChangeRequested {
+    pub requested_change: RoadStructureChange,
+}
+
+impl OnRoadStructureChangeRequested {
+    pub fn new(requested_change: RoadStructureChange) -> Self {
+        Self { requested_change }
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadStructureChanged {
+    pub change: RoadStructureChange,
+    pub changed_road_data: ChangedValue<RoadData>,
+}
+
+impl OnRoadStructureChanged {
+    pub fn new(change: RoadStructureChange, changed_road_data: ChangedValue<RoadData>) -> Self {
+        Self {
+            change,
+            changed_road_data,
+        }
+    }
+
+    pub fn new_road_data(&self) -> &RoadData {
+        &self.changed_road_data.new_value
+    }
+}
+
+fn handle_structure_change_requests(
+    mut requests: EventReader<OnRoadStructureChangeRequested>,
+    mut on_changed: EventWriter<OnRoadStructureChanged>,
+    mut active_road: ResMut<ActiveRoad>,
+) {
+    for request in requests.read() {
+        let previous_road_data = active_road.road_data().clone();
+
+        active_road.change_road_structures(&request.requested_change);
+
+        let new_road_data = active_road.road_data().clone();
+
+        on_changed.send(OnRoadStructureChanged::new(
+            request.requested_change.clone(),
+            ChangedValue::new(previous_road_data, new_road_data),
+        ));
+    }
+}
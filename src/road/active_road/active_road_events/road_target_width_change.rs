@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{
+        active_road::{changed_component_indices::ChangedComponentIndices, ActiveRoad},
+        road_data::RoadData,
+    },
+    utility::changed_value::ChangedValue,
+    GameRunningSet,
+};
+
+pub struct RoadTargetWidthChangePlugin;
+
+impl Plugin for RoadTargetWidthChangePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnRoadTargetWidthChangeRequested>()
+            .add_event::<OnRoadTargetWidthChanged>()
+            .add_systems(
+                Update,
+                handle_target_width_change_requests
+                    .in_set(GameRunningSet::HandleCommands)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadTargetWidthChangeRequested {
+    /// The new target width in meters, None removes the target width.
+    pub target_width: Option<f32>,
+}
+
+impl OnRoadTargetWidthChangeRequested {
+    pub fn new(target_width: Option<f32>) -> Self {
+        Self { target_width }
+    }
+}
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub struct OnRoadTargetWidthChanged {
+    pub changed_road_data: ChangedValue<RoadData>,
+}
+
+impl OnRoadTargetWidthChanged {
+    pub fn new(changed_road_data: ChangedValue<RoadData>) -> Self {
+        Self { changed_road_data }
+    }
+
+    #[expect(unused)]
+    pub fn new_road_data(&self) -> &RoadData {
+        &self.changed_road_data.new_value
+    }
+}
+
+fn handle_target_width_change_requests(
+    mut requests: EventReader<OnRoadTargetWidthChangeRequested>,
+    mut on_changed: EventWriter<OnRoadTargetWidthChanged>,
+    mut active_road: ResMut<ActiveRoad>,
+) {
+    for request in requests.read() {
+        let previous_road_data = active_road.road_data().clone();
+
+        active_road.set_target_width(request.target_width);
+
+        active_road.update_road_marking_positions(
+            &previous_road_data,
+            &ChangedComponentIndices::default(),
+        );
+
+        let new_road_data = active_road.road_data().clone();
+
+        on_changed.send(OnRoadTargetWidthChanged::new(ChangedValue::new(
+            previous_road_data,
+            new_road_data,
+        )));
+    }
+}
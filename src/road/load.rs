@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{game_modes::GameMode, GameRunningSet};
+use crate::{game_modes::GameMode, ui::notifications::OnNotificationRequested, GameRunningSet};
 
 use super::{
     active_road::{active_road_events::OnActiveRoadSet, ActiveRoad},
@@ -40,11 +40,20 @@ impl OnLoadRoadRequested {
 fn handle_load_requests(
     mut requests: EventReader<OnLoadRoadRequested>,
     mut on_road_set: EventWriter<OnActiveRoadSet>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
     mut active_road: ResMut<ActiveRoad>,
 ) {
     for request in requests.read() {
-        let road_data =
-            load_road_data(request.road_name()).expect("RoadData should load from file");
+        let road_data = match load_road_data(request.road_name()) {
+            Ok(road_data) => road_data,
+            Err(error) => {
+                on_notification.send(OnNotificationRequested::error(format!(
+                    "Failed to load \"{}\": {error}",
+                    request.road_name()
+                )));
+                continue;
+            }
+        };
 
         active_road.set_road_data(road_data.clone());
 
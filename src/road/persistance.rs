@@ -1,8 +1,10 @@
-use std::{fs, io};
+use std::{fs, io, path::Path};
 
 use ron::ser::PrettyConfig;
 
-use super::road_data::RoadData;
+use super::{road_component_group::RoadComponentGroupPreset, road_data::RoadData};
+
+const COMPONENT_GROUP_PRESET_FOLDER: &str = "assets/component_groups";
 
 // Saving
 
@@ -21,6 +23,18 @@ fn save_data_to_asset_folder(data: String, file_name: &str) -> Result<(), io::Er
     fs::write(path_from_file_name(file_name), data)
 }
 
+pub fn save_component_group_preset(preset: &RoadComponentGroupPreset) -> Result<(), ron::Error> {
+    let serialized_preset =
+        ron::ser::to_string_pretty(preset, PrettyConfig::new().struct_names(true))?;
+
+    fs::create_dir_all(COMPONENT_GROUP_PRESET_FOLDER)?;
+
+    Ok(fs::write(
+        preset_path_from_name(&preset.name),
+        serialized_preset,
+    )?)
+}
+
 // Loading
 
 pub fn load_road_data(file_name: &str) -> Result<RoadData, ron::Error> {
@@ -35,8 +49,44 @@ fn load_data_from_asset_folder(file_name: &str) -> Result<String, io::Error> {
     fs::read_to_string(path_from_file_name(file_name))
 }
 
+pub fn load_component_group_preset(name: &str) -> Result<RoadComponentGroupPreset, ron::Error> {
+    let serialized_preset = fs::read_to_string(preset_path_from_name(name))?;
+
+    Ok(ron::from_str::<RoadComponentGroupPreset>(
+        &serialized_preset,
+    )?)
+}
+
+/// Returns the names of all saved component group presets, sorted alphabetically.
+pub fn saved_component_group_preset_names() -> io::Result<Vec<String>> {
+    let mut names: Vec<String> = fs::read_dir(COMPONENT_GROUP_PRESET_FOLDER)?
+        .filter_map(|entry| {
+            entry
+                .ok()?
+                .path()
+                .file_stem()?
+                .to_os_string()
+                .into_string()
+                .ok()
+        })
+        .collect();
+
+    names.sort();
+
+    Ok(names)
+}
+
 // Utility
 
+/// Returns true if a road has already been saved under the given file name.
+pub fn road_data_exists(file_name: &str) -> bool {
+    Path::new(&path_from_file_name(file_name)).exists()
+}
+
 fn path_from_file_name(file_name: &str) -> String {
     format!("assets/roads/{file_name}.ron")
 }
+
+fn preset_path_from_name(name: &str) -> String {
+    format!("{COMPONENT_GROUP_PRESET_FOLDER}/{name}.ron")
+}
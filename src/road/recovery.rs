@@ -0,0 +1,324 @@
+pub mod recovery_snapshot;
+
+use bevy::prelude::*;
+use recovery_snapshot::{
+    clear_road_design_recovery_snapshots, load_latest_recovery_snapshot, write_recovery_snapshot,
+    RecoveryContent, RecoverySnapshot, RoadNetworkSnapshot, RoadSectionSnapshot,
+};
+
+use crate::{game_modes::GameMode, GameRunningSet};
+
+use super::{
+    active_road::{active_road_events::OnActiveRoadSet, ActiveRoad},
+    road_node::{road_node_builder::get_or_build_road_node, RequestedRoadNode, RoadNode},
+    road_section::{
+        road_section_builder::OnBuildRoadSectionRequested, RequestedRoadSection,
+        RequestedRoadSectionEnd, RoadSection,
+    },
+    save::OnRoadSaved,
+};
+
+const AUTOSAVE_INTERVAL_SECONDS: f32 = 60.0;
+
+pub struct RecoveryPlugin;
+
+impl Plugin for RecoveryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Autosave>()
+            .add_event::<OnUnsavedChangesDiscarded>()
+            .add_systems(Startup, load_recovery_offer)
+            .add_systems(OnEnter(GameMode::RoadEditor), reset_autosave)
+            .add_systems(OnEnter(GameMode::RoadDrawer), reset_autosave)
+            .add_systems(
+                OnExit(GameMode::RoadEditor),
+                remove_road_design_snapshots_without_unsaved_changes,
+            )
+            .add_systems(
+                Update,
+                (
+                    (
+                        restore_pending_road_design
+                            .in_set(GameRunningSet::SendCommands)
+                            .run_if(resource_exists::<PendingRecovery>),
+                        autosave_road_design.in_set(GameRunningSet::UpdateEntities),
+                        remove_road_design_snapshots_on_road_saved
+                            .in_set(GameRunningSet::UpdateEntities),
+                    )
+                        .run_if(in_state(GameMode::RoadEditor)),
+                    (
+                        restore_pending_road_network
+                            .in_set(GameRunningSet::SendCommands)
+                            .run_if(resource_exists::<PendingRecovery>),
+                        autosave_road_network.in_set(GameRunningSet::UpdateEntities),
+                    )
+                        .run_if(in_state(GameMode::RoadDrawer)),
+                ),
+            )
+            .add_systems(
+                Last,
+                (
+                    remove_road_design_snapshots.run_if(on_event::<OnUnsavedChangesDiscarded>()),
+                    remove_road_design_snapshots_without_unsaved_changes
+                        .run_if(on_event::<AppExit>()),
+                ),
+            );
+    }
+}
+
+/// The recovery snapshot that was left behind by a previous run, it exists until the user restores or discards it.
+#[derive(Resource)]
+pub struct RecoveryOffer {
+    snapshot: RecoverySnapshot,
+}
+
+impl RecoveryOffer {
+    pub fn snapshot(&self) -> &RecoverySnapshot {
+        &self.snapshot
+    }
+
+    /// Returns the game mode in which the snapshot can be restored.
+    pub fn game_mode(&self) -> GameMode {
+        match self.snapshot.content() {
+            RecoveryContent::RoadDesign(_) => GameMode::RoadEditor,
+            RecoveryContent::RoadNetwork(_) => GameMode::RoadDrawer,
+        }
+    }
+}
+
+/// Sent when the user deliberately throws away the unsaved changes of the active road, so they don't have to be recovered.
+#[derive(Event)]
+pub struct OnUnsavedChangesDiscarded;
+
+/// Recovered content that is restored when entering its game mode.
+#[derive(Resource)]
+pub struct PendingRecovery {
+    content: RecoveryContent,
+}
+
+impl PendingRecovery {
+    pub fn new(content: RecoveryContent) -> Self {
+        Self { content }
+    }
+}
+
+#[derive(Resource)]
+struct Autosave {
+    timer: Timer,
+    /// The content of the last written snapshot, used to avoid writing the same content twice.
+    last_content: Option<RecoveryContent>,
+}
+
+impl Default for Autosave {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(AUTOSAVE_INTERVAL_SECONDS, TimerMode::Repeating),
+            last_content: None,
+        }
+    }
+}
+
+impl Autosave {
+    fn write_if_changed(&mut self, content: RecoveryContent) {
+        if self.last_content.as_ref() == Some(&content) {
+            return;
+        }
+
+        if let Err(error) = write_recovery_snapshot(&RecoverySnapshot::new(content.clone())) {
+            warn!("Failed to write recovery snapshot: {error}");
+            return;
+        }
+
+        self.last_content = Some(content);
+    }
+}
+
+fn load_recovery_offer(mut commands: Commands) {
+    match load_latest_recovery_snapshot() {
+        Ok(Some(snapshot)) => commands.insert_resource(RecoveryOffer { snapshot }),
+        Ok(None) => (),
+        Err(error) => warn!("Failed to load recovery snapshot: {error}"),
+    }
+}
+
+fn reset_autosave(mut autosave: ResMut<Autosave>) {
+    *autosave = Autosave::default();
+}
+
+/// Removes the road design snapshots, the road design is either saved or deliberately discarded at this point.
+///
+/// The road network can't be saved, so its snapshots are the only copy of the drawn roads and are kept.
+fn remove_road_design_snapshots() {
+    if let Err(error) = clear_road_design_recovery_snapshots() {
+        warn!("Failed to remove recovery snapshots: {error}");
+    }
+}
+
+/// Keeps the road design snapshots while the active road has unsaved changes, since they may be the only copy of the user's work.
+fn remove_road_design_snapshots_without_unsaved_changes(active_road: Option<Res<ActiveRoad>>) {
+    if active_road.is_some_and(|active_road| active_road.has_unsaved_changes()) {
+        return;
+    }
+
+    remove_road_design_snapshots();
+}
+
+fn remove_road_design_snapshots_on_road_saved(
+    mut on_saved: EventReader<OnRoadSaved>,
+    mut autosave: ResMut<Autosave>,
+) {
+    if on_saved.read().count() == 0 {
+        return;
+    }
+
+    remove_road_design_snapshots();
+
+    // The next change has to be written again, even if it matches the removed snapshot
+    autosave.last_content = None;
+}
+
+fn autosave_road_design(
+    mut autosave: ResMut<Autosave>,
+    time: Res<Time>,
+    active_road: Res<ActiveRoad>,
+) {
+    if !autosave.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    if !active_road.has_unsaved_changes() {
+        return;
+    }
+
+    autosave.write_if_changed(RecoveryContent::RoadDesign(active_road.road_data().clone()));
+}
+
+fn autosave_road_network(
+    mut autosave: ResMut<Autosave>,
+    time: Res<Time>,
+    road_node_query: Query<(Entity, &Transform), With<RoadNode>>,
+    road_section_query: Query<&RoadSection>,
+) {
+    if !autosave.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let road_network = collect_road_network(&road_node_query, &road_section_query);
+
+    if road_network.is_empty() {
+        return;
+    }
+
+    autosave.write_if_changed(RecoveryContent::RoadNetwork(road_network));
+}
+
+fn restore_pending_road_design(
+    mut commands: Commands,
+    mut on_road_set: EventWriter<OnActiveRoadSet>,
+    mut active_road: ResMut<ActiveRoad>,
+    pending_recovery: Res<PendingRecovery>,
+) {
+    if let RecoveryContent::RoadDesign(road_data) = &pending_recovery.content {
+        active_road.recover_road_data(road_data.clone());
+
+        on_road_set.send(OnActiveRoadSet::new(road_data.clone()));
+    }
+
+    commands.remove_resource::<PendingRecovery>();
+}
+
+fn restore_pending_road_network(
+    mut commands: Commands,
+    mut on_build_section: EventWriter<OnBuildRoadSectionRequested>,
+    pending_recovery: Res<PendingRecovery>,
+) {
+    if let RecoveryContent::RoadNetwork(road_network) = &pending_recovery.content {
+        let road_nodes: Vec<RequestedRoadNode> = road_network
+            .node_positions
+            .iter()
+            .map(|position| {
+                let requested_node = RequestedRoadNode::new(*position, None);
+                let node_entity = get_or_build_road_node(&mut commands, requested_node);
+
+                RequestedRoadNode::new(*position, Some(node_entity))
+            })
+            .collect();
+
+        for section in road_network.sections.iter() {
+            let [start_index, end_index] = section.node_indices;
+            let (Some(start_node), Some(end_node)) =
+                (road_nodes.get(start_index), road_nodes.get(end_index))
+            else {
+                warn!("Skipped restoring a road section with a missing road node.");
+                continue;
+            };
+
+            let [start_direction, end_direction] = section.directions;
+            let (Ok(start_direction), Ok(end_direction)) =
+                (Dir3::new(start_direction), Dir3::new(end_direction))
+            else {
+                warn!("Skipped restoring a road section with an invalid direction.");
+                continue;
+            };
+
+            let requested_section = RequestedRoadSection {
+                ends: [
+                    RequestedRoadSectionEnd {
+                        road_node: *start_node,
+                        direction: start_direction,
+                    },
+                    RequestedRoadSectionEnd {
+                        road_node: *end_node,
+                        direction: end_direction,
+                    },
+                ],
+                variant: section.variant,
+            };
+
+            on_build_section.send(OnBuildRoadSectionRequested::with_road_design(
+                requested_section,
+                section.road_design.clone(),
+            ));
+        }
+    }
+
+    commands.remove_resource::<PendingRecovery>();
+}
+
+fn collect_road_network(
+    road_node_query: &Query<(Entity, &Transform), With<RoadNode>>,
+    road_section_query: &Query<&RoadSection>,
+) -> RoadNetworkSnapshot {
+    let road_nodes: Vec<(Entity, Vec3)> = road_node_query
+        .iter()
+        .map(|(entity, transform)| (entity, transform.translation))
+        .collect();
+
+    let node_index = |entity: Entity| {
+        road_nodes
+            .iter()
+            .position(|(node_entity, _)| *node_entity == entity)
+    };
+
+    let sections = road_section_query
+        .iter()
+        .filter_map(|section| {
+            let start_index = node_index(section.start().road_node_entity)?;
+            let end_index = node_index(section.end().road_node_entity)?;
+
+            Some(RoadSectionSnapshot {
+                node_indices: [start_index, end_index],
+                directions: section.ends.map(|end| end.direction.as_vec3()),
+                variant: section.variant,
+                road_design: section.road_design.clone(),
+            })
+        })
+        .collect();
+
+    RoadNetworkSnapshot {
+        node_positions: road_nodes
+            .into_iter()
+            .map(|(_, position)| position)
+            .collect(),
+        sections,
+    }
+}
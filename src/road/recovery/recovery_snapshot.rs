@@ -0,0 +1,230 @@
+use std::{
+    fs, io,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::road::{road_data::RoadData, road_section::RoadSectionVariant};
+
+const RECOVERY_FOLDER: &str = "assets/recovery";
+/// The amount of recovery snapshots that are kept, older snapshots are removed when a new one is written.
+const MAX_RECOVERY_SNAPSHOTS: usize = 5;
+
+/// A periodically written copy of the user's work, used to restore it after a crash.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecoverySnapshot {
+    /// Seconds since the unix epoch at which this snapshot was written.
+    written_at: u64,
+    content: RecoveryContent,
+}
+
+impl RecoverySnapshot {
+    pub fn new(content: RecoveryContent) -> Self {
+        Self {
+            written_at: seconds_since_epoch(),
+            content,
+        }
+    }
+
+    pub fn content(&self) -> &RecoveryContent {
+        &self.content
+    }
+
+    /// Returns a description of when this snapshot was written, relative to now.
+    pub fn age_description(&self) -> String {
+        describe_age(seconds_since_epoch().saturating_sub(self.written_at))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RecoveryContent {
+    /// The design of the active road in the road editor.
+    RoadDesign(RoadData),
+    /// The road network drawn in the road drawer.
+    RoadNetwork(RoadNetworkSnapshot),
+}
+
+impl RecoveryContent {
+    pub fn description(&self) -> &str {
+        match self {
+            RecoveryContent::RoadDesign(_) => "road design",
+            RecoveryContent::RoadNetwork(_) => "road network",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RoadNetworkSnapshot {
+    pub node_positions: Vec<Vec3>,
+    pub sections: Vec<RoadSectionSnapshot>,
+}
+
+impl RoadNetworkSnapshot {
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RoadSectionSnapshot {
+    /// Indices into the node positions of the network, for the start and end of this section.
+    pub node_indices: [usize; 2],
+    /// The outwards facing directions of the start and end of this section.
+    pub directions: [Vec3; 2],
+    pub variant: RoadSectionVariant,
+    pub road_design: RoadData,
+}
+
+// Saving
+
+/// Writes the snapshot to the recovery folder and removes the oldest snapshots if there are too many.
+pub fn write_recovery_snapshot(snapshot: &RecoverySnapshot) -> Result<(), ron::Error> {
+    fs::create_dir_all(RECOVERY_FOLDER)?;
+
+    let serialized_snapshot =
+        ron::ser::to_string_pretty(snapshot, PrettyConfig::new().struct_names(true))?;
+    fs::write(snapshot_path(snapshot.written_at), serialized_snapshot)?;
+
+    for outdated_timestamp in snapshot_timestamps()?
+        .into_iter()
+        .rev()
+        .skip(MAX_RECOVERY_SNAPSHOTS)
+    {
+        fs::remove_file(snapshot_path(outdated_timestamp))?;
+    }
+
+    Ok(())
+}
+
+pub fn clear_recovery_snapshots() -> Result<(), io::Error> {
+    for timestamp in snapshot_timestamps()? {
+        fs::remove_file(snapshot_path(timestamp))?;
+    }
+
+    Ok(())
+}
+
+/// Removes the snapshots of road designs, the snapshots of the road network are kept.
+pub fn clear_road_design_recovery_snapshots() -> Result<(), ron::Error> {
+    for timestamp in snapshot_timestamps()? {
+        let snapshot = load_recovery_snapshot(timestamp)?;
+
+        if matches!(snapshot.content, RecoveryContent::RoadDesign(_)) {
+            fs::remove_file(snapshot_path(timestamp))?;
+        }
+    }
+
+    Ok(())
+}
+
+// Loading
+
+/// Returns the most recently written recovery snapshot, or None if there are no snapshots.
+pub fn load_latest_recovery_snapshot() -> Result<Option<RecoverySnapshot>, ron::Error> {
+    let Some(latest_timestamp) = snapshot_timestamps()?.last().copied() else {
+        return Ok(None);
+    };
+
+    Ok(Some(load_recovery_snapshot(latest_timestamp)?))
+}
+
+fn load_recovery_snapshot(timestamp: u64) -> Result<RecoverySnapshot, ron::Error> {
+    let serialized_snapshot = fs::read_to_string(snapshot_path(timestamp))?;
+
+    Ok(ron::from_str::<RecoverySnapshot>(&serialized_snapshot)?)
+}
+
+// Utility
+
+/// Returns the timestamps of all snapshots in the recovery folder, from oldest to newest.
+fn snapshot_timestamps() -> Result<Vec<u64>, io::Error> {
+    let entries = match fs::read_dir(RECOVERY_FOLDER) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error),
+    };
+
+    let mut timestamps = Vec::new();
+
+    for entry in entries {
+        let path = entry?.path();
+
+        if path.extension().is_some_and(|extension| extension == "ron") {
+            if let Some(timestamp) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<u64>().ok())
+            {
+                timestamps.push(timestamp);
+            }
+        }
+    }
+
+    timestamps.sort_unstable();
+
+    Ok(timestamps)
+}
+
+fn snapshot_path(timestamp: u64) -> PathBuf {
+    PathBuf::from(format!("{RECOVERY_FOLDER}/{timestamp}.ron"))
+}
+
+fn seconds_since_epoch() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+fn describe_age(age_in_seconds: u64) -> String {
+    let (amount, unit) = match age_in_seconds {
+        0..60 => return String::from("less than a minute ago"),
+        60..3_600 => (age_in_seconds / 60, "minute"),
+        3_600..86_400 => (age_in_seconds / 3_600, "hour"),
+        _ => (age_in_seconds / 86_400, "day"),
+    };
+
+    match amount {
+        1 => format!("1 {unit} ago"),
+        _ => format!("{amount} {unit}s ago"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn describes_age_in_largest_whole_unit() {
+        assert_eq!(describe_age(12), "less than a minute ago");
+        assert_eq!(describe_age(60), "1 minute ago");
+        assert_eq!(describe_age(150), "2 minutes ago");
+        assert_eq!(describe_age(3_600 * 5 + 20), "5 hours ago");
+        assert_eq!(describe_age(86_400 * 3), "3 days ago");
+    }
+
+    #[test]
+    fn snapshot_survives_serialization() {
+        let snapshot = RecoverySnapshot::new(RecoveryContent::RoadNetwork(RoadNetworkSnapshot {
+            node_positions: vec![Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0)],
+            sections: vec![RoadSectionSnapshot {
+                node_indices: [0, 1],
+                directions: [Vec3::NEG_X, Vec3::X],
+                variant: RoadSectionVariant::Straight,
+                road_design: RoadData::default(),
+            }],
+        }));
+
+        let serialized =
+            ron::ser::to_string_pretty(&snapshot, PrettyConfig::new().struct_names(true)).unwrap();
+
+        assert_eq!(
+            ron::from_str::<RecoverySnapshot>(&serialized).unwrap(),
+            snapshot
+        );
+    }
+}
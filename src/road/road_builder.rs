@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::*;
 
@@ -7,13 +9,25 @@ use crate::utility::{
 };
 
 use super::{
+    road_component::{
+        rail_track::{
+            RailTrackCutKind, RAIL_COLOR, SLEEPER_COLOR, SLEEPER_HEIGHT, SLEEPER_LENGTH,
+            SLEEPER_SPACING,
+        },
+        RoadComponent,
+    },
     road_data::RoadData,
     road_section::{RequestedRoadSection, RoadSectionVariant},
+    road_structure::RoadStructure,
 };
 
 /// Builds the 3D road mesh from the given road data.
 pub struct RoadBuilder {
     mesh_builder: MeshBuilder,
+    /// The structures along the road, which are drawn with the road but have their own collider.
+    structure_mesh_builder: MeshBuilder,
+    /// Only the structures that have a collider.
+    structure_collider_builder: MeshBuilder,
     texture_builder: TextureBuilder,
 }
 
@@ -21,6 +35,8 @@ impl RoadBuilder {
     pub fn new() -> Self {
         Self {
             mesh_builder: MeshBuilder::new(),
+            structure_mesh_builder: MeshBuilder::new(),
+            structure_collider_builder: MeshBuilder::new(),
             texture_builder: TextureBuilder::new(),
         }
     }
@@ -34,30 +50,49 @@ impl RoadBuilder {
             warn!("build method called on a RoadBuilder that already contains mesh data.");
         }
 
-        let road_component_length = road_design.components().len();
-        let road_texture_length = road_component_length + road_design.markings().len();
+        let road_texture_length = calculate_road_texture_length(road_design);
+        let slice_transforms = get_slice_transforms_from_requested_section(requested_section);
 
         self.build_road_texture(road_design);
-        self.build_mesh_from_slice_transforms(
+        self.build_mesh_from_slice_transforms(road_design, &slice_transforms, road_texture_length);
+        self.build_sleepers_from_slice_transforms(
+            road_design,
+            &slice_transforms,
+            road_texture_length,
+        );
+        self.build_structures_from_slice_transforms(
             road_design,
-            get_slice_transforms_from_requested_section(requested_section),
+            &slice_transforms,
             road_texture_length,
         );
         // self.build_road_markings(road_design, road_texture_length, road_component_length);
     }
 
     pub fn get_mesh(&self) -> Mesh {
-        self.mesh_builder.to_mesh()
+        let mut mesh = self.mesh_builder.to_mesh();
+
+        if !self.structure_mesh_builder.empty() {
+            mesh.merge(&self.structure_mesh_builder.to_mesh());
+        }
+
+        mesh
     }
 
     pub fn get_texture_image(&self) -> Image {
         self.texture_builder.build_texture_image()
     }
 
+    /// Returns the collider of the road surface, without the structures along it.
     pub fn get_collider(&self) -> Collider {
         self.mesh_builder.to_collider()
     }
 
+    /// Returns the collider of the structures along the road, None if none of them have a collider.
+    pub fn get_structure_collider(&self) -> Option<Collider> {
+        (!self.structure_collider_builder.empty())
+            .then(|| self.structure_collider_builder.to_collider())
+    }
+
     #[allow(unused)]
     pub fn to_debug_data(
         &self,
@@ -82,8 +117,27 @@ impl RoadBuilder {
             .map(|marking| marking.color)
             .collect();
 
-        self.texture_builder
-            .add_colors([road_component_colors, road_marking_colors].concat());
+        let road_structure_colors: Vec<Color> = road_design
+            .structures()
+            .iter()
+            .map(|structure| structure.color)
+            .collect();
+
+        // Only roads with rail tracks need the colors of the rails and sleepers
+        let rail_track_colors: Vec<Color> = match road_design.rail_track_count() {
+            0 => Vec::new(),
+            _ => vec![RAIL_COLOR.into(), SLEEPER_COLOR.into()],
+        };
+
+        self.texture_builder.add_colors(
+            [
+                road_component_colors,
+                road_marking_colors,
+                road_structure_colors,
+                rail_track_colors,
+            ]
+            .concat(),
+        );
     }
 
     // TODO: rework road markings to work with the new cross-section method
@@ -120,47 +174,232 @@ impl RoadBuilder {
     fn build_mesh_from_slice_transforms(
         &mut self,
         road_design: &RoadData,
-        slice_transforms: Vec<Transform>,
+        slice_transforms: &[Transform],
         road_texture_length: usize,
     ) {
         let road_design_slice = calculate_road_design_slice(road_design, road_texture_length);
 
-        for (slice_index, slice_transform) in slice_transforms.iter().enumerate() {
-            let translation = slice_transform.translation;
-            let rotation = slice_transform.rotation;
+        extrude_slice_along_transforms(
+            &mut self.mesh_builder,
+            &road_design_slice,
+            slice_transforms,
+        );
+    }
 
-            let transformed_vertices: Vec<Vec3> = road_design_slice
-                .vertices
-                .iter()
-                .map(|vertex| translation + rotation * *vertex)
-                .collect();
+    fn build_structures_from_slice_transforms(
+        &mut self,
+        road_design: &RoadData,
+        slice_transforms: &[Transform],
+        road_texture_length: usize,
+    ) {
+        let texture_index_offset = road_design.component_count() + road_design.markings().len();
 
-            let transformed_normals: Vec<Vec3> = road_design_slice
-                .normals
-                .iter()
-                .map(|normal| rotation * *normal)
-                .collect();
-
-            for vertex_index in 0..road_design_slice.len() {
-                self.mesh_builder.add_vertex(
-                    transformed_vertices[vertex_index],
-                    transformed_normals[vertex_index],
-                    road_design_slice.uvs[vertex_index],
+        for (index, structure) in road_design.structures().iter().enumerate() {
+            let uv = calculate_road_component_uv(texture_index_offset + index, road_texture_length);
+            let structure_slice = calculate_road_structure_slice(structure, uv);
+
+            extrude_slice_along_transforms(
+                &mut self.structure_mesh_builder,
+                &structure_slice,
+                slice_transforms,
+            );
+
+            if structure.has_collider {
+                extrude_slice_along_transforms(
+                    &mut self.structure_collider_builder,
+                    &structure_slice,
+                    slice_transforms,
                 );
             }
+        }
+    }
+
+    /// Adds the sleepers of the rail tracks that have them, spaced evenly along the road.
+    ///
+    /// Sleepers are drawn with the structures, so they don't make the road's collider bumpy.
+    fn build_sleepers_from_slice_transforms(
+        &mut self,
+        road_design: &RoadData,
+        slice_transforms: &[Transform],
+        road_texture_length: usize,
+    ) {
+        let uv = calculate_road_component_uv(
+            calculate_rail_texture_index(road_design) + 1,
+            road_texture_length,
+        );
+        let sleeper_transforms = calculate_transforms_at_spacing(slice_transforms, SLEEPER_SPACING);
+
+        for (index, road_component) in road_design.enumerate_components() {
+            let Some(track) = road_component
+                .fitting_track()
+                .filter(|track| track.has_sleepers)
+            else {
+                continue;
+            };
+
+            let component_position = road_design.component_positions()[index];
+            let height = road_component.height();
+
+            for span in track.sleeper_spans(component_position.center) {
+                // Sleepers don't stick out past the sides of their road component
+                let left = span.start.max(component_position.left);
+                let right = span.end.min(component_position.right);
+
+                if left >= right {
+                    continue;
+                }
 
-            if slice_index < slice_transforms.len() - 1 {
-                for triangle in calculate_road_design_slice_triangles(
-                    slice_index as u32,
-                    road_design_slice.len() as u32,
-                ) {
-                    self.mesh_builder.add_triangle(triangle);
+                let profile = [
+                    Vec2::new(left, height),
+                    Vec2::new(left, height + SLEEPER_HEIGHT),
+                    Vec2::new(right, height + SLEEPER_HEIGHT),
+                    Vec2::new(right, height),
+                ];
+                let sleeper_slice = calculate_closed_profile_slice(&profile, uv);
+
+                for sleeper_transform in sleeper_transforms.iter() {
+                    let half_length = sleeper_transform.forward() * SLEEPER_LENGTH / 2.0;
+                    let sleeper_ends = [
+                        sleeper_transform
+                            .with_translation(sleeper_transform.translation - half_length),
+                        sleeper_transform
+                            .with_translation(sleeper_transform.translation + half_length),
+                    ];
+
+                    extrude_slice_along_transforms(
+                        &mut self.structure_mesh_builder,
+                        &sleeper_slice,
+                        &sleeper_ends,
+                    );
+                    add_profile_caps(
+                        &mut self.structure_mesh_builder,
+                        &profile,
+                        &sleeper_ends,
+                        uv,
+                    );
                 }
             }
         }
     }
 }
 
+/// Adds a copy of the slice at each of the given transforms to the mesh, and connects each copy to the next one.
+fn extrude_slice_along_transforms(
+    mesh_builder: &mut MeshBuilder,
+    slice: &RoadDesignSlice,
+    slice_transforms: &[Transform],
+) {
+    let first_vertex_index = mesh_builder.vertex_count();
+
+    for (slice_index, slice_transform) in slice_transforms.iter().enumerate() {
+        let translation = slice_transform.translation;
+        let rotation = slice_transform.rotation;
+
+        let transformed_vertices: Vec<Vec3> = slice
+            .vertices
+            .iter()
+            .map(|vertex| translation + rotation * *vertex)
+            .collect();
+
+        let transformed_normals: Vec<Vec3> = slice
+            .normals
+            .iter()
+            .map(|normal| rotation * *normal)
+            .collect();
+
+        for vertex_index in 0..slice.len() {
+            mesh_builder.add_vertex(
+                transformed_vertices[vertex_index],
+                transformed_normals[vertex_index],
+                slice.uvs[vertex_index],
+            );
+        }
+
+        if slice_index < slice_transforms.len() - 1 {
+            for triangle in
+                calculate_road_design_slice_triangles(slice_index as u32, slice.len() as u32)
+            {
+                mesh_builder.add_triangle(triangle.offsetted(first_vertex_index));
+            }
+        }
+    }
+}
+
+/// Closes both ends of a profile that was extruded between the given 2 transforms.
+///
+/// - The profile has to be convex, with its points going clockwise.
+fn add_profile_caps(
+    mesh_builder: &mut MeshBuilder,
+    profile: &[Vec2],
+    end_transforms: &[Transform; 2],
+    uv: Vec2,
+) {
+    if profile.len() < 3 {
+        return;
+    }
+
+    for (is_front, end_transform) in [false, true].into_iter().zip(end_transforms) {
+        let first_vertex_index = mesh_builder.vertex_count();
+        let normal = match is_front {
+            true => end_transform.forward().as_vec3(),
+            false => end_transform.back().as_vec3(),
+        };
+
+        for point in profile {
+            mesh_builder.add_vertex(
+                end_transform.translation + end_transform.rotation * point.extend(0.0),
+                normal,
+                uv,
+            );
+        }
+
+        // Fan out from the first point, the front cap is seen from the other side
+        for index in 1..profile.len() as u32 - 1 {
+            let triangle = match is_front {
+                true => TriangleIndices(0, index, index + 1),
+                false => TriangleIndices(0, index + 1, index),
+            };
+
+            mesh_builder.add_triangle(triangle.offsetted(first_vertex_index));
+        }
+    }
+}
+
+/// Returns transforms that are the given distance apart along the path of the given transforms.
+///
+/// - The first transform is half of the spacing away from the start of the path.
+/// - Transforms in between the given transforms are interpolated.
+fn calculate_transforms_at_spacing(transforms: &[Transform], spacing: f32) -> Vec<Transform> {
+    let mut spaced_transforms = Vec::new();
+
+    if spacing <= 0.0 {
+        return spaced_transforms;
+    }
+
+    let mut next_distance = spacing / 2.0;
+    let mut travelled_distance = 0.0;
+
+    for pair in transforms.windows(2) {
+        let (start, end) = (pair[0], pair[1]);
+        let segment_length = start.translation.distance(end.translation);
+
+        while next_distance <= travelled_distance + segment_length {
+            let progress = (next_distance - travelled_distance) / segment_length;
+
+            spaced_transforms.push(
+                Transform::from_translation(start.translation.lerp(end.translation, progress))
+                    .with_rotation(start.rotation.slerp(end.rotation, progress)),
+            );
+
+            next_distance += spacing;
+        }
+
+        travelled_distance += segment_length;
+    }
+
+    spaced_transforms
+}
+
 // New Utils
 
 /// A cross-section slice for the given road_design, made out of vertices with their corresponding normals and UVs.
@@ -220,17 +459,18 @@ pub fn calculate_road_design_slice(
 ) -> RoadDesignSlice {
     let min_vertices_count = road_design.component_count() + 3;
     let mut road_design_slice = RoadDesignSlice::with_capacity(min_vertices_count);
-
-    let mut width_of_built_components = 0.0;
+    let rail_uv = calculate_road_component_uv(
+        calculate_rail_texture_index(road_design),
+        road_texture_length,
+    );
 
     for (index, current_component) in road_design.enumerate_components() {
         let uv = calculate_road_component_uv(index, road_texture_length);
 
-        let left_x_position = calculate_left_x_position_of_road_component(
-            road_design.total_width(),
-            width_of_built_components,
-        );
-        let right_x_position = left_x_position + current_component.size.x;
+        // Relative to the reference line, which is the line the slice transforms are on
+        let component_position = road_design.component_positions()[index];
+        let left_x_position = component_position.left;
+        let right_x_position = component_position.right;
 
         let current_height = current_component.height();
 
@@ -268,16 +508,12 @@ pub fn calculate_road_design_slice(
         }
 
         // Top side
-        road_design_slice.push(
-            Vec3::new(left_x_position, current_height, 0.0),
-            Vec3::Y,
-            uv,
-        );
-
-        road_design_slice.push(
-            Vec3::new(right_x_position, current_height, 0.0),
-            Vec3::Y,
-            uv,
+        push_road_component_top_side(
+            &mut road_design_slice,
+            current_component,
+            component_position.center,
+            left_x_position..right_x_position,
+            (uv, rail_uv),
         );
 
         // Right side
@@ -294,13 +530,93 @@ pub fn calculate_road_design_slice(
                 uv,
             );
         }
-
-        width_of_built_components += current_component.size.x;
     }
 
     road_design_slice
 }
 
+/// Adds the top side of a road component to the slice, from left to right.
+///
+/// - Components with a rail track get the rail heads and their grooves cut into their top side.
+/// - `uvs` are the uvs of the component and of its rails.
+fn push_road_component_top_side(
+    road_design_slice: &mut RoadDesignSlice,
+    road_component: &RoadComponent,
+    center: f32,
+    x_positions: Range<f32>,
+    (uv, rail_uv): (Vec2, Vec2),
+) {
+    let height = road_component.height();
+    let mut left_x_position = x_positions.start;
+
+    for cut in road_component
+        .fitting_track()
+        .map(|track| track.surface_cuts(center))
+        .into_iter()
+        .flatten()
+    {
+        road_design_slice.push(Vec3::new(left_x_position, height, 0.0), Vec3::Y, uv);
+        road_design_slice.push(Vec3::new(cut.left, height, 0.0), Vec3::Y, uv);
+
+        match cut.kind {
+            RailTrackCutKind::RailHead => {
+                road_design_slice.push(Vec3::new(cut.left, height, 0.0), Vec3::Y, rail_uv);
+                road_design_slice.push(Vec3::new(cut.right, height, 0.0), Vec3::Y, rail_uv);
+            }
+            RailTrackCutKind::Groove(depth) => {
+                let bottom_height = height - depth.min(height);
+
+                road_design_slice.push(Vec3::new(cut.left, height, 0.0), Vec3::X, uv);
+                road_design_slice.push(Vec3::new(cut.left, bottom_height, 0.0), Vec3::X, uv);
+
+                road_design_slice.push(Vec3::new(cut.left, bottom_height, 0.0), Vec3::Y, uv);
+                road_design_slice.push(Vec3::new(cut.right, bottom_height, 0.0), Vec3::Y, uv);
+
+                road_design_slice.push(Vec3::new(cut.right, bottom_height, 0.0), Vec3::NEG_X, uv);
+                road_design_slice.push(Vec3::new(cut.right, height, 0.0), Vec3::NEG_X, uv);
+            }
+        }
+
+        left_x_position = cut.right;
+    }
+
+    road_design_slice.push(Vec3::new(left_x_position, height, 0.0), Vec3::Y, uv);
+    road_design_slice.push(Vec3::new(x_positions.end, height, 0.0), Vec3::Y, uv);
+}
+
+/// Returns a cross-section slice of the given structure, placed at its position relative to the road's reference line.
+///
+/// - Every edge of the structure's profile gets its own 2 vertices, so each side is lit flat.
+/// - The profile is closed by connecting its last point back to its first point.
+pub fn calculate_road_structure_slice(structure: &RoadStructure, uv: Vec2) -> RoadDesignSlice {
+    let points: Vec<Vec2> = structure.placed_profile().collect();
+
+    calculate_closed_profile_slice(&points, uv)
+}
+
+/// Returns a cross-section slice of the given closed profile, whose points go clockwise.
+///
+/// - Every edge of the profile gets its own 2 vertices, so each side is lit flat.
+fn calculate_closed_profile_slice(points: &[Vec2], uv: Vec2) -> RoadDesignSlice {
+    let mut profile_slice = RoadDesignSlice::with_capacity(points.len() * 2);
+
+    if points.len() < 2 {
+        return profile_slice;
+    }
+
+    for (index, start) in points.iter().enumerate() {
+        let end = points[(index + 1) % points.len()];
+        let direction = end - *start;
+        // Facing outwards, since the points go clockwise
+        let normal = Vec3::new(-direction.y, direction.x, 0.0).normalize_or_zero();
+
+        profile_slice.push(start.extend(0.0), normal, uv);
+        profile_slice.push(end.extend(0.0), normal, uv);
+    }
+
+    profile_slice
+}
+
 /// Returns a list of TriangleIndices that connect the current slice to the next slice.
 ///
 /// - `slice_index` is the n-th slice that this fn is called for.
@@ -373,14 +689,6 @@ fn get_slice_transforms_from_requested_section(
     }
 }
 
-/// Returns the x position of a component without taking it's size into account, so on the far-left side of the component.
-fn calculate_left_x_position_of_road_component(
-    road_width: f32,
-    width_of_built_components: f32,
-) -> f32 {
-    -road_width / 2.0 + width_of_built_components
-}
-
 #[expect(unused)]
 fn calculate_road_marking_uv(
     index: usize,
@@ -390,6 +698,21 @@ fn calculate_road_marking_uv(
     calculate_road_component_uv(index + road_component_length, texture_length)
 }
 
+/// Returns the amount of colors in the texture of the given road_design.
+fn calculate_road_texture_length(road_design: &RoadData) -> usize {
+    let rail_track_color_count = match road_design.rail_track_count() {
+        0 => 0,
+        _ => 2,
+    };
+
+    calculate_rail_texture_index(road_design) + rail_track_color_count
+}
+
+/// Returns the index of the rail color in the road texture, the sleeper color comes right after it.
+fn calculate_rail_texture_index(road_design: &RoadData) -> usize {
+    road_design.component_count() + road_design.markings().len() + road_design.structures().len()
+}
+
 fn calculate_road_component_uv(index: usize, texture_length: usize) -> Vec2 {
     let step_size = 1.0 / texture_length as f32;
     Vec2::new(step_size * index as f32 + step_size * 0.5, 0.5)
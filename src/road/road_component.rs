@@ -1,6 +1,8 @@
+pub mod rail_track;
 pub mod road_component_position;
 
 use bevy::{color::palettes::tailwind::GRAY_600, prelude::*};
+use rail_track::RailTrack;
 use serde::{Deserialize, Serialize};
 
 /// The pieces that a road design consists of, like lanes or sidewalks.
@@ -8,8 +10,19 @@ use serde::{Deserialize, Serialize};
 #[serde(default)]
 pub struct RoadComponent {
     pub name: String,
+    /// Width and height in meters.
     pub size: Vec2,
     pub color: Color,
+    /// The narrowest this component gets when its width is given by the road's target width, in meters.
+    pub min_width: Option<f32>,
+    /// The widest this component gets when its width is given by the road's target width, in meters.
+    pub max_width: Option<f32>,
+    /// How much of the width that's left of the road's target width this component gets, relative to the other flexible components.
+    ///
+    /// Components with a flex of 0 keep their own width.
+    pub flex: f32,
+    /// The rails embedded in this component's surface, for tram lanes and other rail lanes.
+    pub track: Option<RailTrack>,
 }
 
 impl RoadComponent {
@@ -18,6 +31,10 @@ impl RoadComponent {
             name: name.into(),
             size,
             color: color.into(),
+            min_width: None,
+            max_width: None,
+            flex: 0.0,
+            track: None,
         }
     }
 
@@ -41,6 +58,46 @@ impl RoadComponent {
         self
     }
 
+    pub fn with_min_width(mut self, min_width: Option<f32>) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    pub fn with_max_width(mut self, max_width: Option<f32>) -> Self {
+        self.max_width = max_width;
+        self
+    }
+
+    pub fn with_flex(mut self, flex: f32) -> Self {
+        self.flex = flex;
+        self
+    }
+
+    pub fn with_track(mut self, track: Option<RailTrack>) -> Self {
+        self.track = track;
+        self
+    }
+
+    /// Returns this component's rail track, if it has one that fits within its width.
+    pub fn fitting_track(&self) -> Option<RailTrack> {
+        self.track.filter(|track| track.width() <= self.width())
+    }
+
+    /// Returns true if this component's width is given by the road's target width, instead of being fixed.
+    pub fn is_flexible(&self) -> bool {
+        self.flex > 0.0
+    }
+
+    /// Returns the given width limited to this component's min and max width.
+    pub fn clamp_width(&self, width: f32) -> f32 {
+        let width = width.max(self.min_width.unwrap_or(0.0));
+
+        match self.max_width {
+            Some(max_width) => width.min(max_width),
+            None => width,
+        }
+    }
+
     pub fn width(&self) -> f32 {
         self.size.x
     }
@@ -60,6 +117,10 @@ impl Default for RoadComponent {
             name: String::from("New component"),
             size: Vec2::new(1.0, 0.2),
             color: GRAY_600.into(),
+            min_width: None,
+            max_width: None,
+            flex: 0.0,
+            track: None,
         }
     }
 }
@@ -70,4 +131,10 @@ pub enum RoadComponentField {
     Width,
     Height,
     Color,
+    MinWidth,
+    MaxWidth,
+    Flex,
+    HasTrack,
+    TrackGauge,
+    HasSleepers,
 }
@@ -0,0 +1,143 @@
+use std::ops::Range;
+
+use bevy::{color::palettes::tailwind::*, prelude::*};
+use serde::{Deserialize, Serialize};
+
+/// The distance between the inner faces of the rail heads of most tram and train tracks, in meters.
+pub const STANDARD_GAUGE: f32 = 1.435;
+
+const RAIL_HEAD_WIDTH: f32 = 0.07;
+/// The channel next to the inner side of each rail head, that the flanges of the wheels run through.
+const GROOVE_WIDTH: f32 = 0.04;
+const GROOVE_DEPTH: f32 = 0.05;
+
+/// How far sleepers reach past the outer side of the rail heads.
+const SLEEPER_OVERHANG: f32 = 0.45;
+/// How far sleepers stick out above the surface of their road component.
+pub const SLEEPER_HEIGHT: f32 = 0.02;
+/// The size of a sleeper along the track.
+pub const SLEEPER_LENGTH: f32 = 0.25;
+/// The distance between the centers of 2 neighbouring sleepers.
+pub const SLEEPER_SPACING: f32 = 0.65;
+
+pub const RAIL_COLOR: Srgba = ZINC_400;
+pub const SLEEPER_COLOR: Srgba = STONE_500;
+
+/// Rails embedded in the surface of a road component, like a tram lane.
+///
+/// The track is centered on its road component.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RailTrack {
+    /// The distance between the inner faces of the 2 rail heads, in meters.
+    pub gauge: f32,
+    pub has_sleepers: bool,
+}
+
+impl RailTrack {
+    pub fn new(gauge: f32) -> Self {
+        Self {
+            gauge,
+            has_sleepers: false,
+        }
+    }
+
+    pub fn with_gauge(mut self, gauge: f32) -> Self {
+        self.gauge = gauge;
+        self
+    }
+
+    pub fn with_sleepers(mut self, has_sleepers: bool) -> Self {
+        self.has_sleepers = has_sleepers;
+        self
+    }
+
+    /// Returns the total width of the rail heads and the space between them.
+    pub fn width(&self) -> f32 {
+        self.gauge + RAIL_HEAD_WIDTH * 2.0
+    }
+
+    /// Returns the parts that are cut into the surface of a road component for this track, from left to right.
+    ///
+    /// - `center` is the horizontal position of the track's center.
+    pub fn surface_cuts(&self, center: f32) -> [RailTrackCut; 4] {
+        let half_gauge = self.gauge / 2.0;
+
+        [
+            RailTrackCut::rail_head(center - half_gauge - RAIL_HEAD_WIDTH, center - half_gauge),
+            RailTrackCut::groove(center - half_gauge, center - half_gauge + GROOVE_WIDTH),
+            RailTrackCut::groove(center + half_gauge - GROOVE_WIDTH, center + half_gauge),
+            RailTrackCut::rail_head(center + half_gauge, center + half_gauge + RAIL_HEAD_WIDTH),
+        ]
+    }
+
+    /// Returns the horizontal spans of a sleeper, which is split where the rails and their grooves run over it.
+    ///
+    /// - `center` is the horizontal position of the track's center.
+    pub fn sleeper_spans(&self, center: f32) -> [Range<f32>; 3] {
+        let half_gauge = self.gauge / 2.0;
+        let outer_rail_edge = half_gauge + RAIL_HEAD_WIDTH;
+
+        [
+            (center - outer_rail_edge - SLEEPER_OVERHANG)..(center - outer_rail_edge),
+            (center - half_gauge + GROOVE_WIDTH)..(center + half_gauge - GROOVE_WIDTH),
+            (center + outer_rail_edge)..(center + outer_rail_edge + SLEEPER_OVERHANG),
+        ]
+    }
+}
+
+impl Default for RailTrack {
+    fn default() -> Self {
+        Self::new(STANDARD_GAUGE)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RailTrackCutKind {
+    /// The top of a rail, flush with the surface.
+    RailHead,
+    /// A channel of the given depth.
+    Groove(f32),
+}
+
+/// A part of a road component's surface that belongs to its rail track.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RailTrackCut {
+    pub left: f32,
+    pub right: f32,
+    pub kind: RailTrackCutKind,
+}
+
+impl RailTrackCut {
+    fn rail_head(left: f32, right: f32) -> Self {
+        Self {
+            left,
+            right,
+            kind: RailTrackCutKind::RailHead,
+        }
+    }
+
+    fn groove(left: f32, right: f32) -> Self {
+        Self {
+            left,
+            right,
+            kind: RailTrackCutKind::Groove(GROOVE_DEPTH),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grooves_are_on_the_inner_side_of_the_rail_heads() {
+        let cuts = RailTrack::default().surface_cuts(0.0);
+
+        assert_eq!(cuts[0].right, -STANDARD_GAUGE / 2.0);
+        assert_eq!(cuts[1].left, cuts[0].right);
+        assert_eq!(cuts[2].right, cuts[3].left);
+        assert_eq!(cuts[3].left, STANDARD_GAUGE / 2.0);
+        assert!(cuts.windows(2).all(|pair| pair[0].right <= pair[1].left));
+    }
+}
@@ -0,0 +1,132 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{ui::notifications::OnNotificationRequested, GameRunningSet};
+
+use super::{
+    persistance::save_component_group_preset, road_component::RoadComponent, road_data::RoadData,
+    road_marking::RoadMarking,
+};
+
+pub struct RoadComponentGroupPlugin;
+
+impl Plugin for RoadComponentGroupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnSaveComponentGroupPresetRequested>()
+            .add_systems(
+                Update,
+                handle_save_preset_requests.in_set(GameRunningSet::HandleCommands),
+            );
+    }
+}
+
+/// A named run of neighbouring road components that is edited as one unit, like a sidewalk with its curb and grass verge.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoadComponentGroup {
+    pub name: String,
+    /// Index of the first road component in this group.
+    pub first_index: usize,
+    pub component_count: usize,
+}
+
+impl RoadComponentGroup {
+    pub fn new(name: impl Into<String>, first_index: usize, component_count: usize) -> Self {
+        Self {
+            name: name.into(),
+            first_index,
+            component_count,
+        }
+    }
+
+    pub fn component_range(&self) -> Range<usize> {
+        self.first_index..(self.first_index + self.component_count)
+    }
+
+    pub fn contains(&self, component_index: usize) -> bool {
+        self.component_range().contains(&component_index)
+    }
+}
+
+impl Default for RoadComponentGroup {
+    fn default() -> Self {
+        Self::new("New group", 0, 0)
+    }
+}
+
+/// A saved group of road components that can be added to any road design.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoadComponentGroupPreset {
+    pub name: String,
+    pub components: Vec<RoadComponent>,
+    /// The markings on the group's components, with x positions relative to the group's left edge.
+    pub markings: Vec<RoadMarking>,
+}
+
+impl RoadComponentGroupPreset {
+    /// Returns a preset of the group at `group_index`, including the markings on its components.
+    pub fn from_group(road_data: &RoadData, group_index: usize) -> Self {
+        let group = &road_data.component_groups()[group_index];
+        let range = group.component_range();
+        let left_edge = road_data.component_positions()[range.start].left;
+
+        let markings = road_data
+            .markings()
+            .iter()
+            .filter(|road_marking| {
+                road_data
+                    .find_road_component_under_point(road_marking.x_position)
+                    .is_some_and(|under_point| range.contains(&under_point.road_component_index))
+            })
+            .map(|road_marking| road_marking.with_x_position(road_marking.x_position - left_edge))
+            .collect();
+
+        Self {
+            name: group.name.clone(),
+            components: road_data.components()[range].to_vec(),
+            markings,
+        }
+    }
+}
+
+impl Default for RoadComponentGroupPreset {
+    fn default() -> Self {
+        Self {
+            name: String::from("New group"),
+            components: Vec::new(),
+            markings: Vec::new(),
+        }
+    }
+}
+
+#[derive(Event)]
+pub struct OnSaveComponentGroupPresetRequested {
+    preset: RoadComponentGroupPreset,
+}
+
+impl OnSaveComponentGroupPresetRequested {
+    pub fn new(preset: RoadComponentGroupPreset) -> Self {
+        Self { preset }
+    }
+}
+
+fn handle_save_preset_requests(
+    mut requests: EventReader<OnSaveComponentGroupPresetRequested>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
+) {
+    for request in requests.read() {
+        let preset_name = &request.preset.name;
+
+        match save_component_group_preset(&request.preset) {
+            Ok(()) => on_notification.send(OnNotificationRequested::success(format!(
+                "Saved group preset \"{preset_name}\""
+            ))),
+            Err(error) => on_notification.send(OnNotificationRequested::error(format!(
+                "Failed to save group preset \"{preset_name}\": {error}"
+            ))),
+        };
+    }
+}
@@ -1,4 +1,4 @@
-use std::{iter::Enumerate, slice::Iter};
+use std::{iter::Enumerate, ops::Range, slice::Iter};
 
 use bevy::{math::NormedVectorSpace, prelude::*};
 use serde::{Deserialize, Serialize};
@@ -8,29 +8,47 @@ use super::{
         road_component_position::{RoadComponentPosition, RoadComponentPositionField},
         RoadComponent,
     },
+    road_component_group::RoadComponentGroup,
     road_marking::RoadMarking,
+    road_reference_line::RoadReferenceLine,
+    road_structure::RoadStructure,
 };
 
-/// Describes a road design,
+/// Describes a road design.
+///
+/// Lengths are always stored in meters, no matter which [LengthUnit](crate::utility::distance::LengthUnit) they're shown in.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RoadData {
     name: String,
     components: Vec<RoadComponent>,
-    /// RoadComponentPositions relative to the roads center.
+    /// RoadComponentPositions relative to the roads reference line.
     component_positions: Vec<RoadComponentPosition>,
     markings: Vec<RoadMarking>,
+    structures: Vec<RoadStructure>,
+    /// Groups of neighbouring components, sorted by their first component and never overlapping.
+    component_groups: Vec<RoadComponentGroup>,
+    /// The total width this road should have in meters, like the space between buildings.
+    ///
+    /// When set, the width that's left after the fixed components is divided among the flexible components.
+    target_width: Option<f32>,
+    reference_line: RoadReferenceLine,
 }
 
 impl RoadData {
     pub fn new(name: String, components: Vec<RoadComponent>, markings: Vec<RoadMarking>) -> Self {
-        let component_positions = calculate_road_component_positions(&components);
+        let component_positions =
+            calculate_road_component_positions(&components, RoadReferenceLine::default());
 
         Self {
             name,
             components,
             component_positions,
             markings,
+            structures: Vec::new(),
+            component_groups: Vec::new(),
+            target_width: None,
+            reference_line: RoadReferenceLine::default(),
         }
     }
 
@@ -70,11 +88,95 @@ impl RoadData {
         &mut self.markings
     }
 
-    #[expect(unused)]
     pub fn enumerate_markings(&self) -> Enumerate<Iter<RoadMarking>> {
         self.markings.iter().enumerate()
     }
 
+    pub fn structures(&self) -> &[RoadStructure] {
+        &self.structures
+    }
+
+    pub fn structures_mut(&mut self) -> &mut Vec<RoadStructure> {
+        &mut self.structures
+    }
+
+    pub fn component_groups(&self) -> &[RoadComponentGroup] {
+        &self.component_groups
+    }
+
+    pub fn component_groups_mut(&mut self) -> &mut Vec<RoadComponentGroup> {
+        &mut self.component_groups
+    }
+
+    /// Returns the index of the group that the component at `component_index` belongs to.
+    pub fn component_group_index_of(&self, component_index: usize) -> Option<usize> {
+        self.component_groups
+            .iter()
+            .position(|group| group.contains(component_index))
+    }
+
+    /// Returns the range of components that are edited together with the component at `component_index`.
+    ///
+    /// This is the range of its group, or only the component itself if it isn't grouped.
+    pub fn component_unit_range(&self, component_index: usize) -> Range<usize> {
+        match self.component_group_index_of(component_index) {
+            Some(group_index) => self.component_groups[group_index].component_range(),
+            None => component_index..(component_index + 1),
+        }
+    }
+
+    /// Returns false if swapping the 2 components would move one of them into or out of a group.
+    pub fn can_swap_components(
+        &self,
+        component_index: usize,
+        other_component_index: usize,
+    ) -> bool {
+        self.component_groups
+            .iter()
+            .all(|group| group.contains(component_index) == group.contains(other_component_index))
+    }
+
+    /// Shrinks the group of the removed component and moves all later groups back by one.
+    ///
+    /// Groups that are left without components are removed.
+    pub fn remove_component_from_groups(&mut self, component_index: usize) {
+        for group in self.component_groups.iter_mut() {
+            if group.contains(component_index) {
+                group.component_count -= 1;
+            } else if group.first_index > component_index {
+                group.first_index -= 1;
+            }
+        }
+
+        self.component_groups
+            .retain(|group| group.component_count > 0);
+    }
+
+    /// Keeps a reference line on a component boundary at the same component when a component before it is removed.
+    pub fn remove_component_from_reference_line(&mut self, component_index: usize) {
+        if let RoadReferenceLine::ComponentBoundary(boundary_index) = &mut self.reference_line {
+            if component_index < *boundary_index {
+                *boundary_index -= 1;
+            }
+        }
+    }
+
+    /// Keeps a reference line on the boundary between 2 neighbouring components at the component on its left when they are swapped.
+    pub fn swap_components_in_reference_line(
+        &mut self,
+        component_index: usize,
+        other_component_index: usize,
+    ) {
+        let left_index = component_index.min(other_component_index);
+        let right_index = component_index.max(other_component_index);
+
+        if let RoadReferenceLine::ComponentBoundary(boundary_index) = &mut self.reference_line {
+            if right_index == left_index + 1 && *boundary_index == right_index {
+                *boundary_index = right_index + 1;
+            }
+        }
+    }
+
     pub fn total_size(&self) -> Vec2 {
         Vec2::new(self.total_width(), self.total_height())
     }
@@ -87,6 +189,63 @@ impl RoadData {
         self.total_width() / 2.0
     }
 
+    /// Returns the position of the road's left edge, relative to its reference line.
+    pub fn left_edge(&self) -> f32 {
+        -self
+            .reference_line
+            .distance_from_left_edge(&self.components)
+    }
+
+    /// Returns the position of the road's right edge, relative to its reference line.
+    pub fn right_edge(&self) -> f32 {
+        self.left_edge() + self.total_width()
+    }
+
+    /// Returns the position of the middle of the road's total width, relative to its reference line.
+    pub fn center(&self) -> f32 {
+        self.left_edge() + self.half_width()
+    }
+
+    pub fn reference_line(&self) -> RoadReferenceLine {
+        self.reference_line
+    }
+
+    /// Sets the reference line and moves the components to be relative to it.
+    ///
+    /// The markings aren't moved, see [ActiveRoad::update_road_marking_positions](super::active_road::ActiveRoad::update_road_marking_positions).
+    pub fn set_reference_line(&mut self, reference_line: RoadReferenceLine) {
+        self.reference_line = reference_line;
+        self.recalculate_road_component_positions();
+    }
+
+    pub fn target_width(&self) -> Option<f32> {
+        self.target_width
+    }
+
+    /// Sets the target width and divides it among the flexible components.
+    pub fn set_target_width(&mut self, target_width: Option<f32>) {
+        self.target_width = target_width;
+        self.recalculate_road_component_positions();
+    }
+
+    /// Returns how much of the target width isn't used by the components, negative if they're wider than the target.
+    ///
+    /// This is only non zero when the flexible components can't fill the target width within their width limits.
+    pub fn remaining_width(&self) -> Option<f32> {
+        self.target_width
+            .map(|target_width| target_width - self.total_width())
+    }
+
+    /// Returns the number of components with a rail track, which are counted apart from the other lanes.
+    ///
+    /// Tracks that don't fit within their component aren't built, so they aren't counted either.
+    pub fn rail_track_count(&self) -> usize {
+        self.components
+            .iter()
+            .filter(|component| component.fitting_track().is_some())
+            .count()
+    }
+
     pub fn total_height(&self) -> f32 {
         self.components
             .iter()
@@ -94,12 +253,17 @@ impl RoadData {
     }
 
     pub fn recalculate_road_component_positions(&mut self) {
-        self.component_positions = calculate_road_component_positions(&self.components);
+        if let Some(target_width) = self.target_width {
+            distribute_target_width(&mut self.components, target_width);
+        }
+
+        self.component_positions =
+            calculate_road_component_positions(&self.components, self.reference_line);
     }
 
     /// Returns information about the road component under the given `point`.
     ///
-    /// - `point` is the horizontal position on the road, relative to the roads center.
+    /// - `point` is the horizontal position on the road, relative to the roads reference line.
     pub fn find_road_component_under_point(&self, point: f32) -> Option<RoadComponentUnderPoint> {
         self.components
             .iter()
@@ -130,6 +294,10 @@ impl Default for RoadData {
             components: Vec::new(),
             component_positions: Vec::new(),
             markings: Vec::new(),
+            structures: Vec::new(),
+            component_groups: Vec::new(),
+            target_width: None,
+            reference_line: RoadReferenceLine::default(),
         }
     }
 }
@@ -142,17 +310,18 @@ pub struct RoadComponentUnderPoint {
     pub closest_position_field: RoadComponentPositionField,
 }
 
-/// Returns a vec of RoadComponentPositions that holds each given road component's position relative to the roads center.
+/// Returns a vec of RoadComponentPositions that holds each given road component's position relative to the roads reference line.
 fn calculate_road_component_positions(
     road_components: &[RoadComponent],
+    reference_line: RoadReferenceLine,
 ) -> Vec<RoadComponentPosition> {
-    let half_width = calculate_width_of_road_components(road_components) / 2.0;
+    let reference_line_distance = reference_line.distance_from_left_edge(road_components);
 
     let mut width_of_previous_components = 0.0;
     let mut result = Vec::new();
 
     for component in road_components {
-        let left_position = width_of_previous_components - half_width;
+        let left_position = width_of_previous_components - reference_line_distance;
         let center_position = left_position + component.half_width();
         let right_position = left_position + component.width();
 
@@ -168,6 +337,68 @@ fn calculate_road_component_positions(
     result
 }
 
+/// Sets the width of the flexible components so all components together are as wide as the target width.
+///
+/// The width left after the fixed components is divided by flex weight, components that would go past their
+/// width limits are held at the limit and the rest is divided again among the others, like in a CSS flexbox.
+fn distribute_target_width(road_components: &mut [RoadComponent], target_width: f32) {
+    let mut unfrozen_indices: Vec<usize> = road_components
+        .iter()
+        .enumerate()
+        .filter(|(_, component)| component.is_flexible())
+        .map(|(index, _)| index)
+        .collect();
+
+    while !unfrozen_indices.is_empty() {
+        let frozen_width: f32 = road_components
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| !unfrozen_indices.contains(index))
+            .map(|(_, component)| component.width())
+            .sum();
+        let available_width = (target_width - frozen_width).max(0.0);
+        let total_flex: f32 = unfrozen_indices
+            .iter()
+            .map(|index| road_components[*index].flex)
+            .sum();
+
+        let mut total_violation = 0.0;
+        let proposed_widths: Vec<(usize, f32, f32)> = unfrozen_indices
+            .iter()
+            .map(|index| {
+                let component = &road_components[*index];
+                let proposed_width = available_width * component.flex / total_flex;
+                let clamped_width = component.clamp_width(proposed_width);
+
+                total_violation += clamped_width - proposed_width;
+
+                (*index, proposed_width, clamped_width)
+            })
+            .collect();
+
+        for (index, _, clamped_width) in proposed_widths.iter() {
+            road_components[*index].size.x = *clamped_width;
+        }
+
+        // Freezes the components that hold back the most, the others are divided again in the next pass
+        let frozen_indices: Vec<usize> = proposed_widths
+            .iter()
+            .filter(|(_, proposed_width, clamped_width)| {
+                if total_violation > 0.0 {
+                    clamped_width > proposed_width
+                } else if total_violation < 0.0 {
+                    clamped_width < proposed_width
+                } else {
+                    true
+                }
+            })
+            .map(|(index, _, _)| *index)
+            .collect();
+
+        unfrozen_indices.retain(|index| !frozen_indices.contains(index));
+    }
+}
+
 fn calculate_width_of_road_components(road_components: &[RoadComponent]) -> f32 {
     road_components
         .iter()
@@ -200,3 +431,167 @@ fn get_closest_road_component_position_field(
         .unwrap()
         .1
 }
+
+/// A lane with a raised sidewalk on its right, used by the tests that check the shape of a road.
+#[cfg(test)]
+pub fn road_with_raised_sidewalk() -> RoadData {
+    use bevy::color::palettes::tailwind::{GRAY_600, GRAY_700};
+
+    RoadData::new(
+        String::from("Test road"),
+        vec![
+            RoadComponent::new("Lane", Vec2::new(4.0, 0.2), GRAY_700),
+            RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.4), GRAY_600),
+        ],
+        Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::color::palettes::tailwind::*;
+
+    use crate::road::road_component::rail_track::RailTrack;
+
+    use super::*;
+
+    fn road_with_target_width(components: Vec<RoadComponent>, target_width: f32) -> RoadData {
+        let mut road_data =
+            RoadData::new(String::from("Target width road"), components, Vec::new());
+        road_data.set_target_width(Some(target_width));
+        road_data
+    }
+
+    fn component_widths(road_data: &RoadData) -> Vec<f32> {
+        road_data
+            .components()
+            .iter()
+            .map(RoadComponent::width)
+            .collect()
+    }
+
+    #[test]
+    fn flexible_components_fill_the_target_width() {
+        let road_data = road_with_target_width(
+            vec![
+                RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), GRAY_600),
+                RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800).with_flex(1.0),
+                RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800).with_flex(3.0),
+                RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), GRAY_600),
+            ],
+            20.0,
+        );
+
+        assert_eq!(component_widths(&road_data), [2.0, 4.0, 12.0, 2.0]);
+        assert_eq!(road_data.remaining_width(), Some(0.0));
+    }
+
+    #[test]
+    fn width_limits_pass_the_rest_to_other_flexible_components() {
+        let road_data = road_with_target_width(
+            vec![
+                RoadComponent::new("Verge", Vec2::new(1.0, 0.1), GREEN_700)
+                    .with_flex(1.0)
+                    .with_max_width(Some(2.0)),
+                RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800)
+                    .with_flex(1.0)
+                    .with_min_width(Some(3.0)),
+            ],
+            12.0,
+        );
+
+        assert_eq!(component_widths(&road_data), [2.0, 10.0]);
+    }
+
+    #[test]
+    fn remaining_width_shows_what_the_components_cant_fill() {
+        let mut road_data = road_with_target_width(
+            vec![
+                RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), GRAY_600),
+                RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800)
+                    .with_flex(1.0)
+                    .with_max_width(Some(4.0)),
+            ],
+            10.0,
+        );
+
+        assert_eq!(component_widths(&road_data), [2.0, 4.0]);
+        assert_eq!(road_data.remaining_width(), Some(4.0));
+
+        // Changing a fixed component is balanced out by the flexible one
+        road_data.components_mut()[0].size.x = 7.0;
+        road_data.set_target_width(Some(9.0));
+
+        assert_eq!(component_widths(&road_data), [7.0, 2.0]);
+        assert_eq!(road_data.remaining_width(), Some(0.0));
+    }
+
+    #[test]
+    fn component_positions_are_relative_to_the_reference_line() {
+        let mut road_data = RoadData::new(
+            String::from("One-way road"),
+            vec![
+                RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), GRAY_600),
+                RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800),
+                RoadComponent::new("Verge", Vec2::new(1.0, 0.1), GREEN_700),
+            ],
+            Vec::new(),
+        );
+
+        road_data.set_reference_line(RoadReferenceLine::ComponentBoundary(1));
+
+        assert_eq!(road_data.component_positions()[1].left, 0.0);
+        assert_eq!((road_data.left_edge(), road_data.right_edge()), (-2.0, 4.0));
+        assert_eq!(road_data.center(), 1.0);
+
+        road_data.components_mut().remove(0);
+        road_data.remove_component_from_reference_line(0);
+        road_data.recalculate_road_component_positions();
+
+        assert_eq!(
+            road_data.reference_line(),
+            RoadReferenceLine::ComponentBoundary(0)
+        );
+        assert_eq!(road_data.left_edge(), 0.0);
+    }
+
+    #[test]
+    fn components_cant_be_swapped_across_a_group_boundary() {
+        let mut road_data = RoadData::new(
+            String::from("Grouped road"),
+            vec![
+                RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800),
+                RoadComponent::new("Curb", Vec2::new(0.2, 0.3), GRAY_400),
+                RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), GRAY_600),
+                RoadComponent::new("Verge", Vec2::new(1.0, 0.1), GREEN_700),
+            ],
+            Vec::new(),
+        );
+        road_data
+            .component_groups_mut()
+            .push(RoadComponentGroup::new("Sidewalk", 1, 2));
+
+        assert!(road_data.can_swap_components(1, 2));
+        // Moving the last grouped component out, or an outside component in
+        assert!(!road_data.can_swap_components(2, 3));
+        assert!(!road_data.can_swap_components(0, 1));
+        assert!(road_data.can_swap_components(3, 3));
+    }
+
+    #[test]
+    fn only_rail_tracks_that_fit_their_component_are_counted() {
+        let road_data = RoadData::new(
+            String::from("Tram road"),
+            vec![
+                RoadComponent::new("Tram lane", Vec2::new(3.0, 0.1), GRAY_800)
+                    .with_track(Some(RailTrack::new(1.435))),
+                RoadComponent::new("Narrow tram lane", Vec2::new(1.0, 0.1), GRAY_800)
+                    .with_track(Some(RailTrack::new(1.435))),
+                RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800),
+            ],
+            Vec::new(),
+        );
+
+        assert_eq!(road_data.rail_track_count(), 1);
+    }
+}
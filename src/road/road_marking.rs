@@ -20,8 +20,9 @@ impl Plugin for RoadMarkingPlugin {
 /// Flat markings like lane lines.
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RoadMarking {
-    /// This marking's horizontal position relative to the road center.
+    /// This marking's horizontal position relative to the road center, in meters.
     pub x_position: f32,
+    /// The width of this marking in meters.
     pub segment_width: f32,
     pub color: Color,
 }
@@ -35,19 +36,16 @@ impl RoadMarking {
         }
     }
 
-    #[expect(unused)]
     pub fn with_x_position(mut self, x_position: f32) -> Self {
         self.x_position = x_position;
         self
     }
 
-    #[expect(unused)]
     pub fn with_segment_width(mut self, segment_width: f32) -> Self {
         self.segment_width = segment_width;
         self
     }
 
-    #[expect(unused)]
     pub fn with_color(mut self, color: Color) -> Self {
         self.color = color;
         self
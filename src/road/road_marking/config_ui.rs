@@ -1,20 +1,34 @@
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::prelude::*;
 
 use crate::{
     game_modes::GameMode,
-    road::{active_road::ActiveRoad, road_data::RoadData},
+    road::{
+        active_road::{
+            active_road_events::road_marking_change::{
+                OnRoadMarkingChangeRequested, RoadMarkingChange, RoadMarkingFieldChange,
+            },
+            ActiveRoad,
+        },
+        road_data::RoadData,
+    },
     ui::{
         components::{
+            collapsible_section::CollapsibleSectionBuilder,
+            flexbox::FlexboxConfig,
             inputs::{
-                color_input::{ColorInputBuilder, ColorInputConfig},
+                color_input::{ColorInputBuilder, ColorInputConfig, OnColorInputValueChanged},
                 labeled_element::LabeledElementBuilder,
-                number_input::NumberInputBuilder,
+                number_input::{NumberInputBuilder, OnNumberInputValueChanged},
             },
-            section::{SectionBuilder, SectionConfig},
+            scroll_container::ScrollContainerBuilder,
+            section::SectionConfig,
+            tooltip::Tooltip,
             UiComponentBuilder, UiComponentWithChildrenBuilder,
         },
         sidebar::Sidebar,
+        theme::ThemeColor,
     },
+    utility::find_ancestor_of_entity,
     GameRunningSet,
 };
 
@@ -32,13 +46,26 @@ impl Plugin for RoadMarkingConfigUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (handle_show_config_requests, handle_hide_config_requests)
-                .in_set(GameRunningSet::HandleCommands)
+            (
+                (
+                    handle_number_input_changed_events,
+                    handle_color_input_changed_events,
+                )
+                    .in_set(GameRunningSet::SendCommands),
+                (handle_show_config_requests, handle_hide_config_requests)
+                    .in_set(GameRunningSet::HandleCommands),
+            )
                 .run_if(in_state(GameMode::RoadEditor)),
         );
     }
 }
 
+/// The config section of a single road marking, which holds the inputs for its fields.
+#[derive(Component)]
+struct RoadMarkingConfig {
+    road_marking_index: usize,
+}
+
 fn handle_show_config_requests(
     mut on_show: EventReader<OnShowRoadMarkingConfigRequested>,
     mut commands: Commands,
@@ -46,7 +73,7 @@ fn handle_show_config_requests(
     sidebar_query: Query<Entity, With<Sidebar>>,
     active_road: Res<ActiveRoad>,
 ) {
-    for _ in on_show.read() {
+    for event in on_show.read() {
         let sidebar = sidebar_query.single();
         let road_markings = active_road.road_data().markings();
         let road_data = active_road.road_data();
@@ -55,66 +82,147 @@ fn handle_show_config_requests(
             .entity(sidebar)
             .despawn_descendants()
             .with_children(|sidebar| {
-                sidebar
-                    .spawn(build_scrollable_list_node())
-                    .with_children(|config_container| {
-                        for road_marking in road_markings {
-                            spawn_road_marking_config(
-                                config_container,
-                                road_marking,
-                                road_data,
-                                &mut images,
-                            );
-                        }
-                    });
+                ScrollContainerBuilder::new(
+                    FlexboxConfig::horizontally_centered_column().with_px_gap(20.0),
+                )
+                .spawn(sidebar, (), |config_container| {
+                    for (index, road_marking) in road_markings.iter().enumerate() {
+                        spawn_road_marking_config(
+                            config_container,
+                            index,
+                            road_marking,
+                            road_data,
+                            index == event.expanded_marking_index(),
+                            &mut images,
+                        );
+                    }
+                });
             });
     }
 }
 
 fn spawn_road_marking_config(
     builder: &mut ChildBuilder,
+    index: usize,
     road_marking: &RoadMarking,
     road_data: &RoadData,
+    is_expanded: bool,
     images: &mut Assets<Image>,
 ) {
-    let x_position_value_range = -road_data.half_width()..road_data.half_width();
+    let x_position_value_range = road_data.left_edge()..road_data.right_edge();
 
-    // TODO: make section foldable, so config options are only shown for desired items
-    SectionBuilder::new(SectionConfig::default().with_background_color(NEUTRAL_600)).spawn(
+    CollapsibleSectionBuilder::new(
+        SectionConfig::default().with_background_color(ThemeColor::RaisedSurface),
+        format!("Marking {}", index + 1),
+    )
+    .with_expanded(is_expanded)
+    .spawn(
         builder,
-        (),
+        RoadMarkingConfig {
+            road_marking_index: index,
+        },
         |section| {
             LabeledElementBuilder::centered_top_label("X position").spawn(
                 section,
-                (),
+                Tooltip::new("The distance from the reference line of the road to this marking"),
                 |position_label| {
                     NumberInputBuilder::default()
                         .with_values(road_marking.x_position, x_position_value_range)
+                        .as_length()
                         .spawn(position_label, RoadMarkingField::XPosition);
                 },
             );
 
             LabeledElementBuilder::centered_top_label("Segment width").spawn(
                 section,
-                (),
+                Tooltip::new("The width of this marking"),
                 |width_label| {
                     NumberInputBuilder::default()
                         .with_values(road_marking.segment_width, 0.0..1.0)
+                        .as_length()
                         .spawn(width_label, RoadMarkingField::SegmentWidth);
                 },
             );
 
-            LabeledElementBuilder::centered_top_label("Color").spawn(section, (), |color_label| {
-                ColorInputBuilder::new(
-                    ColorInputConfig::default().with_start_color(road_marking.color),
-                    images,
-                )
-                .spawn(color_label, RoadMarkingField::Color);
-            });
+            LabeledElementBuilder::centered_top_label("Color").spawn(
+                section,
+                Tooltip::new("The paint color of this marking"),
+                |color_label| {
+                    ColorInputBuilder::new(
+                        ColorInputConfig::default().with_start_color(road_marking.color),
+                        images,
+                    )
+                    .spawn(color_label, RoadMarkingField::Color);
+                },
+            );
         },
     );
 }
 
+fn handle_number_input_changed_events(
+    mut on_input_changed: EventReader<OnNumberInputValueChanged>,
+    mut on_change_request: EventWriter<OnRoadMarkingChangeRequested>,
+    number_input_query: Query<&RoadMarkingField>,
+    marking_config_query: Query<(Entity, &RoadMarkingConfig)>,
+    parent_query: Query<&Parent>,
+) {
+    for event in on_input_changed.read() {
+        let Ok(field) = number_input_query.get(event.number_input_entity()) else {
+            continue;
+        };
+
+        let field_to_change = match field {
+            RoadMarkingField::XPosition => RoadMarkingFieldChange::XPosition(event.new_value()),
+            RoadMarkingField::SegmentWidth => {
+                RoadMarkingFieldChange::SegmentWidth(event.new_value())
+            }
+            RoadMarkingField::Color => continue,
+        };
+
+        let Some((_, marking_config)) = find_ancestor_of_entity(
+            event.number_input_entity(),
+            &marking_config_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        on_change_request.send(OnRoadMarkingChangeRequested::new(RoadMarkingChange::new(
+            field_to_change,
+            marking_config.road_marking_index,
+        )));
+    }
+}
+
+fn handle_color_input_changed_events(
+    mut on_input_changed: EventReader<OnColorInputValueChanged>,
+    mut on_change_request: EventWriter<OnRoadMarkingChangeRequested>,
+    color_input_query: Query<&RoadMarkingField>,
+    marking_config_query: Query<(Entity, &RoadMarkingConfig)>,
+    parent_query: Query<&Parent>,
+) {
+    for event in on_input_changed.read() {
+        if color_input_query.get(event.color_input_entity()) != Ok(&RoadMarkingField::Color) {
+            continue;
+        }
+
+        let Some((_, marking_config)) = find_ancestor_of_entity(
+            event.color_input_entity(),
+            &marking_config_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        on_change_request.send(OnRoadMarkingChangeRequested::new(RoadMarkingChange::new(
+            RoadMarkingFieldChange::Color(event.new_color()),
+            marking_config.road_marking_index,
+        )));
+    }
+}
+
 fn handle_hide_config_requests(
     mut on_hide: EventReader<OnHideRoadMarkingConfigRequested>,
     mut commands: Commands,
@@ -126,16 +234,3 @@ fn handle_hide_config_requests(
         commands.entity(sidebar).despawn_descendants();
     }
 }
-
-// TODO: wanted to make the list scrollable but overflow doesn't seem to work as I thought, delete fn if not gonna use anymore
-fn build_scrollable_list_node() -> impl Bundle {
-    NodeBundle {
-        style: Style {
-            flex_direction: FlexDirection::Column,
-            align_items: AlignItems::Center,
-            row_gap: Val::Px(20.0),
-            ..default()
-        },
-        ..default()
-    }
-}
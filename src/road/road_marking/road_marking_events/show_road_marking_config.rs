@@ -17,8 +17,23 @@ impl Plugin for ShowRoadMarkingConfigPlugin {
     }
 }
 
+/// Shows the config of all road markings in the sidebar, with only the given marking expanded.
 #[derive(Event, Clone, Copy)]
-pub struct OnShowRoadMarkingConfigRequested;
+pub struct OnShowRoadMarkingConfigRequested {
+    expanded_marking_index: usize,
+}
+
+impl OnShowRoadMarkingConfigRequested {
+    pub fn new(expanded_marking_index: usize) -> Self {
+        Self {
+            expanded_marking_index,
+        }
+    }
+
+    pub fn expanded_marking_index(&self) -> usize {
+        self.expanded_marking_index
+    }
+}
 
 fn send_request_on_button_pressed(
     mut on_pressed: EventReader<OnButtonPressed>,
@@ -28,6 +43,6 @@ fn send_request_on_button_pressed(
         .read()
         .filter(|event| event.is_action(ButtonAction::ShowRoadMarkingConfig))
     {
-        on_request.send(OnShowRoadMarkingConfigRequested);
+        on_request.send(OnShowRoadMarkingConfigRequested::new(0));
     }
 }
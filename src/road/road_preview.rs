@@ -1,6 +1,11 @@
-use bevy::prelude::*;
+use bevy::{ecs::system::SystemParam, prelude::*};
 
-use crate::{game_modes::GameMode, GameRunningSet};
+use crate::{
+    game_modes::GameMode,
+    settings::{OnSettingChanged, SettingChange, Settings},
+    utility::circular_arc::CircularArc,
+    GameRunningSet,
+};
 
 use super::{
     active_road::{
@@ -8,7 +13,12 @@ use super::{
             new_road_component::OnRoadComponentAdded,
             road_component_change::OnRoadComponentChanged,
             road_component_deletion::OnRoadComponentDeleted,
-            road_component_reorder::OnRoadComponentReordered, OnActiveRoadSet,
+            road_component_group_change::OnRoadComponentGroupChanged,
+            road_component_reorder::OnRoadComponentReordered,
+            road_marking_change::OnRoadMarkingChanged,
+            road_reference_line_change::OnRoadReferenceLineChanged,
+            road_structure_change::OnRoadStructureChanged,
+            road_target_width_change::OnRoadTargetWidthChanged, OnActiveRoadSet,
         },
         ActiveRoad,
     },
@@ -18,25 +28,26 @@ use super::{
     road_section::{RequestedRoadSection, RequestedRoadSectionEnd, RoadSectionVariant},
 };
 
-const PREVIEW_ROAD_LENGTH: f32 = 20.0;
+/// The number of points that lines along a curved preview are drawn with.
+const CURVED_PATH_POINT_COUNT: usize = 48;
 
 pub struct RoadPreviewPlugin;
 
 impl Plugin for RoadPreviewPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.init_resource::<PreviewPath>().add_systems(
             Update,
             (
-                redraw_preview_on_active_road_set,
-                spawn_preview_on_active_road_set,
-                redraw_preview_on_road_component_added,
-                redraw_preview_on_road_component_changed,
-                redraw_preview_on_road_component_reordered,
-                redraw_preview_on_road_component_deleted,
+                update_preview_path_on_settings_changed,
+                (
+                    spawn_preview_on_active_road_set,
+                    rebuild_preview_on_design_changed,
+                )
+                    .chain()
+                    .run_if(in_state(GameMode::RoadEditor)),
             )
                 .chain()
-                .in_set(GameRunningSet::UpdateEntities)
-                .run_if(in_state(GameMode::RoadEditor)),
+                .in_set(GameRunningSet::UpdateEntities),
         );
     }
 }
@@ -44,6 +55,177 @@ impl Plugin for RoadPreviewPlugin {
 #[derive(Component)]
 pub struct RoadPreview;
 
+/// The path that the road preview follows, a straight line or a circular arc.
+///
+/// - Follows the preview settings.
+/// - The middle of the path is at the world origin, where the path runs along the z-axis.
+/// - Offsets are the x positions on the road's cross-section, so positive offsets are on the right side of the road.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub enum PreviewPath {
+    Straight { length: f32 },
+    Curved(CircularArc),
+}
+
+impl Default for PreviewPath {
+    fn default() -> Self {
+        Self::from_settings(&Settings::default())
+    }
+}
+
+impl PreviewPath {
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self::new(
+            settings.preview_road_length,
+            settings.preview_curve_angle.to_radians(),
+        )
+    }
+
+    /// Returns a path with the given length, that curves by the given angle in radians.
+    pub fn new(length: f32, curve_angle: f32) -> Self {
+        if curve_angle == 0.0 {
+            return Self::Straight { length };
+        }
+
+        let radius = length / curve_angle.abs();
+        let position_after_angle = |angle: f32| {
+            Vec3::new(
+                radius * (1.0 - angle.cos()) * curve_angle.signum(),
+                0.0,
+                -radius * angle.sin(),
+            )
+        };
+
+        let middle_position = position_after_angle(curve_angle.abs() / 2.0);
+        let start_position = -middle_position;
+        let end_position = position_after_angle(curve_angle.abs()) - middle_position;
+
+        let inwards_start_transform =
+            Transform::from_translation(start_position).looking_to(Dir3::NEG_Z, Dir3::Y);
+
+        match CircularArc::from_start_transform(inwards_start_transform, end_position) {
+            Some(circular_arc) => Self::Curved(circular_arc),
+            None => Self::Straight { length },
+        }
+    }
+
+    /// Returns the section that the road builder builds the preview from.
+    pub fn requested_section(&self) -> RequestedRoadSection {
+        let variant = match self {
+            PreviewPath::Straight { .. } => RoadSectionVariant::Straight,
+            PreviewPath::Curved(circular_arc) => RoadSectionVariant::Curved(*circular_arc),
+        };
+
+        RequestedRoadSection {
+            ends: [
+                RequestedRoadSectionEnd {
+                    road_node: RequestedRoadNode::new(self.point_at(0.0, 0.0, 0.0), None),
+                    direction: Dir3::new(-self.direction_at(0.0)).unwrap_or(Dir3::Z),
+                },
+                RequestedRoadSectionEnd {
+                    road_node: RequestedRoadNode::new(self.point_at(1.0, 0.0, 0.0), None),
+                    direction: Dir3::new(self.direction_at(1.0)).unwrap_or(Dir3::NEG_Z),
+                },
+            ],
+            variant,
+        }
+    }
+
+    /// Returns the point at the fraction along the path, with the given offset and height.
+    pub fn point_at(&self, fraction: f32, offset: f32, height: f32) -> Vec3 {
+        match self {
+            PreviewPath::Straight { length } => {
+                Vec3::new(offset, height, length / 2.0 - length * fraction)
+            }
+            PreviewPath::Curved(circular_arc) => {
+                let radial_direction = circular_arc
+                    .position_along_radius(circular_arc.lerp_angle(fraction))
+                    .normalize();
+                let radius = circular_arc.radius - offset * self.center_side();
+
+                circular_arc.position + radial_direction * radius + Vec3::Y * height
+            }
+        }
+    }
+
+    /// Returns the direction that the path goes towards at the fraction along it.
+    pub fn direction_at(&self, fraction: f32) -> Vec3 {
+        const STEP: f32 = 0.001;
+
+        let start = self.point_at((fraction - STEP).max(0.0), 0.0, 0.0);
+        let end = self.point_at((fraction + STEP).min(1.0), 0.0, 0.0);
+
+        (end - start).normalize_or_zero()
+    }
+
+    /// Returns the points along the whole path with the given offset and height, to draw lines along the preview.
+    pub fn points_at_offset(&self, offset: f32, height: f32) -> Vec<Vec3> {
+        let point_count = match self {
+            PreviewPath::Straight { .. } => 2,
+            PreviewPath::Curved(_) => CURVED_PATH_POINT_COUNT,
+        };
+
+        (0..point_count)
+            .map(|index| {
+                let fraction = index as f32 / (point_count - 1) as f32;
+
+                self.point_at(fraction, offset, height)
+            })
+            .collect()
+    }
+
+    /// Returns the offset of the point from the middle of the road, ignoring how far along the path it is.
+    pub fn offset_of_point(&self, point: Vec3) -> f32 {
+        match self {
+            PreviewPath::Straight { .. } => point.x,
+            PreviewPath::Curved(circular_arc) => {
+                let distance_to_center = (point - circular_arc.position).xz().length();
+
+                (circular_arc.radius - distance_to_center) * self.center_side()
+            }
+        }
+    }
+
+    /// Returns true if the point is between the start and end of the path.
+    pub fn is_along_path(&self, point: Vec3) -> bool {
+        match self {
+            PreviewPath::Straight { length } => point.z.abs() <= length / 2.0,
+            PreviewPath::Curved(circular_arc) => {
+                let middle_direction = (self.point_at(0.5, 0.0, 0.0) - circular_arc.position).xz();
+                let point_direction = (point - circular_arc.position).xz();
+
+                middle_direction.angle_between(point_direction).abs()
+                    <= circular_arc.delta_angle.abs() / 2.0
+            }
+        }
+    }
+
+    /// Returns 1 if the center of the curve is on the right side of the road, and -1 if it's on the left side.
+    fn center_side(&self) -> f32 {
+        let PreviewPath::Curved(circular_arc) = self else {
+            return 1.0;
+        };
+
+        let start_position = circular_arc.position_along_radius(circular_arc.start_angle);
+        let start_direction =
+            circular_arc.position_along_radius(circular_arc.lerp_angle(0.001)) - start_position;
+        let right_direction = start_direction.cross(Vec3::Y);
+
+        match right_direction.dot(-start_position) >= 0.0 {
+            true => 1.0,
+            false => -1.0,
+        }
+    }
+}
+
+fn update_preview_path_on_settings_changed(
+    mut preview_path: ResMut<PreviewPath>,
+    settings: Res<Settings>,
+) {
+    if settings.is_changed() {
+        preview_path.set_if_neq(PreviewPath::from_settings(&settings));
+    }
+}
+
 /// Spawns in road preview in case it didn't already exists
 fn spawn_preview_on_active_road_set(
     mut on_set: EventReader<OnActiveRoadSet>,
@@ -52,15 +234,20 @@ fn spawn_preview_on_active_road_set(
     mut images: ResMut<Assets<Image>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut active_road: ResMut<ActiveRoad>,
-    road_preview_query: Query<&RoadPreview>,
+    preview_path: Res<PreviewPath>,
 ) {
-    if !road_preview_query.is_empty() {
+    if active_road.road_preview_entity().is_some() {
+        on_set.clear();
         return;
     }
 
     for event in on_set.read() {
-        let (road_mesh, road_texture_image) =
-            create_road_mesh_and_texture(&mut meshes, &mut images, event.road_data());
+        let (road_mesh, road_texture_image) = create_road_mesh_and_texture(
+            &mut meshes,
+            &mut images,
+            event.road_data(),
+            &preview_path,
+        );
 
         let road_material = create_road_preview_material(&mut materials, road_texture_image);
         let road_preview_entity = commands
@@ -71,154 +258,100 @@ fn spawn_preview_on_active_road_set(
     }
 }
 
-/// Redraws road preview in case it already exists
-fn redraw_preview_on_active_road_set(
-    mut on_set: EventReader<OnActiveRoadSet>,
-    mut road_preview_query: Query<
-        (&mut Handle<Mesh>, &Handle<StandardMaterial>),
-        With<RoadPreview>,
-    >,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut images: ResMut<Assets<Image>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    for event in on_set.read() {
-        let Ok((mut preview_mesh, preview_material)) = road_preview_query.get_single_mut() else {
-            continue;
-        };
-
-        redraw_road_preview(
-            create_road_mesh_and_texture(&mut meshes, &mut images, event.road_data()),
-            &mut preview_mesh,
-            materials.get_mut(preview_material).unwrap(),
-        );
-    }
+/// The events after which the road preview looks different.
+#[derive(SystemParam)]
+struct PreviewRebuildTriggers<'w, 's> {
+    on_set: EventReader<'w, 's, OnActiveRoadSet>,
+    on_component_added: EventReader<'w, 's, OnRoadComponentAdded>,
+    on_component_changed: EventReader<'w, 's, OnRoadComponentChanged>,
+    on_component_reordered: EventReader<'w, 's, OnRoadComponentReordered>,
+    on_component_deleted: EventReader<'w, 's, OnRoadComponentDeleted>,
+    on_group_changed: EventReader<'w, 's, OnRoadComponentGroupChanged>,
+    on_marking_changed: EventReader<'w, 's, OnRoadMarkingChanged>,
+    on_target_width_changed: EventReader<'w, 's, OnRoadTargetWidthChanged>,
+    on_reference_line_changed: EventReader<'w, 's, OnRoadReferenceLineChanged>,
+    on_structure_changed: EventReader<'w, 's, OnRoadStructureChanged>,
+    on_setting_changed: EventReader<'w, 's, OnSettingChanged>,
 }
 
-fn redraw_preview_on_road_component_added(
-    mut on_added: EventReader<OnRoadComponentAdded>,
-    mut road_preview_query: Query<
-        (&mut Handle<Mesh>, &Handle<StandardMaterial>),
-        With<RoadPreview>,
-    >,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut images: ResMut<Assets<Image>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    for event in on_added.read() {
-        let (mut preview_mesh, preview_material) = road_preview_query
-            .get_single_mut()
-            .expect("This event should only be sent when a road preview already exists");
-
-        redraw_road_preview(
-            create_road_mesh_and_texture(&mut meshes, &mut images, event.new_road_data()),
-            &mut preview_mesh,
-            materials.get_mut(preview_material).unwrap(),
-        );
-    }
-}
+impl PreviewRebuildTriggers<'_, '_> {
+    /// Reads all triggering events, returns true if at least one was sent since the last read.
+    fn read_all(&mut self) -> bool {
+        let changed_path_settings = self
+            .on_setting_changed
+            .read()
+            .filter(|event| {
+                matches!(
+                    event.change(),
+                    SettingChange::PreviewRoadLength(_) | SettingChange::PreviewCurveAngle(_)
+                )
+            })
+            .count();
 
-fn redraw_preview_on_road_component_changed(
-    mut on_changed: EventReader<OnRoadComponentChanged>,
-    mut road_preview_query: Query<
-        (&mut Handle<Mesh>, &Handle<StandardMaterial>),
-        With<RoadPreview>,
-    >,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut images: ResMut<Assets<Image>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    for event in on_changed.read() {
-        let (mut preview_mesh, preview_material) = road_preview_query
-            .get_single_mut()
-            .expect("This event should only be sent when a road preview already exists");
-
-        redraw_road_preview(
-            create_road_mesh_and_texture(&mut meshes, &mut images, event.new_road_data()),
-            &mut preview_mesh,
-            materials.get_mut(preview_material).unwrap(),
-        );
-    }
-}
+        let trigger_counts = [
+            self.on_set.read().count(),
+            self.on_component_added.read().count(),
+            self.on_component_changed.read().count(),
+            self.on_component_reordered.read().count(),
+            self.on_component_deleted.read().count(),
+            self.on_group_changed.read().count(),
+            self.on_marking_changed.read().count(),
+            self.on_target_width_changed.read().count(),
+            self.on_reference_line_changed.read().count(),
+            self.on_structure_changed.read().count(),
+            changed_path_settings,
+        ];
 
-fn redraw_preview_on_road_component_reordered(
-    mut on_reordered: EventReader<OnRoadComponentReordered>,
-    mut road_preview_query: Query<
-        (&mut Handle<Mesh>, &Handle<StandardMaterial>),
-        With<RoadPreview>,
-    >,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut images: ResMut<Assets<Image>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
-) {
-    for event in on_reordered.read() {
-        let (mut preview_mesh, preview_material) = road_preview_query
-            .get_single_mut()
-            .expect("This event should only be sent when a road preview already exists");
-
-        redraw_road_preview(
-            create_road_mesh_and_texture(&mut meshes, &mut images, event.new_road_data()),
-            &mut preview_mesh,
-            materials.get_mut(preview_material).unwrap(),
-        );
+        trigger_counts.iter().any(|count| *count > 0)
     }
 }
 
-fn redraw_preview_on_road_component_deleted(
-    mut on_deleted: EventReader<OnRoadComponentDeleted>,
-    mut road_preview_query: Query<
-        (&mut Handle<Mesh>, &Handle<StandardMaterial>),
-        With<RoadPreview>,
-    >,
+/// Rebuilds the existing road preview from the active road, at most once per frame however many changes were made.
+///
+/// The new mesh and texture replace the contents of the preview's assets, so no replaced assets are left behind.
+fn rebuild_preview_on_design_changed(
+    mut triggers: PreviewRebuildTriggers,
     mut meshes: ResMut<Assets<Mesh>>,
     mut images: ResMut<Assets<Image>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    road_preview_query: Query<(&Handle<Mesh>, &Handle<StandardMaterial>), With<RoadPreview>>,
+    active_road: Option<Res<ActiveRoad>>,
+    preview_path: Res<PreviewPath>,
 ) {
-    for event in on_deleted.read() {
-        let (mut preview_mesh, preview_material) = road_preview_query
-            .get_single_mut()
-            .expect("This event should only be sent when a road preview already exists");
-
-        redraw_road_preview(
-            create_road_mesh_and_texture(&mut meshes, &mut images, event.new_road_data()),
-            &mut preview_mesh,
-            materials.get_mut(preview_material).unwrap(),
-        );
+    if !triggers.read_all() {
+        return;
     }
-}
 
-fn redraw_road_preview(
-    road_mesh_and_texture: (Handle<Mesh>, Handle<Image>),
-    preview_mesh: &mut Handle<Mesh>,
-    road_material: &mut StandardMaterial,
-) {
-    let (road_mesh, road_texture_image) = road_mesh_and_texture;
+    // The preview only exists once a road is set
+    let (Some(active_road), Ok((preview_mesh, preview_material))) =
+        (&active_road, road_preview_query.get_single())
+    else {
+        return;
+    };
+
+    let road_builder = build_road_preview(active_road.road_data(), &preview_path);
 
-    *preview_mesh = road_mesh;
-    road_material.base_color_texture = Some(road_texture_image);
+    if let Some(mesh) = meshes.get_mut(preview_mesh) {
+        *mesh = road_builder.get_mesh();
+    }
+
+    // Getting the material mutably also marks it as changed, so it picks up the new texture
+    if let Some(texture_image) = materials
+        .get_mut(preview_material)
+        .and_then(|material| material.base_color_texture.as_ref())
+        .and_then(|texture_handle| images.get_mut(texture_handle))
+    {
+        *texture_image = road_builder.get_texture_image();
+    }
 }
 
 fn create_road_mesh_and_texture(
     meshes: &mut Assets<Mesh>,
     images: &mut Assets<Image>,
     road_data: &RoadData,
+    preview_path: &PreviewPath,
 ) -> (Handle<Mesh>, Handle<Image>) {
-    let requested_section = RequestedRoadSection {
-        ends: [
-            RequestedRoadSectionEnd {
-                road_node: RequestedRoadNode::new(Vec3::Z * PREVIEW_ROAD_LENGTH / 2.0, None),
-                direction: Dir3::Z,
-            },
-            RequestedRoadSectionEnd {
-                road_node: RequestedRoadNode::new(Vec3::NEG_Z * PREVIEW_ROAD_LENGTH / 2.0, None),
-                direction: Dir3::NEG_Z,
-            },
-        ],
-        variant: RoadSectionVariant::Straight,
-    };
-
-    let mut road_builder = RoadBuilder::new();
-    road_builder.build_from_requested_section(&requested_section, road_data);
+    let road_builder = build_road_preview(road_data, preview_path);
 
     let road_mesh = meshes.add(road_builder.get_mesh());
     let road_texture_image = images.add(road_builder.get_texture_image());
@@ -226,6 +359,13 @@ fn create_road_mesh_and_texture(
     (road_mesh, road_texture_image)
 }
 
+fn build_road_preview(road_data: &RoadData, preview_path: &PreviewPath) -> RoadBuilder {
+    let mut road_builder = RoadBuilder::new();
+    road_builder.build_from_requested_section(&preview_path.requested_section(), road_data);
+
+    road_builder
+}
+
 fn create_road_preview_material(
     materials: &mut Assets<StandardMaterial>,
     texture_image: Handle<Image>,
@@ -250,3 +390,152 @@ fn build_road_preview_bundle(
         },
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use bevy::{color::palettes::tailwind::GRAY_700, render::mesh::VertexAttributeValues};
+
+    use crate::road::{road_component::RoadComponent, road_data::road_with_raised_sidewalk};
+
+    use super::*;
+
+    #[test]
+    fn rebuilding_the_preview_keeps_the_asset_counts() {
+        let mut app = App::new();
+        app.init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<Image>>()
+            .init_resource::<Assets<StandardMaterial>>()
+            .init_resource::<PreviewPath>()
+            .insert_resource(ActiveRoad::new(road_with_raised_sidewalk()))
+            .add_event::<OnActiveRoadSet>()
+            .add_event::<OnRoadComponentAdded>()
+            .add_event::<OnRoadComponentChanged>()
+            .add_event::<OnRoadComponentReordered>()
+            .add_event::<OnRoadComponentDeleted>()
+            .add_event::<OnRoadComponentGroupChanged>()
+            .add_event::<OnRoadMarkingChanged>()
+            .add_event::<OnRoadTargetWidthChanged>()
+            .add_event::<OnRoadReferenceLineChanged>()
+            .add_event::<OnRoadStructureChanged>()
+            .add_event::<OnSettingChanged>()
+            .add_systems(
+                Update,
+                (
+                    spawn_preview_on_active_road_set,
+                    rebuild_preview_on_design_changed,
+                )
+                    .chain(),
+            );
+
+        app.world_mut()
+            .send_event(OnActiveRoadSet::new(road_with_raised_sidewalk()));
+        app.update();
+
+        let asset_counts = |app: &App| {
+            (
+                app.world().resource::<Assets<Mesh>>().len(),
+                app.world().resource::<Assets<Image>>().len(),
+                app.world().resource::<Assets<StandardMaterial>>().len(),
+            )
+        };
+
+        assert_eq!(asset_counts(&app), (1, 1, 1));
+
+        for edit in 0..50 {
+            *app.world_mut().resource_mut::<PreviewPath>() =
+                PreviewPath::new(20.0, edit as f32 / 50.0);
+
+            app.world_mut()
+                .send_event(OnActiveRoadSet::new(road_with_raised_sidewalk()));
+            app.world_mut()
+                .send_event(OnSettingChanged::new(SettingChange::PreviewCurveAngle(
+                    edit as f32,
+                )));
+            app.update();
+
+            assert_eq!(asset_counts(&app), (1, 1, 1));
+        }
+
+        // The last edit adds a component, which the rebuilt mesh has to include
+        app.world_mut()
+            .resource_mut::<ActiveRoad>()
+            .add_road_component(RoadComponent::new("Lane", Vec2::new(3.0, 0.2), GRAY_700));
+        app.world_mut()
+            .send_event(OnActiveRoadSet::new(road_with_raised_sidewalk()));
+        app.update();
+
+        assert_eq!(asset_counts(&app), (1, 1, 1));
+
+        let mesh_positions = |mesh: &Mesh| match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+            Some(VertexAttributeValues::Float32x3(positions)) => positions.clone(),
+            _ => panic!("The road mesh should have positions"),
+        };
+
+        let preview_path = app.world().resource::<PreviewPath>();
+        let edited_mesh = build_road_preview(
+            app.world().resource::<ActiveRoad>().road_data(),
+            preview_path,
+        )
+        .get_mesh();
+        let unedited_mesh =
+            build_road_preview(&road_with_raised_sidewalk(), preview_path).get_mesh();
+
+        let (_, preview_mesh) = app
+            .world()
+            .resource::<Assets<Mesh>>()
+            .iter()
+            .next()
+            .unwrap();
+
+        assert_eq!(mesh_positions(preview_mesh), mesh_positions(&edited_mesh));
+        assert_ne!(mesh_positions(preview_mesh), mesh_positions(&unedited_mesh));
+    }
+
+    #[test]
+    fn curved_path_keeps_its_length_and_is_centered() {
+        let preview_path = PreviewPath::new(20.0, FRAC_PI_2);
+
+        let PreviewPath::Curved(circular_arc) = preview_path else {
+            panic!("A preview with a curve angle should be curved");
+        };
+
+        assert!((circular_arc.length().abs() - 20.0).abs() < 1e-3);
+        assert!(preview_path.point_at(0.5, 0.0, 0.0).length() < 1e-3);
+        assert!(preview_path.point_at(0.0, 0.0, 0.0).x < 0.0);
+        assert!(preview_path.point_at(1.0, 0.0, 0.0).x > 0.0);
+    }
+
+    #[test]
+    fn offsets_match_the_built_road_mesh() {
+        let road_data = road_with_raised_sidewalk();
+
+        for preview_path in [
+            PreviewPath::new(20.0, 0.0),
+            PreviewPath::new(20.0, FRAC_PI_2),
+            PreviewPath::new(20.0, -FRAC_PI_2),
+        ] {
+            let mut road_builder = RoadBuilder::new();
+            road_builder
+                .build_from_requested_section(&preview_path.requested_section(), &road_data);
+
+            let mesh = road_builder.get_mesh();
+            let Some(VertexAttributeValues::Float32x3(positions)) =
+                mesh.attribute(Mesh::ATTRIBUTE_POSITION)
+            else {
+                panic!("The road mesh should have positions");
+            };
+
+            // The first vertex of every slice is the bottom-left of the leftmost component
+            let first_vertex = Vec3::from(positions[0]);
+
+            assert!(
+                (preview_path.offset_of_point(first_vertex) - road_data.left_edge()).abs() < 1e-3,
+                "{preview_path:?}"
+            );
+            assert!(preview_path.is_along_path(preview_path.point_at(0.9, 1.0, 0.0)));
+            assert!(!preview_path.is_along_path(preview_path.point_at(1.1, 1.0, 0.0)));
+        }
+    }
+}
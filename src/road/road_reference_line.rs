@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+
+use super::road_component::RoadComponent;
+
+/// The line along a road that its nodes are placed on, and that all horizontal positions on the road are relative to.
+///
+/// This is the centerline of a road section, so asymmetric designs like one-way roads with a single sidewalk
+/// can be drawn along their edge or the edge of a lane, instead of along the middle of their total width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RoadReferenceLine {
+    #[default]
+    Center,
+    LeftEdge,
+    RightEdge,
+    /// The boundary between the road component at this index and the one before it.
+    ComponentBoundary(usize),
+}
+
+impl RoadReferenceLine {
+    /// Returns the distance in meters from the left edge of the given road components to this reference line.
+    ///
+    /// Boundaries past the last component are treated as the right edge.
+    pub fn distance_from_left_edge(&self, road_components: &[RoadComponent]) -> f32 {
+        let total_width: f32 = road_components.iter().map(RoadComponent::width).sum();
+
+        match self {
+            RoadReferenceLine::Center => total_width / 2.0,
+            RoadReferenceLine::LeftEdge => 0.0,
+            RoadReferenceLine::RightEdge => total_width,
+            RoadReferenceLine::ComponentBoundary(component_index) => road_components
+                .iter()
+                .take(*component_index)
+                .map(RoadComponent::width)
+                .sum(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{color::palettes::tailwind::*, prelude::*};
+
+    use super::*;
+
+    fn one_way_road_components() -> Vec<RoadComponent> {
+        vec![
+            RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), GRAY_600),
+            RoadComponent::new("Lane", Vec2::new(3.5, 0.1), GRAY_800),
+            RoadComponent::new("Verge", Vec2::new(1.0, 0.1), GREEN_700),
+        ]
+    }
+
+    #[test]
+    fn distances_are_measured_from_the_left_edge() {
+        let road_components = one_way_road_components();
+
+        assert_eq!(
+            RoadReferenceLine::Center.distance_from_left_edge(&road_components),
+            3.25
+        );
+        assert_eq!(
+            RoadReferenceLine::LeftEdge.distance_from_left_edge(&road_components),
+            0.0
+        );
+        assert_eq!(
+            RoadReferenceLine::RightEdge.distance_from_left_edge(&road_components),
+            6.5
+        );
+        assert_eq!(
+            RoadReferenceLine::ComponentBoundary(2).distance_from_left_edge(&road_components),
+            5.5
+        );
+    }
+
+    #[test]
+    fn boundaries_past_the_last_component_are_the_right_edge() {
+        let road_components = one_way_road_components();
+
+        assert_eq!(
+            RoadReferenceLine::ComponentBoundary(7).distance_from_left_edge(&road_components),
+            6.5
+        );
+    }
+}
@@ -4,6 +4,7 @@ pub mod road_section_builder;
 use bevy::prelude::*;
 use gizmos::RoadSectionGizmosPlugin;
 use road_section_builder::RoadSectionBuilderPlugin;
+use serde::{Deserialize, Serialize};
 
 use crate::utility::circular_arc::CircularArc;
 
@@ -60,7 +61,6 @@ pub struct RoadSectionEnd {
 
     // TODO: can't this direction just be calculated when needed? Removing this would remove a lot of code in the drawer that only exists to calculate the direction
     /// The outwards facing direction that this end looks towards.
-    #[expect(unused)]
     pub direction: Dir3,
 }
 
@@ -73,7 +73,7 @@ impl RoadSectionEnd {
     }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum RoadSectionVariant {
     Straight,
     Curved(CircularArc),
@@ -95,7 +95,7 @@ pub fn draw_curved_road_section_gizmo(
     circular_arc: &CircularArc,
     color: impl Into<Color> + Clone,
 ) {
-    let half_road_width = road_design.half_width();
+    let edges = (road_design.left_edge(), road_design.right_edge());
     let transform_count =
         ((circular_arc.length().abs() * CURVED_SECTION_TRANSFORM_COUNT_MULTIPLIER).round() as u32)
             .max(5);
@@ -108,12 +108,12 @@ pub fn draw_curved_road_section_gizmo(
         .into_iter()
         .map(|transform| transform.with_translation(transform.translation + circular_arc.position))
     {
-        let offset_points = get_transform_offset_points(transform_along_arc, half_road_width);
+        let offset_points = get_transform_offset_points(transform_along_arc, edges);
 
         gizmos.line(offset_points.0, offset_points.1, color.clone());
 
         if let Some(transform) = previous_transform {
-            let previous_offset_points = get_transform_offset_points(transform, half_road_width);
+            let previous_offset_points = get_transform_offset_points(transform, edges);
 
             gizmos.line(previous_offset_points.0, offset_points.0, color.clone());
             gizmos.line(previous_offset_points.1, offset_points.1, color.clone());
@@ -122,10 +122,11 @@ pub fn draw_curved_road_section_gizmo(
         previous_transform = Some(transform_along_arc);
     }
 
-    fn get_transform_offset_points(transform: Transform, half_road_width: f32) -> (Vec3, Vec3) {
+    /// Returns the points on the left and right edge of the road, which are relative to the road's reference line.
+    fn get_transform_offset_points(transform: Transform, edges: (f32, f32)) -> (Vec3, Vec3) {
         (
-            transform.translation + transform.left() * half_road_width,
-            transform.translation + transform.right() * half_road_width,
+            transform.translation + transform.right() * edges.0,
+            transform.translation + transform.right() * edges.1,
         )
     }
 }
@@ -138,6 +139,8 @@ fn calculate_road_section_gizmo_transform(
     let mut section_transform =
         calculate_road_section_transform(start_node_position, end_node_position);
 
+    // The nodes are on the reference line, which isn't always the middle of the road
+    section_transform.translation += section_transform.right() * road_design.center();
     section_transform.translation.y += road_design.total_height() / 2.0;
     section_transform.scale = road_design
         .total_size()
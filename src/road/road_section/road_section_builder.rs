@@ -5,6 +5,7 @@ use crate::{
     road::{
         road_builder::RoadBuilder, road_data::RoadData,
         road_node::road_node_builder::get_or_build_road_node,
+        road_structure::RoadStructureCollider,
     },
     road_drawer::selected_road::SelectedRoad,
     GameRunningSet,
@@ -28,13 +29,29 @@ impl Plugin for RoadSectionBuilderPlugin {
 #[derive(Event)]
 pub struct OnBuildRoadSectionRequested {
     requested_section: RequestedRoadSection,
+    /// The road design to build the section with, the selected road is used if this is None.
+    road_design: Option<RoadData>,
 }
 
 impl OnBuildRoadSectionRequested {
     pub fn new(requested_section: RequestedRoadSection) -> Self {
         // TODO: assert that straight sections have to have their ends in opposite directions
         // TODO: assert that curved sections can't have their ends in opposite directions
-        Self { requested_section }
+        Self {
+            requested_section,
+            road_design: None,
+        }
+    }
+
+    /// Returns a request to build a section with the given road design instead of the selected road.
+    pub fn with_road_design(
+        requested_section: RequestedRoadSection,
+        road_design: RoadData,
+    ) -> Self {
+        Self {
+            requested_section,
+            road_design: Some(road_design),
+        }
     }
 }
 
@@ -48,9 +65,12 @@ fn build_road_sections_on_request(
 ) {
     for request in requests.read() {
         let requested_section = &request.requested_section;
-        let selected_road_design = selected_road
-            .selected_road()
-            .expect("Requests should only be possible to send when a road is selected");
+        let selected_road_design = match &request.road_design {
+            Some(road_design) => road_design,
+            None => selected_road
+                .selected_road()
+                .expect("Requests should only be possible to send when a road is selected"),
+        };
 
         let mut road_builder = RoadBuilder::new();
         road_builder.build_from_requested_section(requested_section, selected_road_design);
@@ -71,7 +91,18 @@ fn build_road_sections_on_request(
 
         let collider = road_builder.get_collider();
 
-        commands.spawn((road_section, pbr_bundle, collider));
+        let mut road_section_entity = commands.spawn((road_section, pbr_bundle, collider));
+
+        // Kept apart from the road's collider, so the structures can be told apart from the road surface
+        if let Some(structure_collider) = road_builder.get_structure_collider() {
+            road_section_entity.with_children(|road_section| {
+                road_section.spawn((
+                    RoadStructureCollider,
+                    structure_collider,
+                    TransformBundle::default(),
+                ));
+            });
+        }
 
         // commands.spawn(road_builder.to_debug_data(Vec3::ZERO, true, false));
     }
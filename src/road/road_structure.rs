@@ -0,0 +1,224 @@
+pub mod config_ui;
+
+use bevy::{color::palettes::tailwind::*, prelude::*};
+use config_ui::RoadStructureConfigUiPlugin;
+use serde::{Deserialize, Serialize};
+
+pub struct RoadStructurePlugin;
+
+impl Plugin for RoadStructurePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(RoadStructureConfigUiPlugin);
+    }
+}
+
+/// Continuous objects along the road, like barriers, guardrails, noise walls and fences.
+///
+/// A structure's profile is extruded along the road, just like the cross-section of its components.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RoadStructure {
+    pub name: String,
+    /// The outline of this structure's cross-section in meters, relative to its position.
+    ///
+    /// The points go clockwise starting on the bottom-left, the last point is connected back to the first.
+    pub profile: Vec<Vec2>,
+    /// This structure's horizontal position relative to the road's reference line, in meters.
+    pub x_position: f32,
+    /// The height this structure is placed at, in meters above the bottom of the road.
+    pub height: f32,
+    pub color: Color,
+    /// Whether this structure blocks things, its collider is built separately from the road's collider.
+    pub has_collider: bool,
+}
+
+impl RoadStructure {
+    pub fn new(name: impl Into<String>, profile: Vec<Vec2>, color: impl Into<Color>) -> Self {
+        Self {
+            name: name.into(),
+            profile,
+            x_position: 0.0,
+            height: 0.0,
+            color: color.into(),
+            has_collider: true,
+        }
+    }
+
+    pub fn from_preset(preset: RoadStructurePreset) -> Self {
+        Self::new(preset.name(), preset.profile(), preset.color())
+    }
+
+    pub fn with_x_position(mut self, x_position: f32) -> Self {
+        self.x_position = x_position;
+        self
+    }
+
+    pub fn with_height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_collider(mut self, has_collider: bool) -> Self {
+        self.has_collider = has_collider;
+        self
+    }
+
+    /// Returns the corners of this structure's profile relative to the road's reference line and bottom.
+    pub fn placed_profile(&self) -> impl Iterator<Item = Vec2> + '_ {
+        let origin = Vec2::new(self.x_position, self.height);
+
+        self.profile.iter().map(move |point| origin + *point)
+    }
+
+    /// Returns the smallest rect that contains this structure's placed profile.
+    pub fn bounds(&self) -> Rect {
+        self.placed_profile()
+            .fold(None, |bounds: Option<Rect>, point| {
+                Some(match bounds {
+                    Some(bounds) => bounds.union_point(point),
+                    None => Rect::from_corners(point, point),
+                })
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Default for RoadStructure {
+    fn default() -> Self {
+        Self::from_preset(RoadStructurePreset::JerseyBarrier)
+    }
+}
+
+/// Commonly used structures, with a profile of typical dimensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoadStructurePreset {
+    JerseyBarrier,
+    Guardrail,
+    NoiseWall,
+    Fence,
+}
+
+impl RoadStructurePreset {
+    pub const ALL: [RoadStructurePreset; 4] = [
+        RoadStructurePreset::JerseyBarrier,
+        RoadStructurePreset::Guardrail,
+        RoadStructurePreset::NoiseWall,
+        RoadStructurePreset::Fence,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            RoadStructurePreset::JerseyBarrier => "Jersey barrier",
+            RoadStructurePreset::Guardrail => "Guardrail",
+            RoadStructurePreset::NoiseWall => "Noise wall",
+            RoadStructurePreset::Fence => "Fence",
+        }
+    }
+
+    fn profile(&self) -> Vec<Vec2> {
+        match self {
+            RoadStructurePreset::JerseyBarrier => vec![
+                Vec2::new(-0.3, 0.0),
+                Vec2::new(-0.3, 0.08),
+                Vec2::new(-0.2, 0.33),
+                Vec2::new(-0.075, 0.81),
+                Vec2::new(0.075, 0.81),
+                Vec2::new(0.2, 0.33),
+                Vec2::new(0.3, 0.08),
+                Vec2::new(0.3, 0.0),
+            ],
+            // Only the rail, the posts are placed one by one
+            RoadStructurePreset::Guardrail => vec![
+                Vec2::new(-0.05, 0.45),
+                Vec2::new(-0.1, 0.55),
+                Vec2::new(-0.05, 0.65),
+                Vec2::new(-0.1, 0.75),
+                Vec2::new(0.0, 0.75),
+                Vec2::new(0.0, 0.45),
+            ],
+            RoadStructurePreset::NoiseWall => rectangle_profile(0.2, 4.0),
+            RoadStructurePreset::Fence => rectangle_profile(0.05, 1.5),
+        }
+    }
+
+    fn color(&self) -> Srgba {
+        match self {
+            RoadStructurePreset::JerseyBarrier => STONE_300,
+            RoadStructurePreset::Guardrail => ZINC_400,
+            RoadStructurePreset::NoiseWall => STONE_500,
+            RoadStructurePreset::Fence => ZINC_600,
+        }
+    }
+}
+
+/// Returns the profile of a rectangle standing on its bottom center.
+fn rectangle_profile(width: f32, height: f32) -> Vec<Vec2> {
+    let half_width = width / 2.0;
+
+    vec![
+        Vec2::new(-half_width, 0.0),
+        Vec2::new(-half_width, height),
+        Vec2::new(half_width, height),
+        Vec2::new(half_width, 0.0),
+    ]
+}
+
+#[derive(Component, Clone, Copy, PartialEq)]
+pub enum RoadStructureField {
+    XPosition,
+    Height,
+    Color,
+    HasCollider,
+}
+
+/// Marker component for the collider of the structures along a road section, which is a child of the section.
+#[derive(Component)]
+pub struct RoadStructureCollider;
+
+#[cfg(test)]
+mod tests {
+    use crate::road::road_builder::calculate_road_structure_slice;
+
+    use super::*;
+
+    #[test]
+    fn placed_structures_are_offset_by_their_position() {
+        let noise_wall = RoadStructure::from_preset(RoadStructurePreset::NoiseWall)
+            .with_x_position(6.0)
+            .with_height(0.2);
+
+        assert_eq!(
+            noise_wall.bounds(),
+            Rect::new(5.9, 0.2, 6.1, 4.2),
+            "{noise_wall:?}"
+        );
+    }
+
+    #[test]
+    fn structure_slices_are_closed_and_face_outwards() {
+        let fence = RoadStructure::from_preset(RoadStructurePreset::Fence);
+        let slice = calculate_road_structure_slice(&fence, Vec2::ZERO);
+
+        // Every side of the profile has its own start and end, including the closing bottom side
+        assert_eq!(slice.vertices.len(), fence.profile.len() * 2);
+        assert_eq!(slice.vertices.last(), slice.vertices.first());
+        assert_eq!(
+            slice.normals,
+            [
+                Vec3::NEG_X,
+                Vec3::NEG_X,
+                Vec3::Y,
+                Vec3::Y,
+                Vec3::X,
+                Vec3::X,
+                Vec3::NEG_Y,
+                Vec3::NEG_Y
+            ]
+        );
+    }
+}
@@ -0,0 +1,416 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{
+        active_road::{
+            active_road_events::road_structure_change::{
+                OnRoadStructureChangeRequested, OnRoadStructureChanged, RoadStructureChange,
+                RoadStructureFieldChange,
+            },
+            ActiveRoad,
+        },
+        road_data::RoadData,
+    },
+    ui::{
+        components::{
+            buttons::{ButtonAction, OnButtonPressed, TextButtonBuilder},
+            collapsible_section::CollapsibleSectionBuilder,
+            flexbox::{FlexboxBuilder, FlexboxConfig},
+            inputs::{
+                checkbox::{CheckboxBuilder, CheckboxConfig, OnCheckboxValueChanged},
+                color_input::{ColorInputBuilder, ColorInputConfig, OnColorInputValueChanged},
+                dropdown::{Dropdown, DropdownBuilder, DropdownConfig},
+                labeled_element::LabeledElementBuilder,
+                number_input::{NumberInputBuilder, OnNumberInputValueChanged},
+            },
+            scroll_container::ScrollContainerBuilder,
+            section::SectionConfig,
+            tooltip::Tooltip,
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        sidebar::Sidebar,
+        theme::ThemeColor,
+    },
+    utility::find_ancestor_of_entity,
+    GameRunningSet,
+};
+
+use super::{RoadStructure, RoadStructureField, RoadStructurePreset};
+
+/// How far next to the road structures can be placed, in meters.
+const MAX_DISTANCE_FROM_ROAD: f32 = 20.0;
+const MAX_STRUCTURE_HEIGHT: f32 = 10.0;
+
+pub struct RoadStructureConfigUiPlugin;
+
+impl Plugin for RoadStructureConfigUiPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnShowRoadStructureConfigRequested>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        send_show_requests,
+                        handle_add_button_pressed_events,
+                        handle_delete_button_pressed_events,
+                        handle_number_input_changed_events,
+                        handle_color_input_changed_events,
+                        handle_checkbox_changed_events,
+                    )
+                        .in_set(GameRunningSet::SendCommands),
+                    handle_show_config_requests.in_set(GameRunningSet::HandleCommands),
+                )
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+/// Shows the config of all road structures in the sidebar, with only the given structure expanded.
+#[derive(Event, Clone, Copy)]
+pub struct OnShowRoadStructureConfigRequested {
+    expanded_structure_index: Option<usize>,
+}
+
+impl OnShowRoadStructureConfigRequested {
+    pub fn new(expanded_structure_index: Option<usize>) -> Self {
+        Self {
+            expanded_structure_index,
+        }
+    }
+}
+
+/// The content of the sidebar while it shows the road structures.
+#[derive(Component)]
+struct RoadStructureConfigList;
+
+/// The config section of a single road structure, which holds the inputs for its fields.
+#[derive(Component)]
+struct RoadStructureConfig {
+    road_structure_index: usize,
+}
+
+#[derive(Component)]
+struct RoadStructurePresetDropdown;
+
+#[derive(Component)]
+struct DeleteRoadStructureButton;
+
+/// Shows the config when its toolbar button is pressed, and again when a structure was added or removed while it's shown.
+fn send_show_requests(
+    mut on_pressed: EventReader<OnButtonPressed>,
+    mut on_structure_changed: EventReader<OnRoadStructureChanged>,
+    mut on_request: EventWriter<OnShowRoadStructureConfigRequested>,
+    config_list_query: Query<(), With<RoadStructureConfigList>>,
+) {
+    for _ in on_pressed
+        .read()
+        .filter(|event| event.is_action(ButtonAction::ShowRoadStructureConfig))
+    {
+        on_request.send(OnShowRoadStructureConfigRequested::new(None));
+    }
+
+    for event in on_structure_changed
+        .read()
+        .filter(|event| event.change.changes_structure_count())
+    {
+        if config_list_query.is_empty() {
+            continue;
+        }
+
+        let expanded_structure_index = match event.change {
+            RoadStructureChange::Add(_) => {
+                Some(event.new_road_data().structures().len().saturating_sub(1))
+            }
+            _ => None,
+        };
+
+        on_request.send(OnShowRoadStructureConfigRequested::new(
+            expanded_structure_index,
+        ));
+    }
+}
+
+fn handle_show_config_requests(
+    mut on_show: EventReader<OnShowRoadStructureConfigRequested>,
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    sidebar_query: Query<Entity, With<Sidebar>>,
+    active_road: Res<ActiveRoad>,
+) {
+    // Only the last request matters, since each one replaces the whole sidebar
+    let Some(event) = on_show.read().last() else {
+        return;
+    };
+
+    let sidebar = sidebar_query.single();
+    let road_data = active_road.road_data();
+
+    commands
+        .entity(sidebar)
+        .despawn_descendants()
+        .with_children(|sidebar| {
+            ScrollContainerBuilder::new(
+                FlexboxConfig::horizontally_centered_column().with_px_gap(20.0),
+            )
+            .spawn(sidebar, RoadStructureConfigList, |config_container| {
+                spawn_add_structure_row(config_container);
+
+                for (index, road_structure) in road_data.structures().iter().enumerate() {
+                    spawn_road_structure_config(
+                        config_container,
+                        index,
+                        road_structure,
+                        road_data,
+                        event.expanded_structure_index == Some(index),
+                        &mut images,
+                    );
+                }
+            });
+        });
+}
+
+fn spawn_add_structure_row(builder: &mut ChildBuilder) {
+    FlexboxBuilder::new(FlexboxConfig::row().with_px_gap(4.0)).spawn(builder, (), |row| {
+        DropdownBuilder::new(DropdownConfig::new(
+            RoadStructurePreset::ALL.map(|preset| preset.name()),
+        ))
+        .spawn(row, RoadStructurePresetDropdown);
+
+        TextButtonBuilder::default_with_text("Add").spawn(
+            row,
+            (
+                ButtonAction::AddRoadStructure,
+                Tooltip::new("Add the selected structure along the road"),
+            ),
+        );
+    });
+}
+
+fn spawn_road_structure_config(
+    builder: &mut ChildBuilder,
+    index: usize,
+    road_structure: &RoadStructure,
+    road_data: &RoadData,
+    is_expanded: bool,
+    images: &mut Assets<Image>,
+) {
+    let x_position_value_range = (road_data.left_edge() - MAX_DISTANCE_FROM_ROAD)
+        ..(road_data.right_edge() + MAX_DISTANCE_FROM_ROAD);
+
+    CollapsibleSectionBuilder::new(
+        SectionConfig::default().with_background_color(ThemeColor::RaisedSurface),
+        format!("{} {}", road_structure.name, index + 1),
+    )
+    .with_expanded(is_expanded)
+    .spawn(
+        builder,
+        RoadStructureConfig {
+            road_structure_index: index,
+        },
+        |section| {
+            LabeledElementBuilder::centered_top_label("X position").spawn(
+                section,
+                Tooltip::new("The distance from the reference line of the road to this structure"),
+                |position_label| {
+                    NumberInputBuilder::default()
+                        .with_values(road_structure.x_position, x_position_value_range)
+                        .as_length()
+                        .spawn(position_label, RoadStructureField::XPosition);
+                },
+            );
+
+            LabeledElementBuilder::centered_top_label("Height").spawn(
+                section,
+                Tooltip::new("The height this structure stands at, above the bottom of the road"),
+                |height_label| {
+                    NumberInputBuilder::default()
+                        .with_values(road_structure.height, 0.0..MAX_STRUCTURE_HEIGHT)
+                        .as_length()
+                        .spawn(height_label, RoadStructureField::Height);
+                },
+            );
+
+            LabeledElementBuilder::centered_top_label("Color").spawn(
+                section,
+                Tooltip::new("The color of this structure"),
+                |color_label| {
+                    ColorInputBuilder::new(
+                        ColorInputConfig::default().with_start_color(road_structure.color),
+                        images,
+                    )
+                    .spawn(color_label, RoadStructureField::Color);
+                },
+            );
+
+            LabeledElementBuilder::centered_top_label("Collider").spawn(
+                section,
+                Tooltip::new("Whether this structure blocks things, like a barrier"),
+                |collider_label| {
+                    CheckboxBuilder::new(
+                        CheckboxConfig::default().with_checked(road_structure.has_collider),
+                    )
+                    .spawn(collider_label, RoadStructureField::HasCollider);
+                },
+            );
+
+            TextButtonBuilder::default_with_text("Delete").spawn(
+                section,
+                (
+                    DeleteRoadStructureButton,
+                    Tooltip::new("Remove this structure from the road"),
+                ),
+            );
+        },
+    );
+}
+
+fn handle_add_button_pressed_events(
+    mut on_pressed: EventReader<OnButtonPressed>,
+    mut on_change_request: EventWriter<OnRoadStructureChangeRequested>,
+    preset_dropdown_query: Query<&Dropdown, With<RoadStructurePresetDropdown>>,
+    active_road: Res<ActiveRoad>,
+) {
+    for _ in on_pressed
+        .read()
+        .filter(|event| event.is_action(ButtonAction::AddRoadStructure))
+    {
+        let Some(preset) = preset_dropdown_query
+            .get_single()
+            .ok()
+            .and_then(|dropdown| RoadStructurePreset::ALL.get(dropdown.selected_index()))
+        else {
+            continue;
+        };
+
+        // New structures start at the right edge, where they're in nobody's way
+        let road_structure = RoadStructure::from_preset(*preset)
+            .with_x_position(active_road.road_data().right_edge());
+
+        on_change_request.send(OnRoadStructureChangeRequested::new(
+            RoadStructureChange::Add(road_structure),
+        ));
+    }
+}
+
+fn handle_delete_button_pressed_events(
+    mut on_change_request: EventWriter<OnRoadStructureChangeRequested>,
+    delete_button_query: Query<(Entity, Ref<Interaction>), With<DeleteRoadStructureButton>>,
+    structure_config_query: Query<(Entity, &RoadStructureConfig)>,
+    parent_query: Query<&Parent>,
+) {
+    for (button_entity, _) in delete_button_query
+        .iter()
+        .filter(|(_, interaction)| interaction.is_changed() && **interaction == Interaction::Pressed)
+    {
+        let Some((_, structure_config)) = find_ancestor_of_entity(
+            button_entity,
+            &structure_config_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        on_change_request.send(OnRoadStructureChangeRequested::new(
+            RoadStructureChange::Delete(structure_config.road_structure_index),
+        ));
+    }
+}
+
+fn handle_number_input_changed_events(
+    mut on_input_changed: EventReader<OnNumberInputValueChanged>,
+    mut on_change_request: EventWriter<OnRoadStructureChangeRequested>,
+    number_input_query: Query<&RoadStructureField>,
+    structure_config_query: Query<(Entity, &RoadStructureConfig)>,
+    parent_query: Query<&Parent>,
+) {
+    for event in on_input_changed.read() {
+        let Ok(field) = number_input_query.get(event.number_input_entity()) else {
+            continue;
+        };
+
+        let field_to_change = match field {
+            RoadStructureField::XPosition => RoadStructureFieldChange::XPosition(event.new_value()),
+            RoadStructureField::Height => RoadStructureFieldChange::Height(event.new_value()),
+            RoadStructureField::Color | RoadStructureField::HasCollider => continue,
+        };
+
+        send_field_change_request(
+            &mut on_change_request,
+            event.number_input_entity(),
+            field_to_change,
+            &structure_config_query,
+            &parent_query,
+        );
+    }
+}
+
+fn handle_color_input_changed_events(
+    mut on_input_changed: EventReader<OnColorInputValueChanged>,
+    mut on_change_request: EventWriter<OnRoadStructureChangeRequested>,
+    color_input_query: Query<&RoadStructureField>,
+    structure_config_query: Query<(Entity, &RoadStructureConfig)>,
+    parent_query: Query<&Parent>,
+) {
+    for event in on_input_changed.read() {
+        if color_input_query.get(event.color_input_entity()) != Ok(&RoadStructureField::Color) {
+            continue;
+        }
+
+        send_field_change_request(
+            &mut on_change_request,
+            event.color_input_entity(),
+            RoadStructureFieldChange::Color(event.new_color()),
+            &structure_config_query,
+            &parent_query,
+        );
+    }
+}
+
+fn handle_checkbox_changed_events(
+    mut on_checkbox_changed: EventReader<OnCheckboxValueChanged>,
+    mut on_change_request: EventWriter<OnRoadStructureChangeRequested>,
+    checkbox_query: Query<&RoadStructureField>,
+    structure_config_query: Query<(Entity, &RoadStructureConfig)>,
+    parent_query: Query<&Parent>,
+) {
+    for event in on_checkbox_changed.read() {
+        if checkbox_query.get(event.checkbox_entity()) != Ok(&RoadStructureField::HasCollider) {
+            continue;
+        }
+
+        send_field_change_request(
+            &mut on_change_request,
+            event.checkbox_entity(),
+            RoadStructureFieldChange::HasCollider(event.is_checked()),
+            &structure_config_query,
+            &parent_query,
+        );
+    }
+}
+
+/// Sends a request to change the structure whose config holds the given input.
+fn send_field_change_request(
+    on_change_request: &mut EventWriter<OnRoadStructureChangeRequested>,
+    input_entity: Entity,
+    field: RoadStructureFieldChange,
+    structure_config_query: &Query<(Entity, &RoadStructureConfig)>,
+    parent_query: &Query<&Parent>,
+) {
+    let Some((_, structure_config)) = find_ancestor_of_entity(
+        input_entity,
+        structure_config_query,
+        |item| item.0,
+        parent_query,
+    ) else {
+        return;
+    };
+
+    on_change_request.send(OnRoadStructureChangeRequested::new(
+        RoadStructureChange::Field {
+            road_structure_index: structure_config.road_structure_index,
+            field,
+        },
+    ));
+}
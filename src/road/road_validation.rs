@@ -0,0 +1,369 @@
+use bevy::prelude::*;
+
+use crate::{game_modes::GameMode, ui::notifications::OnNotificationRequested, GameRunningSet};
+
+use super::{active_road::ActiveRoad, road_data::RoadData};
+
+pub struct RoadValidationPlugin;
+
+impl Plugin for RoadValidationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RoadDiagnostics>().add_systems(
+            Update,
+            update_road_diagnostics
+                .in_set(GameRunningSet::UpdateEntities)
+                .run_if(in_state(GameMode::RoadEditor))
+                .run_if(resource_exists_and_changed::<ActiveRoad>),
+        );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiagnosticSeverity {
+    Warning,
+    Error,
+}
+
+/// The part of a road design that a diagnostic is about.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DiagnosticSubject {
+    Road,
+    /// Holds the index of the road component.
+    Component(usize),
+    /// Holds the index of the road marking.
+    Marking(usize),
+}
+
+/// A problem found in a road design.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    pub severity: DiagnosticSeverity,
+    pub subject: DiagnosticSubject,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn new(
+        severity: DiagnosticSeverity,
+        subject: DiagnosticSubject,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            subject,
+            message: message.into(),
+        }
+    }
+
+    pub fn error(subject: DiagnosticSubject, message: impl Into<String>) -> Self {
+        Self::new(DiagnosticSeverity::Error, subject, message)
+    }
+
+    pub fn warning(subject: DiagnosticSubject, message: impl Into<String>) -> Self {
+        Self::new(DiagnosticSeverity::Warning, subject, message)
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == DiagnosticSeverity::Error
+    }
+}
+
+/// Holds the diagnostics of the active road, updated whenever the active road changes.
+#[derive(Resource, Debug, Default)]
+pub struct RoadDiagnostics {
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl RoadDiagnostics {
+    pub fn diagnostics(&self) -> &[Diagnostic] {
+        &self.diagnostics
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(Diagnostic::is_error)
+    }
+
+    pub fn errors(&self) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.is_error())
+    }
+
+    /// Returns the highest severity of all diagnostics about the given road component, or None if it has no diagnostics.
+    pub fn component_severity(&self, component_index: usize) -> Option<DiagnosticSeverity> {
+        self.diagnostics
+            .iter()
+            .filter(|diagnostic| {
+                diagnostic.subject == DiagnosticSubject::Component(component_index)
+            })
+            .map(|diagnostic| diagnostic.severity)
+            .max()
+    }
+}
+
+fn update_road_diagnostics(
+    mut road_diagnostics: ResMut<RoadDiagnostics>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
+    active_road: Res<ActiveRoad>,
+) {
+    let diagnostics = validate(active_road.road_data());
+
+    if road_diagnostics.diagnostics == diagnostics {
+        return;
+    }
+
+    // Only problems introduced by the latest change are notified, the panel lists all of them
+    for new_diagnostic in diagnostics
+        .iter()
+        .filter(|diagnostic| !road_diagnostics.diagnostics.contains(diagnostic))
+    {
+        on_notification.send(match new_diagnostic.severity {
+            DiagnosticSeverity::Warning => {
+                OnNotificationRequested::warning(new_diagnostic.message.clone())
+            }
+            DiagnosticSeverity::Error => {
+                OnNotificationRequested::error(new_diagnostic.message.clone())
+            }
+        });
+    }
+
+    road_diagnostics.diagnostics = diagnostics;
+}
+
+/// Checks the given road design for problems, returns a diagnostic for each problem found.
+pub fn validate(road_data: &RoadData) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    validate_components(road_data, &mut diagnostics);
+    validate_markings(road_data, &mut diagnostics);
+
+    diagnostics
+}
+
+fn validate_components(road_data: &RoadData, diagnostics: &mut Vec<Diagnostic>) {
+    if road_data.component_count() == 0 {
+        diagnostics.push(Diagnostic::error(
+            DiagnosticSubject::Road,
+            "Road design has no components.",
+        ));
+    }
+
+    for (index, component) in road_data.enumerate_components() {
+        let subject = DiagnosticSubject::Component(index);
+
+        if component.width() <= 0.0 {
+            diagnostics.push(Diagnostic::error(
+                subject,
+                format!(
+                    "'{}' has a width of {:.2}.",
+                    component.name,
+                    component.width()
+                ),
+            ));
+        }
+
+        if component.height() < 0.0 {
+            diagnostics.push(Diagnostic::error(
+                subject,
+                format!(
+                    "'{}' has a negative height of {:.2}.",
+                    component.name,
+                    component.height()
+                ),
+            ));
+        }
+    }
+}
+
+fn validate_markings(road_data: &RoadData, diagnostics: &mut Vec<Diagnostic>) {
+    let (left_edge, right_edge) = (road_data.left_edge(), road_data.right_edge());
+    let markings = road_data.markings();
+
+    for (index, marking) in markings.iter().enumerate() {
+        let subject = DiagnosticSubject::Marking(index);
+        let (left, right) = marking_edges(marking.x_position, marking.segment_width);
+
+        if marking.segment_width <= 0.0 {
+            diagnostics.push(Diagnostic::error(
+                subject,
+                format!(
+                    "Marking {} has a width of {:.2}.",
+                    index + 1,
+                    marking.segment_width
+                ),
+            ));
+        }
+
+        if left < left_edge || right > right_edge {
+            diagnostics.push(Diagnostic::error(
+                subject,
+                format!("Marking {} is outside of the road.", index + 1),
+            ));
+        }
+
+        for (other_index, other_marking) in markings.iter().enumerate().skip(index + 1) {
+            let (other_left, other_right) =
+                marking_edges(other_marking.x_position, other_marking.segment_width);
+
+            if left < other_right && other_left < right {
+                diagnostics.push(Diagnostic::warning(
+                    subject,
+                    format!(
+                        "Marking {} overlaps marking {}.",
+                        index + 1,
+                        other_index + 1
+                    ),
+                ));
+            }
+        }
+
+        if crosses_height_step(road_data, left, right) {
+            diagnostics.push(Diagnostic::warning(
+                subject,
+                format!(
+                    "Marking {} crosses a height difference between components.",
+                    index + 1
+                ),
+            ));
+        }
+    }
+}
+
+/// Returns true if there's a boundary between 2 components of different height between the given `left` and `right` positions.
+fn crosses_height_step(road_data: &RoadData, left: f32, right: f32) -> bool {
+    let components = road_data.components();
+
+    road_data
+        .component_positions()
+        .iter()
+        .enumerate()
+        .skip(1)
+        .any(|(index, position)| {
+            components[index - 1].height() != components[index].height()
+                && left < position.left
+                && position.left < right
+        })
+}
+
+fn marking_edges(x_position: f32, segment_width: f32) -> (f32, f32) {
+    let half_segment_width = segment_width / 2.0;
+
+    (
+        x_position - half_segment_width,
+        x_position + half_segment_width,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::road::{road_component::RoadComponent, road_marking::RoadMarking};
+
+    use super::*;
+
+    fn build_road(markings: Vec<RoadMarking>) -> RoadData {
+        RoadData::new(
+            String::from("Test road"),
+            vec![
+                RoadComponent::new("Sidewalk", Vec2::new(2.0, 0.3), Color::WHITE),
+                RoadComponent::new("Lane", Vec2::new(4.0, 0.1), Color::BLACK),
+                RoadComponent::new("Lane", Vec2::new(4.0, 0.1), Color::BLACK),
+            ],
+            markings,
+        )
+    }
+
+    #[test]
+    fn valid_road_has_no_diagnostics() {
+        let road = build_road(vec![RoadMarking::new(1.0, 0.15, Color::WHITE)]);
+
+        assert_eq!(validate(&road), Vec::new());
+    }
+
+    #[test]
+    fn empty_road_is_an_error() {
+        let road = RoadData::default();
+
+        let diagnostics = validate(&road);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].subject, DiagnosticSubject::Road);
+        assert!(diagnostics[0].is_error());
+    }
+
+    #[test]
+    fn zero_width_component_is_an_error() {
+        let mut road = build_road(Vec::new());
+        road.components_mut()[1].size.x = 0.0;
+        road.recalculate_road_component_positions();
+
+        let diagnostics = validate(&road);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].subject, DiagnosticSubject::Component(1));
+        assert!(diagnostics[0].is_error());
+    }
+
+    #[test]
+    fn marking_outside_road_is_an_error() {
+        let road = build_road(vec![RoadMarking::new(4.95, 0.2, Color::WHITE)]);
+
+        let diagnostics = validate(&road);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].subject, DiagnosticSubject::Marking(0));
+        assert!(diagnostics[0].is_error());
+    }
+
+    #[test]
+    fn overlapping_markings_are_a_warning() {
+        let road = build_road(vec![
+            RoadMarking::new(1.0, 0.2, Color::WHITE),
+            RoadMarking::new(1.1, 0.2, Color::WHITE),
+        ]);
+
+        let diagnostics = validate(&road);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    }
+
+    #[test]
+    fn marking_over_height_step_is_a_warning() {
+        // The sidewalk ends at -3.0
+        let road = build_road(vec![RoadMarking::new(-3.0, 0.2, Color::WHITE)]);
+
+        let diagnostics = validate(&road);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, DiagnosticSeverity::Warning);
+    }
+
+    #[test]
+    fn marking_over_boundary_without_height_step_is_valid() {
+        // The 2 lanes meet at 1.0
+        let road = build_road(vec![RoadMarking::new(1.0, 0.2, Color::WHITE)]);
+
+        assert!(validate(&road).is_empty());
+    }
+
+    #[test]
+    fn component_severity_returns_highest_severity() {
+        let road_diagnostics = RoadDiagnostics {
+            diagnostics: vec![
+                Diagnostic::warning(DiagnosticSubject::Component(0), "warning"),
+                Diagnostic::error(DiagnosticSubject::Component(0), "error"),
+                Diagnostic::warning(DiagnosticSubject::Component(1), "warning"),
+            ],
+        };
+
+        assert_eq!(
+            road_diagnostics.component_severity(0),
+            Some(DiagnosticSeverity::Error)
+        );
+        assert_eq!(
+            road_diagnostics.component_severity(1),
+            Some(DiagnosticSeverity::Warning)
+        );
+        assert_eq!(road_diagnostics.component_severity(2), None);
+    }
+}
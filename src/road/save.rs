@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::GameRunningSet;
+use crate::{ui::notifications::OnNotificationRequested, GameRunningSet};
 
 use crate::road::road_data::RoadData;
 
@@ -12,10 +12,13 @@ pub struct SaveRoadPlugin;
 
 impl Plugin for SaveRoadPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<OnSaveRoadRequested>().add_systems(
-            Update,
-            handle_save_requests.in_set(GameRunningSet::HandleCommands),
-        );
+        app.add_event::<OnSaveRoadRequested>()
+            .add_event::<OnRoadSaved>()
+            .add_event::<OnRoadSaveFailed>()
+            .add_systems(
+                Update,
+                handle_save_requests.in_set(GameRunningSet::HandleCommands),
+            );
     }
 }
 
@@ -34,10 +37,58 @@ impl OnSaveRoadRequested {
     }
 }
 
-fn handle_save_requests(mut requests: EventReader<OnSaveRoadRequested>) {
+#[derive(Event)]
+pub struct OnRoadSaved {
+    road: RoadData,
+}
+
+impl OnRoadSaved {
+    pub fn new(road: RoadData) -> Self {
+        Self { road }
+    }
+
+    pub fn road(&self) -> &RoadData {
+        &self.road
+    }
+}
+
+/// Sent when a road couldn't be saved, the user is notified of the error separately.
+#[derive(Event)]
+pub struct OnRoadSaveFailed {
+    road: RoadData,
+}
+
+impl OnRoadSaveFailed {
+    pub fn new(road: RoadData) -> Self {
+        Self { road }
+    }
+
+    pub fn road(&self) -> &RoadData {
+        &self.road
+    }
+}
+
+fn handle_save_requests(
+    mut requests: EventReader<OnSaveRoadRequested>,
+    mut on_saved: EventWriter<OnRoadSaved>,
+    mut on_save_failed: EventWriter<OnRoadSaveFailed>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
+) {
     for request in requests.read() {
         let file_name = request.road().name();
 
-        save_road_data(request.road(), file_name).expect("RoadData should save to file.");
+        if let Err(error) = save_road_data(request.road(), file_name) {
+            on_notification.send(OnNotificationRequested::error(format!(
+                "Failed to save \"{file_name}\": {error}"
+            )));
+            on_save_failed.send(OnRoadSaveFailed::new(request.road().clone()));
+            continue;
+        }
+
+        on_saved.send(OnRoadSaved::new(request.road().clone()));
+
+        on_notification.send(OnNotificationRequested::success(format!(
+            "Saved \"{file_name}\""
+        )));
     }
 }
@@ -1,7 +1,7 @@
 mod bulldozer;
 mod gizmos;
 mod road_being_drawn;
-mod road_drawer_tool;
+pub mod road_drawer_tool;
 pub mod selected_road;
 
 use bevy::prelude::*;
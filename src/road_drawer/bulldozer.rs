@@ -5,7 +5,8 @@ use ui::BulldozerUiPlugin;
 
 use crate::{
     game_modes::GameMode,
-    road::road_section::RoadSection,
+    keybindings::{ActionInput, InputAction, Keybindings},
+    road::{road_section::RoadSection, road_structure::RoadStructureCollider},
     world::world_interaction::{
         mouse_interaction_events::{InteractionPhase, OnMouseInteraction},
         WorldInteraction,
@@ -22,7 +23,7 @@ impl Plugin for BulldozerPlugin {
         app.add_plugins(BulldozerUiPlugin).add_systems(
             Update,
             (
-                switch_to_bulldozer_on_b_key.in_set(GameRunningSet::GetUserInput),
+                switch_tool_on_bulldozer_input.in_set(GameRunningSet::GetUserInput),
                 delete_road_sections_that_are_clicked_on
                     .in_set(GameRunningSet::UpdateEntities)
                     .run_if(in_state(RoadDrawerTool::Bulldozer)),
@@ -32,12 +33,12 @@ impl Plugin for BulldozerPlugin {
     }
 }
 
-fn switch_to_bulldozer_on_b_key(
+fn switch_tool_on_bulldozer_input(
     mut on_request: EventWriter<OnRoadDrawerToolChangeRequested>,
     current_tool: Res<State<RoadDrawerTool>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    action_input: ActionInput,
 ) {
-    if keyboard_input.just_pressed(KeyCode::KeyB) {
+    if action_input.just_pressed(InputAction::ToggleBulldozer) {
         let new_value = match current_tool.get() {
             RoadDrawerTool::Bulldozer => RoadDrawerTool::Drawer,
             _ => RoadDrawerTool::Bulldozer,
@@ -52,9 +53,11 @@ fn delete_road_sections_that_are_clicked_on(
     mut commands: Commands,
     world_interaction: Res<WorldInteraction>,
     road_section_query: Query<&RoadSection>,
+    structure_collider_query: Query<&Parent, With<RoadStructureCollider>>,
+    keybindings: Res<Keybindings>,
 ) {
     for _ in on_interaction.read().filter(|event| {
-        event.button == MouseButton::Left
+        keybindings.is_mouse_button(InputAction::UseDrawerTool, event.button)
             && event.phase == InteractionPhase::Started
             && !event.is_on_ui
     }) {
@@ -62,11 +65,12 @@ fn delete_road_sections_that_are_clicked_on(
             continue;
         };
 
-        delete_road_section(
-            interaction_target.entity,
-            &mut commands,
-            &road_section_query,
-        );
+        // The structures along a road section have their own collider, which is a child of the section
+        let road_section_entity = structure_collider_query
+            .get(interaction_target.entity)
+            .map_or(interaction_target.entity, Parent::get);
+
+        delete_road_section(road_section_entity, &mut commands, &road_section_query);
     }
 }
 
@@ -79,7 +83,7 @@ fn delete_road_section(
         return;
     };
 
-    commands.entity(road_section_to_delete).despawn();
+    commands.entity(road_section_to_delete).despawn_recursive();
 
     for end in road_section.ends {
         if count_sections_connected_to_road_node(end.road_node_entity, road_section_query) == 1 {
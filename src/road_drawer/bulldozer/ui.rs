@@ -1,15 +1,16 @@
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::prelude::*;
 
 use crate::{
     game_modes::GameMode,
     road_drawer::road_drawer_tool::{
         OnRoadDrawerToolChangeRequested, OnRoadDrawerToolChanged, RoadDrawerTool,
     },
+    ui::theme::{ThemeColor, Themed},
     GameRunningSet,
 };
 
-const BUTTON_COLOR: Srgba = NEUTRAL_500;
-const BUTTON_ACTIVE_COLOR: Srgba = YELLOW_500;
+const BUTTON_COLOR: ThemeColor = ThemeColor::Control;
+const BUTTON_ACTIVE_COLOR: ThemeColor = ThemeColor::Warning;
 
 pub struct BulldozerUiPlugin;
 
@@ -49,7 +50,7 @@ fn send_request_on_button_pressed(
 
 fn change_button_color_on_tool_changed(
     mut on_changed: EventReader<OnRoadDrawerToolChanged>,
-    mut button_query: Query<&mut BackgroundColor, With<BulldozerUiButton>>,
+    mut button_query: Query<&mut Themed, With<BulldozerUiButton>>,
 ) {
     for event in on_changed.read() {
         let new_color = match event.tool {
@@ -57,6 +58,7 @@ fn change_button_color_on_tool_changed(
             _ => BUTTON_COLOR,
         };
 
-        button_query.single_mut().0 = new_color.into();
+        let mut themed = button_query.single_mut();
+        *themed = themed.with_background_color(new_color.into());
     }
 }
@@ -11,7 +11,9 @@ use section_end_being_drawn::SectionEndBeingDrawn;
 
 use crate::{
     game_modes::GameMode,
+    keybindings::{ActionInput, InputAction, Keybindings},
     road::{road_node::RoadNode, road_section::road_section_builder::OnBuildRoadSectionRequested},
+    settings::Settings,
     utility::circular_arc::CircularArc,
     world::world_interaction::{
         interaction_target::OnWorldInteractionTargetUpdated,
@@ -23,9 +25,6 @@ use crate::{
 
 use super::{road_drawer_tool::RoadDrawerTool, selected_road::SelectedRoad, RoadDrawer};
 
-const MOUSE_BUTTON_TO_DRAW: MouseButton = MouseButton::Left;
-const ROAD_NODE_SNAP_DISTANCE: f32 = 5.0;
-
 // TODO: Curved section being drawn should set it's start direction to mouse position until mouse is pressed (instead of only setting it on mouse press)
 //          - maybe add a boolean like "is_setting_start_direction" and update either start direction or curve arc in update system based on this bool
 // TODO: right-clicking a curve being drawn should allow you to set a new start direction (instead of cancelling the whole section)
@@ -42,7 +41,7 @@ impl Plugin for RoadBeingDrawnPlugin {
                     update_road_being_drawn_on_target_update,
                     set_curved_section_direction_on_mouse_press,
                     start_drawing_road_on_mouse_press,
-                    cancel_road_on_right_click,
+                    cancel_road_on_cancel_input,
                 )
                     .chain()
                     .in_set(GameRunningSet::UpdateEntities),
@@ -64,9 +63,11 @@ fn start_drawing_road_on_mouse_press(
     world_interaction: Res<WorldInteraction>,
     road_node_query: Query<(Entity, &Transform), With<RoadNode>>,
     selected_road: Res<SelectedRoad>,
+    keybindings: Res<Keybindings>,
+    settings: Res<Settings>,
 ) {
     for _ in on_interaction.read().filter(|event| {
-        event.button == MOUSE_BUTTON_TO_DRAW
+        keybindings.is_mouse_button(InputAction::UseDrawerTool, event.button)
             && event.phase == InteractionPhase::Started
             && !event.is_on_ui
     }) {
@@ -82,7 +83,7 @@ fn start_drawing_road_on_mouse_press(
         let nearest_road_node = NearestRoadNode::find_from_position(
             &road_node_query,
             target_position,
-            ROAD_NODE_SNAP_DISTANCE,
+            settings.road_node_snap_distance,
         );
 
         let road_section_end = SectionEndBeingDrawn::new(target_position, nearest_road_node);
@@ -100,6 +101,7 @@ fn update_road_being_drawn_on_target_update(
     mut on_target_updated: EventReader<OnWorldInteractionTargetUpdated>,
     mut road_drawer: ResMut<RoadDrawer>,
     road_node_query: Query<(Entity, &Transform), With<RoadNode>>,
+    settings: Res<Settings>,
 ) {
     for interaction_target in on_target_updated
         .read()
@@ -112,7 +114,7 @@ fn update_road_being_drawn_on_target_update(
         let nearest_road_node = NearestRoadNode::find_from_position(
             &road_node_query,
             interaction_target.position,
-            ROAD_NODE_SNAP_DISTANCE,
+            settings.road_node_snap_distance,
         );
 
         let snapped_target_position = match nearest_road_node {
@@ -151,9 +153,10 @@ fn set_curved_section_direction_on_mouse_press(
     mut on_interaction: EventReader<OnMouseInteraction>,
     mut road_drawer: ResMut<RoadDrawer>,
     world_interaction: Res<WorldInteraction>,
+    keybindings: Res<Keybindings>,
 ) {
     for _ in on_interaction.read().filter(|event| {
-        event.button == MOUSE_BUTTON_TO_DRAW
+        keybindings.is_mouse_button(InputAction::UseDrawerTool, event.button)
             && event.phase == InteractionPhase::Started
             && !event.is_on_ui
     }) {
@@ -172,8 +175,6 @@ fn set_curved_section_direction_on_mouse_press(
                 continue;
             }
 
-            println!("Set start direction");
-
             curved_section.start_direction =
                 get_direction_from_to(snapped_start_position, interaction_target.position)
                     .map_err(|_| SectionBeingDrawnError::InvalidSectionLength)
@@ -185,9 +186,10 @@ fn send_build_section_request_on_mouse_press(
     mut on_interaction: EventReader<OnMouseInteraction>,
     mut on_request_section: EventWriter<OnBuildRoadSectionRequested>,
     mut road_drawer: ResMut<RoadDrawer>,
+    keybindings: Res<Keybindings>,
 ) {
     for _ in on_interaction.read().filter(|event| {
-        event.button == MOUSE_BUTTON_TO_DRAW
+        keybindings.is_mouse_button(InputAction::UseDrawerTool, event.button)
             && event.phase == InteractionPhase::Started
             && !event.is_on_ui
     }) {
@@ -204,13 +206,8 @@ fn send_build_section_request_on_mouse_press(
     }
 }
 
-fn cancel_road_on_right_click(
-    mut on_interaction: EventReader<OnMouseInteraction>,
-    mut road_drawer: ResMut<RoadDrawer>,
-) {
-    for _ in on_interaction.read().filter(|event| {
-        event.button == MouseButton::Right && event.phase == InteractionPhase::Started
-    }) {
+fn cancel_road_on_cancel_input(mut road_drawer: ResMut<RoadDrawer>, action_input: ActionInput) {
+    if action_input.just_pressed(InputAction::CancelDrawing) {
         road_drawer.section_being_drawn = None;
     }
 }
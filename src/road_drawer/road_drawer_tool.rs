@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{game_modes::GameMode, GameRunningSet};
+use crate::{game_modes::GameMode, ui::notifications::OnNotificationRequested, GameRunningSet};
 
 pub struct RoadDrawerToolPlugin;
 
@@ -49,10 +49,14 @@ impl OnRoadDrawerToolChanged {
 fn handle_tool_change_requests(
     mut requests: EventReader<OnRoadDrawerToolChangeRequested>,
     mut on_changed: EventWriter<OnRoadDrawerToolChanged>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
     mut next_tool_state: ResMut<NextState<RoadDrawerTool>>,
 ) {
     for request in requests.read() {
-        println!("Switch to tool: {:?}", request.tool);
+        on_notification.send(OnNotificationRequested::info(match request.tool {
+            RoadDrawerTool::Drawer => "Switched to the drawer",
+            RoadDrawerTool::Bulldozer => "Switched to the bulldozer",
+        }));
 
         next_tool_state.set(request.tool);
         on_changed.send(OnRoadDrawerToolChanged::new(request.tool));
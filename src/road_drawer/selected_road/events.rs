@@ -3,6 +3,7 @@ use bevy::prelude::*;
 use crate::{
     game_modes::GameMode,
     road::{persistance::load_road_data, road_data::RoadData},
+    ui::notifications::OnNotificationRequested,
     GameRunningSet,
 };
 
@@ -48,10 +49,20 @@ impl OnRoadSelected {
 fn handle_select_requests(
     mut requests: EventReader<OnSelectRoadRequested>,
     mut on_selected: EventWriter<OnRoadSelected>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
     mut selected_road: ResMut<SelectedRoad>,
 ) {
     for request in requests.read() {
-        let road_data = load_road_data(&request.road_name).expect("RoadData should load from file");
+        let road_data = match load_road_data(&request.road_name) {
+            Ok(road_data) => road_data,
+            Err(error) => {
+                on_notification.send(OnNotificationRequested::error(format!(
+                    "Failed to load \"{}\": {error}",
+                    request.road_name
+                )));
+                continue;
+            }
+        };
 
         selected_road.set_selected_road(road_data.clone());
         on_selected.send(OnRoadSelected::new(road_data));
@@ -10,6 +10,7 @@ use crate::{
         flexbox::FlexboxConfig,
         section::{SectionBuilder, SectionConfig},
         text::TextBuilder,
+        tooltip::Tooltip,
         UiComponentBuilder, UiComponentWithChildrenBuilder,
     },
     utility::despawn_component_recursive,
@@ -58,14 +59,25 @@ fn spawn_selected_road_ui(mut commands: Commands) {
                     .with_flexbox_config(FlexboxConfig::row().with_px_gap(20.0)),
             )
             .spawn(container, (), |section| {
-                TextButtonBuilder::default_with_text("Select road")
-                    .spawn(section, ButtonAction::LoadRoad);
+                TextButtonBuilder::default_with_text("Select road").spawn(
+                    section,
+                    (
+                        ButtonAction::LoadRoad,
+                        Tooltip::new("Choose the road design to draw with"),
+                    ),
+                );
 
                 TextBuilder::default()
                     .with_text("Selected road name")
                     .spawn(section, SelectedRoadName);
 
-                TextButtonBuilder::default_with_text("Bulldozer").spawn(section, BulldozerUiButton);
+                TextButtonBuilder::default_with_text("Bulldozer").spawn(
+                    section,
+                    (
+                        BulldozerUiButton,
+                        Tooltip::new("Remove road sections by clicking on them (B)"),
+                    ),
+                );
             });
         });
 }
@@ -1,9 +1,14 @@
+pub mod exit_protection;
+mod preview_interaction;
+
 use bevy::prelude::*;
+use exit_protection::ExitProtectionPlugin;
+use preview_interaction::PreviewInteractionPlugin;
 
 pub struct RoadEditorPlugin;
 
 impl Plugin for RoadEditorPlugin {
-    fn build(&self, _app: &mut App) {
-        // app.add_systems(Update, ());
+    fn build(&self, app: &mut App) {
+        app.add_plugins((ExitProtectionPlugin, PreviewInteractionPlugin));
     }
 }
@@ -0,0 +1,264 @@
+use bevy::{
+    ecs::system::SystemParam,
+    prelude::*,
+    window::{PrimaryWindow, WindowCloseRequested},
+};
+
+use crate::{
+    game_modes::GameMode,
+    keybindings::{ActionInput, InputAction},
+    road::{
+        active_road::ActiveRoad,
+        persistance::road_data_exists,
+        recovery::OnUnsavedChangesDiscarded,
+        road_data::RoadData,
+        road_validation::RoadDiagnostics,
+        save::{OnRoadSaveFailed, OnRoadSaved, OnSaveRoadRequested},
+    },
+    ui::{
+        keybindings_menu::binding_capture_is_active,
+        modal::confirm_dialog::{
+            ConfirmDialogChoice, ConfirmDialogConfig, ConfirmDialogPurpose, OnConfirmDialogClosed,
+            OnConfirmDialogRequested,
+        },
+        toolbar::save::{OnShowSaveModalRequested, SaveModal},
+    },
+    utility::shutdown,
+    GameRunningSet,
+};
+
+pub struct ExitProtectionPlugin;
+
+impl Plugin for ExitProtectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnLeaveEditorRequested>()
+            .add_systems(
+                Update,
+                handle_window_close_requests.in_set(GameRunningSet::GetUserInput),
+            )
+            .add_systems(
+                Update,
+                (
+                    send_leave_request_on_quit_input
+                        .in_set(GameRunningSet::GetUserInput)
+                        .run_if(not(binding_capture_is_active)),
+                    (
+                        handle_leave_requests,
+                        handle_exit_prompt_answers,
+                        leave_editor_when_saved,
+                    )
+                        .in_set(GameRunningSet::SendCommands),
+                    cancel_pending_leave_when_save_modal_closes
+                        .in_set(GameRunningSet::HandleCommands),
+                )
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+/// Where to go after leaving the road editor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaveEditorDestination {
+    MainMenu,
+    Desktop,
+}
+
+/// Requests to leave the road editor, the user is asked what to do with unsaved changes first.
+#[derive(Event)]
+pub struct OnLeaveEditorRequested {
+    destination: LeaveEditorDestination,
+}
+
+impl OnLeaveEditorRequested {
+    pub fn new(destination: LeaveEditorDestination) -> Self {
+        Self { destination }
+    }
+
+    pub fn destination(&self) -> LeaveEditorDestination {
+        self.destination
+    }
+}
+
+/// A leave request that waits for the road to be saved, the editor is only left once saving succeeded.
+#[derive(Resource)]
+struct PendingLeave {
+    destination: LeaveEditorDestination,
+    /// Whether the save modal has been opened for this request, closing it without saving cancels leaving.
+    save_modal_was_open: bool,
+    save_was_requested: bool,
+}
+
+fn send_leave_request_on_quit_input(
+    action_input: ActionInput,
+    mut on_leave: EventWriter<OnLeaveEditorRequested>,
+) {
+    if action_input.just_pressed(InputAction::Quit) {
+        on_leave.send(OnLeaveEditorRequested::new(LeaveEditorDestination::Desktop));
+    }
+}
+
+/// Closing the window leaves the road editor like quitting does, in the other game modes the app exits right away.
+fn handle_window_close_requests(
+    mut on_close_requested: EventReader<WindowCloseRequested>,
+    mut on_leave: EventWriter<OnLeaveEditorRequested>,
+    mut on_exit: EventWriter<AppExit>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    game_mode: Res<State<GameMode>>,
+) {
+    if on_close_requested.read().count() == 0 {
+        return;
+    }
+
+    match game_mode.get() {
+        GameMode::RoadEditor => {
+            on_leave.send(OnLeaveEditorRequested::new(LeaveEditorDestination::Desktop));
+        }
+        _ => shutdown(&mut window_query, &mut on_exit),
+    }
+}
+
+fn handle_leave_requests(
+    mut requests: EventReader<OnLeaveEditorRequested>,
+    mut on_confirm_request: EventWriter<OnConfirmDialogRequested>,
+    mut editor_exit: EditorExit,
+    active_road: Res<ActiveRoad>,
+) {
+    for request in requests.read() {
+        if !active_road.has_unsaved_changes() {
+            editor_exit.leave(request.destination());
+            continue;
+        }
+
+        on_confirm_request.send(OnConfirmDialogRequested::new(
+            ConfirmDialogConfig::new(
+                ConfirmDialogPurpose::LeaveEditor(request.destination()),
+                "Unsaved changes",
+                "This road has unsaved changes",
+            )
+            .with_confirm_label("Save")
+            .with_alternative_label("Discard"),
+        ));
+    }
+}
+
+fn handle_exit_prompt_answers(
+    mut on_closed: EventReader<OnConfirmDialogClosed>,
+    mut on_save_request: EventWriter<OnSaveRoadRequested>,
+    mut on_save_modal_request: EventWriter<OnShowSaveModalRequested>,
+    mut editor_exit: EditorExit,
+    mut commands: Commands,
+    active_road: Res<ActiveRoad>,
+    road_diagnostics: Res<RoadDiagnostics>,
+) {
+    for event in on_closed.read() {
+        let ConfirmDialogPurpose::LeaveEditor(destination) = event.purpose() else {
+            continue;
+        };
+
+        match event.choice() {
+            ConfirmDialogChoice::Confirm => {
+                let road_data = active_road.road_data();
+
+                // Roads that were never saved need a name, and errors have to be confirmed in the save modal
+                let needs_save_modal =
+                    !road_data_exists(road_data.name()) || road_diagnostics.has_errors();
+
+                if needs_save_modal {
+                    on_save_modal_request.send(OnShowSaveModalRequested);
+                } else {
+                    on_save_request.send(OnSaveRoadRequested::new(road_data.clone()));
+                }
+
+                commands.insert_resource(PendingLeave {
+                    destination,
+                    save_modal_was_open: false,
+                    save_was_requested: !needs_save_modal,
+                });
+            }
+            ConfirmDialogChoice::Alternative => editor_exit.discard_changes_and_leave(destination),
+            ConfirmDialogChoice::Cancel => (),
+        }
+    }
+}
+
+/// Leaves the editor once the road is saved, a failed save keeps the user in the editor.
+fn leave_editor_when_saved(
+    mut on_saved: EventReader<OnRoadSaved>,
+    mut on_save_failed: EventReader<OnRoadSaveFailed>,
+    mut editor_exit: EditorExit,
+    mut commands: Commands,
+    pending_leave: Option<Res<PendingLeave>>,
+    active_road: Res<ActiveRoad>,
+) {
+    // Always read the events, so saves from before the leave request aren't seen later on
+    let was_saved = on_saved
+        .read()
+        .any(|event| is_active_road(event.road(), &active_road));
+    let save_failed = on_save_failed
+        .read()
+        .any(|event| is_active_road(event.road(), &active_road));
+
+    let Some(pending_leave) = pending_leave else {
+        return;
+    };
+
+    if save_failed {
+        commands.remove_resource::<PendingLeave>();
+    } else if was_saved {
+        commands.remove_resource::<PendingLeave>();
+        editor_exit.leave(pending_leave.destination);
+    }
+}
+
+/// Returns true if the given road data is the active road's design, which may have been saved under another name.
+fn is_active_road(road_data: &RoadData, active_road: &ActiveRoad) -> bool {
+    let mut active_road_data = active_road.road_data().clone();
+    active_road_data.set_name(road_data.name().to_string());
+
+    *road_data == active_road_data
+}
+
+fn cancel_pending_leave_when_save_modal_closes(
+    mut on_save_request: EventReader<OnSaveRoadRequested>,
+    mut commands: Commands,
+    pending_leave: Option<ResMut<PendingLeave>>,
+    save_modal_query: Query<(), With<SaveModal>>,
+) {
+    let save_was_requested = on_save_request.read().count() > 0;
+
+    let Some(mut pending_leave) = pending_leave else {
+        return;
+    };
+
+    pending_leave.save_was_requested |= save_was_requested;
+
+    if !save_modal_query.is_empty() {
+        pending_leave.save_modal_was_open = true;
+    } else if pending_leave.save_modal_was_open && !pending_leave.save_was_requested {
+        commands.remove_resource::<PendingLeave>();
+    }
+}
+
+/// Leaves the road editor for the main menu or the desktop.
+#[derive(SystemParam)]
+struct EditorExit<'w, 's> {
+    next_game_mode: ResMut<'w, NextState<GameMode>>,
+    window_query: Query<'w, 's, &'static mut Window, With<PrimaryWindow>>,
+    on_exit: EventWriter<'w, AppExit>,
+    on_discarded: EventWriter<'w, OnUnsavedChangesDiscarded>,
+}
+
+impl EditorExit<'_, '_> {
+    fn leave(&mut self, destination: LeaveEditorDestination) {
+        match destination {
+            LeaveEditorDestination::MainMenu => self.next_game_mode.set(GameMode::MainMenu),
+            LeaveEditorDestination::Desktop => shutdown(&mut self.window_query, &mut self.on_exit),
+        }
+    }
+
+    /// Leaves the road editor, without keeping the unsaved changes around for recovery.
+    fn discard_changes_and_leave(&mut self, destination: LeaveEditorDestination) {
+        self.on_discarded.send(OnUnsavedChangesDiscarded);
+        self.leave(destination);
+    }
+}
@@ -0,0 +1,885 @@
+use bevy::{color::palettes::tailwind::*, prelude::*};
+
+use crate::{
+    game_modes::GameMode,
+    keybindings::{control_is_pressed, InputAction, Keybindings},
+    road::{
+        active_road::{
+            active_road_events::{
+                road_component_change::{
+                    OnRoadComponentChangeRequested, RoadComponentChange, RoadComponentFieldChange,
+                },
+                road_marking_change::{
+                    OnRoadMarkingChangeRequested, RoadMarkingChange, RoadMarkingFieldChange,
+                },
+            },
+            ActiveRoad,
+        },
+        road_component::{road_component_position::RoadComponentPositionField, RoadComponent},
+        road_data::RoadData,
+        road_marking::road_marking_events::show_road_marking_config::OnShowRoadMarkingConfigRequested,
+        road_preview::PreviewPath,
+    },
+    settings::Settings,
+    ui::{
+        components::{
+            text::{TextBuilder, TextConfig},
+            UiComponentBuilder,
+        },
+        list::ListItem,
+        theme::{ThemeColor, ThemeFontSize, ThemeSpacing, Themed},
+        toolbar::components::{
+            selected_road_component::OnRoadComponentSelected, RoadComponentItem,
+        },
+    },
+    utility::{despawn_component_recursive, mouse_on_ui::MouseOnUi},
+    world::world_interaction::{
+        mouse_interaction_events::{InteractionPhase, OnMouseInteraction},
+        WorldInteraction,
+    },
+    GameRunningSet,
+};
+
+/// How close to the edge of a component the cursor needs to be to drag that edge, in meters.
+const EDGE_GRAB_DISTANCE: f32 = 0.25;
+/// The smallest width a component can be dragged to, so its edges can still be grabbed.
+const MIN_DRAGGED_WIDTH: f32 = 0.1;
+/// The largest width a component can be dragged to, the same as the width input allows.
+const MAX_DRAGGED_WIDTH: f32 = 10.0;
+/// How close to a road marking the cursor needs to be to drag it, for markings thinner than this, in meters.
+const MARKING_GRAB_DISTANCE: f32 = 0.15;
+/// How close to the edge or center of a component a dragged marking snaps onto it, in meters.
+const MARKING_SNAP_DISTANCE: f32 = 0.2;
+/// Lifts the gizmos above the preview's surface, so they aren't hidden by it.
+const GIZMO_HEIGHT_OFFSET: f32 = 0.01;
+/// Lifts the road markings above the component gizmos.
+const MARKING_GIZMO_HEIGHT_OFFSET: f32 = 0.02;
+/// The length of the lines at both ends of the marking offset gizmo.
+const OFFSET_GIZMO_END_LENGTH: f32 = 0.5;
+const HOVERED_COMPONENT_GIZMO_COLOR: Srgba = SKY_300;
+const SELECTED_COMPONENT_GIZMO_COLOR: Srgba = AMBER_400;
+const EDGE_GIZMO_COLOR: Srgba = LIME_400;
+const ACTIVE_MARKING_GIZMO_COLOR: Srgba = LIME_400;
+const MARKING_OFFSET_GIZMO_COLOR: Srgba = FUCHSIA_400;
+
+pub struct PreviewInteractionPlugin;
+
+impl Plugin for PreviewInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PreviewInteraction>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        update_hovered_parts,
+                        select_or_start_drag_on_mouse_press,
+                        (drag_component_edge, drag_road_marking),
+                        end_drag_on_mouse_release,
+                    )
+                        .chain()
+                        .in_set(GameRunningSet::GetUserInput),
+                    update_marking_offset_label.in_set(GameRunningSet::UpdateEntities),
+                    (draw_preview_interaction_gizmos, draw_road_marking_gizmos)
+                        .in_set(GameRunningSet::DrawGizmos),
+                )
+                    .run_if(in_state(GameMode::RoadEditor)),
+            )
+            .add_systems(OnEnter(GameMode::RoadEditor), spawn_marking_offset_label)
+            .add_systems(
+                OnExit(GameMode::RoadEditor),
+                (
+                    reset_preview_interaction,
+                    despawn_component_recursive::<MarkingOffsetLabel>,
+                ),
+            );
+    }
+}
+
+/// What the cursor is doing with the road preview.
+#[derive(Resource, Default, Debug)]
+struct PreviewInteraction {
+    hovered: Option<PreviewHover>,
+    /// The offset on the road's cross-section of the point on the preview under the cursor.
+    hovered_point: Option<f32>,
+    /// The road marking under the cursor, markings are hovered instead of the component they're on.
+    hovered_marking: Option<usize>,
+    edge_drag: Option<EdgeDrag>,
+    marking_drag: Option<MarkingDrag>,
+}
+
+impl PreviewInteraction {
+    /// Returns the road marking that is being dragged, or otherwise the one that is hovered.
+    fn active_marking(&self) -> Option<usize> {
+        self.marking_drag
+            .map(|marking_drag| marking_drag.road_marking_index)
+            .or(self.hovered_marking)
+    }
+}
+
+/// The road component under the cursor, and the edge of it that is close enough to drag.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PreviewHover {
+    component_index: usize,
+    edge: Option<RoadComponentPositionField>,
+}
+
+/// A component edge that is being dragged to change the width of the component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct EdgeDrag {
+    component_index: usize,
+    edge: RoadComponentPositionField,
+    start_point: f32,
+    start_width: f32,
+    /// How far the dragged edge moves when the component gets 1 meter wider, which depends on the road's reference line.
+    edge_movement: f32,
+    /// The cursor keeps following the height of the component's top face, also when it leaves the road.
+    height: f32,
+}
+
+/// A road marking that is being dragged across the road.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct MarkingDrag {
+    road_marking_index: usize,
+    /// The cursor keeps following the height of the surface the marking started on.
+    height: f32,
+}
+
+/// Shows how far the active road marking is from the nearest component edge.
+#[derive(Component)]
+struct MarkingOffsetLabel;
+
+impl EdgeDrag {
+    fn new(
+        road_data: &RoadData,
+        component_index: usize,
+        edge: RoadComponentPositionField,
+        start_point: f32,
+    ) -> Self {
+        let road_component = &road_data.components()[component_index];
+
+        Self {
+            component_index,
+            edge,
+            start_point,
+            start_width: road_component.width(),
+            edge_movement: calculate_edge_movement(road_data, component_index, edge),
+            height: road_component.height(),
+        }
+    }
+
+    /// Returns the width that keeps the dragged edge under the cursor.
+    fn width_at(&self, point: f32) -> f32 {
+        let delta_point = point - self.start_point;
+
+        let delta_width = if self.edge_movement.abs() > f32::EPSILON {
+            delta_point / self.edge_movement
+        } else {
+            // The edge is on the reference line and stays in place, so dragging it outwards widens the component
+            match self.edge {
+                RoadComponentPositionField::Left => -delta_point,
+                _ => delta_point,
+            }
+        };
+
+        (self.start_width + delta_width).clamp(MIN_DRAGGED_WIDTH, MAX_DRAGGED_WIDTH)
+    }
+}
+
+/// Returns how far the given edge of a component moves when the component gets 1 meter wider.
+///
+/// Positions are relative to the reference line, so this depends on how far the reference line moves along.
+fn calculate_edge_movement(
+    road_data: &RoadData,
+    component_index: usize,
+    edge: RoadComponentPositionField,
+) -> f32 {
+    let components_left_of_edge = match edge {
+        RoadComponentPositionField::Left => component_index,
+        _ => component_index + 1,
+    };
+
+    let edge_position = |road_components: &[RoadComponent]| {
+        let distance_to_edge: f32 = road_components
+            .iter()
+            .take(components_left_of_edge)
+            .map(RoadComponent::width)
+            .sum();
+
+        distance_to_edge
+            - road_data
+                .reference_line()
+                .distance_from_left_edge(road_components)
+    };
+
+    let mut widened_components = road_data.components().to_vec();
+    widened_components[component_index].size.x += 1.0;
+
+    edge_position(&widened_components) - edge_position(road_data.components())
+}
+
+fn update_hovered_parts(
+    mut preview_interaction: ResMut<PreviewInteraction>,
+    world_interaction: Res<WorldInteraction>,
+    mouse_on_ui: Res<MouseOnUi>,
+    active_road: Res<ActiveRoad>,
+    preview_path: Res<PreviewPath>,
+) {
+    let road_data = active_road.road_data();
+    let point = match (world_interaction.interaction_ray(), mouse_on_ui.is_on_ui()) {
+        (Some(ray), false) => find_point_on_preview(ray, road_data, &preview_path),
+        _ => None,
+    };
+
+    let hovered_marking = point.and_then(|point| find_hovered_marking(road_data, point));
+    let hovered = match hovered_marking {
+        Some(_) => None,
+        None => point.and_then(|point| find_hovered_component(road_data, point)),
+    };
+
+    if preview_interaction.hovered != hovered
+        || preview_interaction.hovered_point != point
+        || preview_interaction.hovered_marking != hovered_marking
+    {
+        preview_interaction.hovered = hovered;
+        preview_interaction.hovered_point = point;
+        preview_interaction.hovered_marking = hovered_marking;
+    }
+}
+
+fn select_or_start_drag_on_mouse_press(
+    mut on_interaction: EventReader<OnMouseInteraction>,
+    mut on_selected: EventWriter<OnRoadComponentSelected>,
+    mut preview_interaction: ResMut<PreviewInteraction>,
+    active_road: Res<ActiveRoad>,
+    keybindings: Res<Keybindings>,
+    road_component_item_query: Query<(Entity, &ListItem, &RoadComponentItem)>,
+) {
+    for _ in on_interaction.read().filter(|event| {
+        keybindings.is_mouse_button(InputAction::EditPreview, event.button)
+            && event.phase == InteractionPhase::Started
+            && !event.is_on_ui
+    }) {
+        if let Some(road_marking_index) = preview_interaction.hovered_marking {
+            let road_data = active_road.road_data();
+            let x_position = road_data.markings()[road_marking_index].x_position;
+
+            preview_interaction.marking_drag = Some(MarkingDrag {
+                road_marking_index,
+                height: surface_height_at(road_data, x_position),
+            });
+            continue;
+        }
+
+        let Some(hovered) = preview_interaction.hovered else {
+            continue;
+        };
+
+        // The hovered point is on top of the hovered component, so at the height the edge is dragged at
+        if let (Some(edge), Some(start_point)) = (hovered.edge, preview_interaction.hovered_point) {
+            preview_interaction.edge_drag = Some(EdgeDrag::new(
+                active_road.road_data(),
+                hovered.component_index,
+                edge,
+                start_point,
+            ));
+        }
+
+        if let Some((item_entity, _, _)) =
+            road_component_item_query
+                .iter()
+                .find(|(_, list_item, road_component_item)| {
+                    list_item.index() == hovered.component_index
+                        && !road_component_item.is_selected()
+                })
+        {
+            on_selected.send(OnRoadComponentSelected::new(
+                hovered.component_index,
+                item_entity,
+            ));
+        }
+    }
+}
+
+fn drag_component_edge(
+    mut on_change_request: EventWriter<OnRoadComponentChangeRequested>,
+    preview_interaction: Res<PreviewInteraction>,
+    world_interaction: Res<WorldInteraction>,
+    active_road: Res<ActiveRoad>,
+    preview_path: Res<PreviewPath>,
+) {
+    let Some(edge_drag) = preview_interaction.edge_drag else {
+        return;
+    };
+
+    let Some(point) = world_interaction
+        .interaction_ray()
+        .and_then(|ray| offset_at_height(ray, edge_drag.height, &preview_path))
+    else {
+        return;
+    };
+
+    let width = edge_drag.width_at(point);
+
+    if width
+        == active_road
+            .component_at_index(edge_drag.component_index)
+            .width()
+    {
+        return;
+    }
+
+    on_change_request.send(OnRoadComponentChangeRequested::new(
+        RoadComponentChange::new(
+            RoadComponentFieldChange::Width(width),
+            edge_drag.component_index,
+        ),
+    ));
+}
+
+/// Moves the dragged road marking to the cursor, snapping it to the edges and centers of components.
+///
+/// Holding Ctrl places the marking without snapping.
+fn drag_road_marking(
+    mut on_change_request: EventWriter<OnRoadMarkingChangeRequested>,
+    preview_interaction: Res<PreviewInteraction>,
+    world_interaction: Res<WorldInteraction>,
+    active_road: Res<ActiveRoad>,
+    preview_path: Res<PreviewPath>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    let Some(marking_drag) = preview_interaction.marking_drag else {
+        return;
+    };
+
+    let Some(point) = world_interaction
+        .interaction_ray()
+        .and_then(|ray| offset_at_height(ray, marking_drag.height, &preview_path))
+    else {
+        return;
+    };
+
+    let road_data = active_road.road_data();
+    let point = point.clamp(road_data.left_edge(), road_data.right_edge());
+    let x_position = match control_is_pressed(&keyboard_input) {
+        true => point,
+        false => snap_marking_position(road_data, point),
+    };
+
+    if x_position == road_data.markings()[marking_drag.road_marking_index].x_position {
+        return;
+    }
+
+    on_change_request.send(OnRoadMarkingChangeRequested::new(RoadMarkingChange::new(
+        RoadMarkingFieldChange::XPosition(x_position),
+        marking_drag.road_marking_index,
+    )));
+}
+
+/// Stops dragging, and shows the config of what was dragged so it shows the new values.
+fn end_drag_on_mouse_release(
+    mut on_interaction: EventReader<OnMouseInteraction>,
+    mut on_selected: EventWriter<OnRoadComponentSelected>,
+    mut on_show_marking_config: EventWriter<OnShowRoadMarkingConfigRequested>,
+    mut preview_interaction: ResMut<PreviewInteraction>,
+    keybindings: Res<Keybindings>,
+    road_component_item_query: Query<(Entity, &ListItem), With<RoadComponentItem>>,
+) {
+    for _ in on_interaction.read().filter(|event| {
+        keybindings.is_mouse_button(InputAction::EditPreview, event.button)
+            && event.phase == InteractionPhase::Released
+    }) {
+        if let Some(marking_drag) = preview_interaction.marking_drag.take() {
+            on_show_marking_config.send(OnShowRoadMarkingConfigRequested::new(
+                marking_drag.road_marking_index,
+            ));
+        }
+
+        let Some(edge_drag) = preview_interaction.edge_drag.take() else {
+            continue;
+        };
+
+        if let Some((item_entity, _)) = road_component_item_query
+            .iter()
+            .find(|(_, list_item)| list_item.index() == edge_drag.component_index)
+        {
+            on_selected.send(OnRoadComponentSelected::new(
+                edge_drag.component_index,
+                item_entity,
+            ));
+        }
+    }
+}
+
+fn draw_preview_interaction_gizmos(
+    mut gizmos: Gizmos,
+    preview_interaction: Res<PreviewInteraction>,
+    active_road: Res<ActiveRoad>,
+    preview_path: Res<PreviewPath>,
+    road_component_item_query: Query<(&ListItem, &RoadComponentItem)>,
+) {
+    let road_data = active_road.road_data();
+
+    if let Some((list_item, _)) = road_component_item_query
+        .iter()
+        .find(|(_, road_component_item)| road_component_item.is_selected())
+    {
+        draw_component_outline(
+            &mut gizmos,
+            road_data,
+            list_item.index(),
+            &preview_path,
+            SELECTED_COMPONENT_GIZMO_COLOR,
+        );
+    }
+
+    let highlighted_edge = match (preview_interaction.edge_drag, preview_interaction.hovered) {
+        (Some(edge_drag), _) => Some((edge_drag.component_index, edge_drag.edge)),
+        (None, Some(hovered)) => {
+            draw_component_outline(
+                &mut gizmos,
+                road_data,
+                hovered.component_index,
+                &preview_path,
+                HOVERED_COMPONENT_GIZMO_COLOR,
+            );
+
+            hovered.edge.map(|edge| (hovered.component_index, edge))
+        }
+        (None, None) => None,
+    };
+
+    if let Some((component_index, edge)) = highlighted_edge {
+        let (Some(component_position), Some(road_component)) = (
+            road_data.component_positions().get(component_index),
+            road_data.components().get(component_index),
+        ) else {
+            return;
+        };
+
+        gizmos.linestrip(
+            preview_path.points_at_offset(
+                component_position.get_field(edge),
+                road_component.height() + GIZMO_HEIGHT_OFFSET,
+            ),
+            EDGE_GIZMO_COLOR,
+        );
+    }
+}
+
+/// Draws the road markings on the preview, since the road mesh doesn't include them yet.
+///
+/// The active marking is highlighted, together with its offset from the nearest component edge.
+fn draw_road_marking_gizmos(
+    mut gizmos: Gizmos,
+    preview_interaction: Res<PreviewInteraction>,
+    active_road: Res<ActiveRoad>,
+    preview_path: Res<PreviewPath>,
+) {
+    let road_data = active_road.road_data();
+
+    for (index, road_marking) in road_data.enumerate_markings() {
+        let height =
+            surface_height_at(road_data, road_marking.x_position) + MARKING_GIZMO_HEIGHT_OFFSET;
+        let half_width = road_marking.segment_width / 2.0;
+
+        draw_outline_along_path(
+            &mut gizmos,
+            &preview_path,
+            road_marking.x_position - half_width,
+            road_marking.x_position + half_width,
+            height,
+            road_marking.color,
+        );
+
+        if preview_interaction.active_marking() != Some(index) {
+            continue;
+        }
+
+        draw_outline_along_path(
+            &mut gizmos,
+            &preview_path,
+            road_marking.x_position - half_width - MARKING_GRAB_DISTANCE / 2.0,
+            road_marking.x_position + half_width + MARKING_GRAB_DISTANCE / 2.0,
+            height,
+            ACTIVE_MARKING_GIZMO_COLOR,
+        );
+
+        let Some(edge) = find_nearest_component_edge(road_data, road_marking.x_position) else {
+            continue;
+        };
+
+        let position = preview_path.point_at(0.5, road_marking.x_position, height);
+        let edge_position = preview_path.point_at(0.5, edge, height);
+        let end_offset = preview_path.direction_at(0.5) * OFFSET_GIZMO_END_LENGTH / 2.0;
+
+        gizmos.line(edge_position, position, MARKING_OFFSET_GIZMO_COLOR);
+
+        for end_position in [edge_position, position] {
+            gizmos.line(
+                end_position - end_offset,
+                end_position + end_offset,
+                MARKING_OFFSET_GIZMO_COLOR,
+            );
+        }
+    }
+}
+
+/// Places a label with the offset of the active road marking from the nearest component edge, in the chosen unit.
+fn update_marking_offset_label(
+    mut label_query: Query<(&mut Style, &Children), With<MarkingOffsetLabel>>,
+    mut label_text_query: Query<&mut Text>,
+    preview_interaction: Res<PreviewInteraction>,
+    active_road: Res<ActiveRoad>,
+    preview_path: Res<PreviewPath>,
+    settings: Res<Settings>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    let Ok((mut style, children)) = label_query.get_single_mut() else {
+        return;
+    };
+
+    let road_data = active_road.road_data();
+    let label_content = preview_interaction
+        .active_marking()
+        .and_then(|index| road_data.markings().get(index))
+        .and_then(|road_marking| {
+            let edge = find_nearest_component_edge(road_data, road_marking.x_position)?;
+            let surface_height = surface_height_at(road_data, road_marking.x_position);
+            let center =
+                preview_path.point_at(0.5, (edge + road_marking.x_position) / 2.0, surface_height);
+
+            let (camera, camera_transform) = camera_query.get_single().ok()?;
+            let label_position = camera.world_to_viewport(camera_transform, center)?;
+
+            let text = settings
+                .length_unit
+                .format_meters((road_marking.x_position - edge).abs());
+
+            Some((label_position, text))
+        });
+
+    let Some((label_position, text)) = label_content else {
+        if style.display != Display::None {
+            style.display = Display::None;
+        }
+        return;
+    };
+
+    style.display = Display::Flex;
+    style.left = Val::Px(label_position.x);
+    style.top = Val::Px(label_position.y);
+
+    for child in children.iter() {
+        if let Ok(mut label_text) = label_text_query.get_mut(*child) {
+            label_text.sections[0].value.clone_from(&text);
+        }
+    }
+}
+
+/// Spawns the hidden label, it's shown while a road marking is hovered or dragged.
+fn spawn_marking_offset_label(mut commands: Commands) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                border_radius: BorderRadius::all(Val::Px(4.0)),
+                z_index: ZIndex::Global(90),
+                ..default()
+            },
+            Themed::default()
+                .with_padding(ThemeSpacing::Small.into())
+                .with_background_color(ThemeColor::SunkenSurface.into()),
+            MarkingOffsetLabel,
+        ))
+        .with_children(|label| {
+            TextBuilder::new(TextConfig {
+                font_size: ThemeFontSize::Small.into(),
+                ..default()
+            })
+            .spawn(label, ());
+        });
+}
+
+/// Outlines the top face of a road component along the whole preview.
+fn draw_component_outline(
+    gizmos: &mut Gizmos,
+    road_data: &RoadData,
+    component_index: usize,
+    preview_path: &PreviewPath,
+    color: Srgba,
+) {
+    let (Some(component_position), Some(road_component)) = (
+        road_data.component_positions().get(component_index),
+        road_data.components().get(component_index),
+    ) else {
+        return;
+    };
+
+    draw_outline_along_path(
+        gizmos,
+        preview_path,
+        component_position.left,
+        component_position.right,
+        road_component.height() + GIZMO_HEIGHT_OFFSET,
+        color,
+    );
+}
+
+/// Outlines the area between two offsets along the whole preview.
+fn draw_outline_along_path(
+    gizmos: &mut Gizmos,
+    preview_path: &PreviewPath,
+    left: f32,
+    right: f32,
+    height: f32,
+    color: impl Into<Color>,
+) {
+    let left_points = preview_path.points_at_offset(left, height);
+    let right_points = preview_path.points_at_offset(right, height);
+    let first_point = left_points.first().copied();
+
+    gizmos.linestrip(
+        left_points
+            .into_iter()
+            .chain(right_points.into_iter().rev())
+            .chain(first_point),
+        color,
+    );
+}
+
+fn reset_preview_interaction(mut preview_interaction: ResMut<PreviewInteraction>) {
+    *preview_interaction = PreviewInteraction::default();
+}
+
+// Utility
+
+/// Returns the horizontal position on the road where the ray hits the top of the preview.
+///
+/// The horizontal position is the offset on the road's cross-section, also when the preview is curved.
+fn find_point_on_preview(
+    ray: Ray3d,
+    road_data: &RoadData,
+    preview_path: &PreviewPath,
+) -> Option<f32> {
+    road_data
+        .enumerate_components()
+        .filter_map(|(index, road_component)| {
+            let distance = ray.intersect_plane(
+                Vec3::Y * road_component.height(),
+                InfinitePlane3d::new(Vec3::Y),
+            )?;
+            let point = ray.get_point(distance);
+            let offset = preview_path.offset_of_point(point);
+            let component_position = road_data.component_positions()[index];
+
+            let is_on_component = preview_path.is_along_path(point)
+                && offset >= component_position.left
+                && offset <= component_position.right;
+
+            is_on_component.then_some((distance, offset))
+        })
+        .min_by(|(distance, _), (other_distance, _)| distance.total_cmp(other_distance))
+        .map(|(_, point)| point)
+}
+
+fn find_hovered_component(road_data: &RoadData, point: f32) -> Option<PreviewHover> {
+    let component_under_point = road_data.find_road_component_under_point(point)?;
+    let component_position =
+        road_data.component_positions()[component_under_point.road_component_index];
+
+    let edge = match component_under_point.closest_position_field {
+        RoadComponentPositionField::Center => None,
+        edge => ((point - component_position.get_field(edge)).abs() <= EDGE_GRAB_DISTANCE)
+            .then_some(edge),
+    };
+
+    Some(PreviewHover {
+        component_index: component_under_point.road_component_index,
+        edge,
+    })
+}
+
+/// Returns the road marking under the point, the closest one if they overlap.
+fn find_hovered_marking(road_data: &RoadData, point: f32) -> Option<usize> {
+    road_data
+        .enumerate_markings()
+        .map(|(index, road_marking)| (index, (point - road_marking.x_position).abs(), road_marking))
+        .filter(|(_, distance, road_marking)| {
+            *distance <= (road_marking.segment_width / 2.0).max(MARKING_GRAB_DISTANCE)
+        })
+        .min_by(|(_, distance, _), (_, other_distance, _)| distance.total_cmp(other_distance))
+        .map(|(index, _, _)| index)
+}
+
+/// Returns the edge or center of a component that is close enough to the point, or the point itself.
+fn snap_marking_position(road_data: &RoadData, point: f32) -> f32 {
+    road_data
+        .component_positions()
+        .iter()
+        .flat_map(|position| [position.left, position.center, position.right])
+        .filter(|snap_position| (point - snap_position).abs() <= MARKING_SNAP_DISTANCE)
+        .min_by(|a, b| (point - a).abs().total_cmp(&(point - b).abs()))
+        .unwrap_or(point)
+}
+
+fn find_nearest_component_edge(road_data: &RoadData, point: f32) -> Option<f32> {
+    road_data
+        .component_positions()
+        .iter()
+        .flat_map(|position| [position.left, position.right])
+        .min_by(|a, b| (point - a).abs().total_cmp(&(point - b).abs()))
+}
+
+/// Returns the height of the top of the road at the point, or 0 next to the road.
+fn surface_height_at(road_data: &RoadData, point: f32) -> f32 {
+    road_data
+        .find_road_component_under_point(point)
+        .map(|component_under_point| {
+            road_data.components()[component_under_point.road_component_index].height()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the offset on the road's cross-section where the ray crosses the given height.
+fn offset_at_height(ray: Ray3d, height: f32, preview_path: &PreviewPath) -> Option<f32> {
+    ray.intersect_plane(Vec3::Y * height, InfinitePlane3d::new(Vec3::Y))
+        .map(|distance| preview_path.offset_of_point(ray.get_point(distance)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use crate::road::{
+        road_data::road_with_raised_sidewalk, road_marking::RoadMarking,
+        road_reference_line::RoadReferenceLine,
+    };
+
+    use super::*;
+
+    fn downwards_ray_at(x: f32) -> Ray3d {
+        Ray3d::new(Vec3::new(x, 10.0, 0.0), Vec3::NEG_Y)
+    }
+
+    #[test]
+    fn ray_hits_the_top_of_the_component_under_it() {
+        let road_data = road_with_raised_sidewalk();
+        let straight_preview = PreviewPath::new(20.0, 0.0);
+
+        assert_eq!(
+            find_point_on_preview(downwards_ray_at(2.5), &road_data, &straight_preview),
+            Some(2.5)
+        );
+        assert_eq!(
+            find_point_on_preview(downwards_ray_at(5.0), &road_data, &straight_preview),
+            None
+        );
+    }
+
+    #[test]
+    fn ray_hits_the_component_under_it_on_a_curved_preview() {
+        let road_data = road_with_raised_sidewalk();
+        let curved_preview = PreviewPath::new(20.0, FRAC_PI_2);
+        let ray = Ray3d::new(curved_preview.point_at(0.25, 2.5, 10.0), Vec3::NEG_Y);
+
+        let point = find_point_on_preview(ray, &road_data, &curved_preview).unwrap();
+
+        assert!((point - 2.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn only_edges_within_grab_distance_can_be_dragged() {
+        let road_data = road_with_raised_sidewalk();
+
+        assert_eq!(
+            find_hovered_component(&road_data, 0.9),
+            Some(PreviewHover {
+                component_index: 0,
+                edge: Some(RoadComponentPositionField::Right),
+            })
+        );
+        assert_eq!(
+            find_hovered_component(&road_data, 0.0),
+            Some(PreviewHover {
+                component_index: 0,
+                edge: None,
+            })
+        );
+    }
+
+    #[test]
+    fn markings_snap_to_nearby_component_edges_and_centers() {
+        let road_data = road_with_raised_sidewalk();
+
+        assert_eq!(snap_marking_position(&road_data, 1.1), 1.0);
+        assert_eq!(snap_marking_position(&road_data, -1.05), -1.0);
+        assert_eq!(snap_marking_position(&road_data, 0.5), 0.5);
+    }
+
+    #[test]
+    fn markings_are_hovered_instead_of_their_component() {
+        let mut road_data = road_with_raised_sidewalk();
+        road_data
+            .markings_mut()
+            .push(RoadMarking::new(-2.8, 0.15, Color::WHITE));
+
+        assert_eq!(find_hovered_marking(&road_data, -2.7), Some(0));
+        assert_eq!(find_hovered_marking(&road_data, -2.0), None);
+        assert_eq!(find_nearest_component_edge(&road_data, -2.8), Some(-3.0));
+    }
+
+    #[test]
+    fn dragged_edge_stays_under_the_cursor() {
+        let road_data = road_with_raised_sidewalk();
+        let edge_drag = EdgeDrag::new(&road_data, 0, RoadComponentPositionField::Left, -3.0);
+
+        assert_eq!(edge_drag.width_at(-3.5), 5.0);
+        assert_eq!(edge_drag.width_at(100.0), MIN_DRAGGED_WIDTH);
+
+        for reference_line in [
+            RoadReferenceLine::LeftEdge,
+            RoadReferenceLine::RightEdge,
+            RoadReferenceLine::ComponentBoundary(1),
+        ] {
+            for (component_index, edge) in [
+                (0, RoadComponentPositionField::Left),
+                (1, RoadComponentPositionField::Right),
+            ] {
+                let mut road_data = road_with_raised_sidewalk();
+                road_data.set_reference_line(reference_line);
+
+                let start_point = road_data.component_positions()[component_index].get_field(edge);
+                let edge_drag = EdgeDrag::new(&road_data, component_index, edge, start_point);
+
+                // Dragging outwards, the edge follows unless it's the one the road is anchored on
+                let point = match edge {
+                    RoadComponentPositionField::Left => start_point - 1.0,
+                    _ => start_point + 1.0,
+                };
+
+                road_data.components_mut()[component_index].size.x = edge_drag.width_at(point);
+                road_data.recalculate_road_component_positions();
+
+                let new_position = road_data.component_positions()[component_index].get_field(edge);
+                let is_anchored = new_position == start_point;
+
+                assert!(
+                    new_position == point || is_anchored,
+                    "{reference_line:?} {edge:?}: {new_position} != {point}"
+                );
+                assert_eq!(
+                    road_data.components()[component_index].width(),
+                    match component_index {
+                        0 => 5.0,
+                        _ => 3.0,
+                    },
+                    "{reference_line:?} {edge:?}"
+                );
+            }
+        }
+    }
+}
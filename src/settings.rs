@@ -0,0 +1,268 @@
+use std::{fs, io};
+
+use bevy::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ui::{
+        cross_section_view::CrossSectionViewMode,
+        theme::{OnUiThemeChangeRequested, UiThemeKind},
+    },
+    utility::distance::LengthUnit,
+    GameRunningSet,
+};
+
+const SETTINGS_PATH: &str = "assets/settings.ron";
+
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Settings>()
+            .add_event::<OnSettingChangeRequested>()
+            .add_event::<OnSettingChanged>()
+            .add_systems(Startup, load_settings_on_startup)
+            .add_systems(
+                Update,
+                (
+                    handle_setting_change_requests.in_set(GameRunningSet::HandleCommands),
+                    request_theme_change_on_setting_changed.in_set(GameRunningSet::SendCommands),
+                ),
+            );
+    }
+}
+
+/// The user's preferences, saved between runs.
+///
+/// Changes are requested with [OnSettingChangeRequested], every applied change is sent as an [OnSettingChanged] event.
+/// All settings are sent as changes once after loading, so systems that apply them only need to listen to that event.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Multiplies how fast the camera moves.
+    pub camera_movement_speed: f32,
+    /// Multiplies how fast the camera rotates.
+    pub camera_rotation_speed: f32,
+    /// Multiplies how fast the camera zooms.
+    pub camera_zoom_speed: f32,
+    /// How far away the road drawer can place points, in meters.
+    pub max_interaction_distance: f32,
+    /// How close to an existing road node a new point snaps onto it, in meters.
+    pub road_node_snap_distance: f32,
+    /// The length of the road shown in the road editor, in meters.
+    pub preview_road_length: f32,
+    /// How far the road in the road editor curves, in degrees, positive angles curve to the right.
+    ///
+    /// The preview is straight at 0 degrees, its length is kept along the curve.
+    pub preview_curve_angle: f32,
+    pub length_unit: LengthUnit,
+    pub theme: UiThemeKind,
+    /// Where the 2D cross-section of the road is shown in the road editor.
+    pub cross_section_view: CrossSectionViewMode,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            camera_movement_speed: 1.0,
+            camera_rotation_speed: 1.0,
+            camera_zoom_speed: 1.0,
+            max_interaction_distance: 400.0,
+            road_node_snap_distance: 5.0,
+            preview_road_length: 20.0,
+            preview_curve_angle: 0.0,
+            length_unit: LengthUnit::default(),
+            theme: UiThemeKind::default(),
+            cross_section_view: CrossSectionViewMode::default(),
+        }
+    }
+}
+
+impl Settings {
+    fn apply(&mut self, change: SettingChange) {
+        match change {
+            SettingChange::CameraMovementSpeed(value) => self.camera_movement_speed = value,
+            SettingChange::CameraRotationSpeed(value) => self.camera_rotation_speed = value,
+            SettingChange::CameraZoomSpeed(value) => self.camera_zoom_speed = value,
+            SettingChange::MaxInteractionDistance(value) => self.max_interaction_distance = value,
+            SettingChange::RoadNodeSnapDistance(value) => self.road_node_snap_distance = value,
+            SettingChange::PreviewRoadLength(value) => self.preview_road_length = value,
+            SettingChange::PreviewCurveAngle(value) => self.preview_curve_angle = value,
+            SettingChange::LengthUnit(length_unit) => self.length_unit = length_unit,
+            SettingChange::Theme(theme) => self.theme = theme,
+            SettingChange::CrossSectionView(mode) => self.cross_section_view = mode,
+        }
+    }
+
+    /// Returns every setting as a change, to apply all settings at once.
+    fn as_changes(&self) -> [SettingChange; 10] {
+        [
+            SettingChange::CameraMovementSpeed(self.camera_movement_speed),
+            SettingChange::CameraRotationSpeed(self.camera_rotation_speed),
+            SettingChange::CameraZoomSpeed(self.camera_zoom_speed),
+            SettingChange::MaxInteractionDistance(self.max_interaction_distance),
+            SettingChange::RoadNodeSnapDistance(self.road_node_snap_distance),
+            SettingChange::PreviewRoadLength(self.preview_road_length),
+            SettingChange::PreviewCurveAngle(self.preview_curve_angle),
+            SettingChange::LengthUnit(self.length_unit),
+            SettingChange::Theme(self.theme),
+            SettingChange::CrossSectionView(self.cross_section_view),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SettingChange {
+    CameraMovementSpeed(f32),
+    CameraRotationSpeed(f32),
+    CameraZoomSpeed(f32),
+    MaxInteractionDistance(f32),
+    RoadNodeSnapDistance(f32),
+    PreviewRoadLength(f32),
+    PreviewCurveAngle(f32),
+    LengthUnit(LengthUnit),
+    Theme(UiThemeKind),
+    CrossSectionView(CrossSectionViewMode),
+}
+
+/// Changes a setting and saves all settings.
+#[derive(Event)]
+pub struct OnSettingChangeRequested {
+    change: SettingChange,
+}
+
+impl OnSettingChangeRequested {
+    pub fn new(change: SettingChange) -> Self {
+        Self { change }
+    }
+}
+
+/// Sent after a setting changed, or after the settings were loaded.
+#[derive(Event, Clone, Copy, Debug)]
+pub struct OnSettingChanged {
+    change: SettingChange,
+}
+
+impl OnSettingChanged {
+    pub fn new(change: SettingChange) -> Self {
+        Self { change }
+    }
+
+    pub fn change(&self) -> SettingChange {
+        self.change
+    }
+}
+
+fn load_settings_on_startup(
+    mut settings: ResMut<Settings>,
+    mut on_changed: EventWriter<OnSettingChanged>,
+) {
+    match load_settings() {
+        Ok(Some(loaded_settings)) => *settings = loaded_settings,
+        Ok(None) => (),
+        Err(error) => warn!("Failed to load settings: {error}"),
+    }
+
+    on_changed.send_batch(settings.as_changes().map(OnSettingChanged::new));
+}
+
+fn handle_setting_change_requests(
+    mut requests: EventReader<OnSettingChangeRequested>,
+    mut on_changed: EventWriter<OnSettingChanged>,
+    mut settings: ResMut<Settings>,
+) {
+    if requests.is_empty() {
+        return;
+    }
+
+    for request in requests.read() {
+        settings.apply(request.change);
+        on_changed.send(OnSettingChanged::new(request.change));
+    }
+
+    save_settings_or_warn(&settings);
+}
+
+fn request_theme_change_on_setting_changed(
+    mut on_changed: EventReader<OnSettingChanged>,
+    mut on_theme_request: EventWriter<OnUiThemeChangeRequested>,
+) {
+    for event in on_changed.read() {
+        if let SettingChange::Theme(theme) = event.change() {
+            on_theme_request.send(OnUiThemeChangeRequested::new(theme));
+        }
+    }
+}
+
+fn save_settings_or_warn(settings: &Settings) {
+    if let Err(error) = save_settings(settings) {
+        warn!("Failed to save settings: {error}");
+    }
+}
+
+fn save_settings(settings: &Settings) -> Result<(), ron::Error> {
+    Ok(fs::write(SETTINGS_PATH, serialize_settings(settings)?)?)
+}
+
+/// Returns the saved settings, or `None` if the settings have never been changed.
+fn load_settings() -> Result<Option<Settings>, ron::Error> {
+    match fs::read_to_string(SETTINGS_PATH) {
+        Ok(serialized_settings) => Ok(Some(deserialize_settings(&serialized_settings)?)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn serialize_settings(settings: &Settings) -> Result<String, ron::Error> {
+    ron::ser::to_string_pretty(settings, PrettyConfig::new().struct_names(true))
+}
+
+fn deserialize_settings(serialized_settings: &str) -> Result<Settings, ron::Error> {
+    Ok(ron::from_str::<Settings>(serialized_settings)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_survive_ron_round_trip() {
+        let mut settings = Settings::default();
+        settings.apply(SettingChange::PreviewRoadLength(42.0));
+        settings.apply(SettingChange::LengthUnit(LengthUnit::Feet));
+        settings.apply(SettingChange::Theme(UiThemeKind::Light));
+        settings.apply(SettingChange::CrossSectionView(
+            CrossSectionViewMode::Alongside,
+        ));
+
+        let serialized = serialize_settings(&settings).unwrap();
+
+        assert_eq!(deserialize_settings(&serialized).unwrap(), settings);
+    }
+
+    #[test]
+    fn missing_settings_use_their_default() {
+        let settings = deserialize_settings("Settings(camera_zoom_speed: 3.0)").unwrap();
+
+        assert_eq!(settings.camera_zoom_speed, 3.0);
+        assert_eq!(
+            settings.road_node_snap_distance,
+            Settings::default().road_node_snap_distance
+        );
+    }
+
+    #[test]
+    fn applying_all_changes_restores_the_settings() {
+        let mut changed_settings = Settings::default();
+        changed_settings.apply(SettingChange::CameraMovementSpeed(2.5));
+        changed_settings.apply(SettingChange::MaxInteractionDistance(800.0));
+
+        let mut settings = Settings::default();
+        for change in changed_settings.as_changes() {
+            settings.apply(change);
+        }
+
+        assert_eq!(settings, changed_settings);
+    }
+}
@@ -1,23 +1,34 @@
 pub mod components;
+pub mod cross_section_view;
+pub mod diagnostics_panel;
+pub mod help_overlay;
 mod inputs;
+pub mod keybindings_menu;
 pub mod list;
 pub mod main_menu;
-mod modal;
+pub mod modal;
+pub mod notifications;
 pub mod pause_menu;
+pub mod settings_menu;
 pub mod sidebar;
+pub mod theme;
 pub mod toolbar;
 
 use bevy::prelude::*;
-use components::{
-    flexbox::{FlexboxBuilder, FlexboxConfig},
-    UiComponentWithChildrenBuilder, UiComponentsPlugin,
-};
+use components::UiComponentsPlugin;
+use cross_section_view::{spawn_cross_section_view, CrossSectionViewPlugin};
+use diagnostics_panel::{spawn_diagnostics_panel, DiagnosticsPanelPlugin};
+use help_overlay::HelpOverlayPlugin;
 use inputs::UiInputsPlugin;
+use keybindings_menu::KeybindingsMenuPlugin;
 use list::ListPlugin;
 use main_menu::MainMenuPlugin;
 use modal::ModalPlugin;
+use notifications::NotificationsPlugin;
 use pause_menu::PauseMenuPlugin;
+use settings_menu::SettingsMenuPlugin;
 use sidebar::{spawn_sidebar, SidebarPlugin};
+use theme::UiThemePlugin;
 use toolbar::{spawn_toolbar, ToolbarPlugin};
 
 use crate::{game_modes::GameMode, utility::despawn_component_recursive};
@@ -27,6 +38,7 @@ pub struct UiPlugin;
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((
+            UiThemePlugin,
             UiComponentsPlugin,
             ToolbarPlugin,
             SidebarPlugin,
@@ -35,6 +47,12 @@ impl Plugin for UiPlugin {
             ListPlugin,
             MainMenuPlugin,
             PauseMenuPlugin,
+            DiagnosticsPanelPlugin,
+            HelpOverlayPlugin,
+            NotificationsPlugin,
+            KeybindingsMenuPlugin,
+            SettingsMenuPlugin,
+            CrossSectionViewPlugin,
         ))
         .add_systems(OnEnter(GameMode::RoadEditor), spawn_editor_layout)
         .add_systems(
@@ -64,13 +82,23 @@ fn spawn_editor_layout(mut commands: Commands) {
     commands
         .spawn((container_node, EditorLayoutContainer))
         .with_children(|container| {
-            FlexboxBuilder::new(FlexboxConfig::row().with_justify(JustifyContent::Start)).spawn(
-                container,
-                (),
-                |left_side| {
-                    spawn_sidebar(left_side);
+            // Takes up the space above the toolbar, so the sidebar can scroll when its content is higher
+            let top_side_node = NodeBundle {
+                style: Style {
+                    justify_content: JustifyContent::SpaceBetween,
+                    align_items: AlignItems::FlexStart,
+                    flex_grow: 1.0,
+                    min_height: Val::ZERO,
+                    ..default()
                 },
-            );
+                ..default()
+            };
+
+            container.spawn(top_side_node).with_children(|top_side| {
+                spawn_sidebar(top_side);
+                spawn_cross_section_view(top_side);
+                spawn_diagnostics_panel(top_side);
+            });
 
             spawn_toolbar(container);
         });
@@ -1,20 +1,32 @@
 pub mod buttons;
+pub mod collapsible_section;
 pub mod content_size;
 pub mod content_wrap;
 pub mod flexbox;
 pub mod inputs;
+pub mod scroll_container;
 pub mod section;
 pub mod text;
+pub mod tooltip;
 
 use bevy::prelude::*;
 use buttons::ButtonsPlugin;
+use collapsible_section::CollapsibleSectionPlugin;
 use inputs::InputComponentsPlugin;
+use scroll_container::ScrollContainerPlugin;
+use tooltip::TooltipPlugin;
 
 pub struct UiComponentsPlugin;
 
 impl Plugin for UiComponentsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((ButtonsPlugin, InputComponentsPlugin));
+        app.add_plugins((
+            ButtonsPlugin,
+            InputComponentsPlugin,
+            ScrollContainerPlugin,
+            CollapsibleSectionPlugin,
+            TooltipPlugin,
+        ));
     }
 }
 
@@ -1,6 +1,9 @@
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::prelude::*;
 
-use crate::GameRunningSet;
+use crate::{
+    ui::theme::{ThemeColor, ThemedColor},
+    GameRunningSet,
+};
 
 use super::{
     content_size::ContentSizeConfig,
@@ -62,7 +65,7 @@ impl Default for ButtonConfig {
         Self {
             background_image: None,
             wrap: ContentWrapConfig::wide_element()
-                .with_background_color(NEUTRAL_500)
+                .with_background_color(ThemeColor::Control)
                 .with_all_px_border_radius(8.0),
             size: ContentSizeConfig::default(),
         }
@@ -90,10 +93,7 @@ impl ButtonBuilder {
     }
 
     #[expect(unused)]
-    pub fn with_background_color(
-        &mut self,
-        background_color: impl Into<BackgroundColor>,
-    ) -> &mut Self {
+    pub fn with_background_color(&mut self, background_color: impl Into<ThemedColor>) -> &mut Self {
         self.config.wrap.background_color = background_color.into();
         self
     }
@@ -112,20 +112,22 @@ impl UiComponentWithChildrenBuilder for ButtonBuilder {
             image_component,
             NodeBundle {
                 style: Style {
-                    padding: self.config.wrap.padding,
+                    padding: self.config.wrap.padding.initial_padding(),
                     border: self.config.wrap.border_size,
                     width: self.config.size.width,
                     height: self.config.size.height,
                     min_width: self.config.size.min_width,
                     min_height: self.config.size.min_height,
+                    max_height: self.config.size.max_height,
                     ..default()
                 },
-                background_color: self.config.wrap.background_color,
-                border_color: self.config.wrap.border_color,
+                background_color: self.config.wrap.background_color.initial_color().into(),
+                border_color: self.config.wrap.border_color.initial_color().into(),
                 border_radius: self.config.wrap.border_radius,
                 focus_policy: self.config.wrap.focus_policy,
                 ..default()
             },
+            self.config.wrap.themed(),
         )
     }
 }
@@ -176,7 +178,6 @@ impl TextButtonBuilder {
         self
     }
 
-    #[expect(unused)]
     pub fn with_text_config(&mut self, text_config: TextConfig) -> &mut Self {
         self.config.text = text_config;
         self
@@ -216,6 +217,10 @@ impl OnButtonPressed {
     pub fn is_action(&self, action: ButtonAction) -> bool {
         self.action == action
     }
+
+    pub fn action(&self) -> ButtonAction {
+        self.action
+    }
 }
 
 #[derive(Component, PartialEq, Clone, Copy)]
@@ -225,6 +230,17 @@ pub enum ButtonAction {
     AddComponent,
     DeleteComponent,
     ShowRoadMarkingConfig,
+    ShowRoadStructureConfig,
+    AddRoadStructure,
+    GroupWithNextComponent,
+    MoveComponentGroupLeft,
+    MoveComponentGroupRight,
+    DuplicateComponentGroup,
+    MirrorComponentGroup,
+    UngroupComponents,
+    DeleteComponentGroup,
+    SaveComponentGroupPreset,
+    ShowComponentGroupPresets,
 }
 
 fn send_button_pressed_events(
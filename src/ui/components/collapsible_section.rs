@@ -0,0 +1,170 @@
+use bevy::prelude::*;
+
+use crate::{utility::find_descendant_of_entity_mut, GameRunningSet};
+
+use super::{
+    buttons::TextButtonBuilder,
+    section::{SectionBuilder, SectionConfig},
+    UiComponentBuilder, UiComponentWithChildrenBuilder,
+};
+
+pub struct CollapsibleSectionPlugin;
+
+impl Plugin for CollapsibleSectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                toggle_section_on_header_press.in_set(GameRunningSet::GetUserInput),
+                update_collapsible_section_content.in_set(GameRunningSet::UpdateEntities),
+            ),
+        );
+    }
+}
+
+#[derive(Component)]
+pub struct CollapsibleSection {
+    title: String,
+    is_expanded: bool,
+}
+
+impl CollapsibleSection {
+    fn header_text(&self) -> String {
+        let indicator = match self.is_expanded {
+            true => '-',
+            false => '+',
+        };
+
+        format!("{indicator} {}", self.title)
+    }
+}
+
+#[derive(Component)]
+struct CollapsibleSectionHeader;
+
+#[derive(Component)]
+struct CollapsibleSectionBody;
+
+/// A section UiComponent with a header button that shows or hides its children.
+#[derive(Default)]
+pub struct CollapsibleSectionBuilder {
+    config: SectionConfig,
+    title: String,
+    is_expanded: bool,
+}
+
+impl CollapsibleSectionBuilder {
+    pub fn new(config: SectionConfig, title: impl Into<String>) -> Self {
+        Self {
+            config,
+            title: title.into(),
+            is_expanded: false,
+        }
+    }
+
+    /// Returns this component but with its children shown or hidden when spawned.
+    pub fn with_expanded(mut self, is_expanded: bool) -> Self {
+        self.is_expanded = is_expanded;
+        self
+    }
+
+    fn build_collapsible_section(&self) -> CollapsibleSection {
+        CollapsibleSection {
+            title: self.title.clone(),
+            is_expanded: self.is_expanded,
+        }
+    }
+}
+
+impl UiComponentWithChildrenBuilder for CollapsibleSectionBuilder {
+    fn spawn(
+        &self,
+        builder: &mut ChildBuilder,
+        components: impl Bundle,
+        children: impl FnOnce(&mut ChildBuilder),
+    ) -> Entity {
+        let section = self.build_collapsible_section();
+        let conf = &self.config.flexbox;
+
+        let body_node = NodeBundle {
+            style: Style {
+                display: match self.is_expanded {
+                    true => Display::Flex,
+                    false => Display::None,
+                },
+                flex_direction: conf.flex_direction,
+                justify_content: conf.justify_content,
+                align_items: conf.align_items,
+                flex_wrap: conf.flex_wrap,
+                row_gap: conf.row_gap,
+                column_gap: conf.column_gap,
+                ..default()
+            },
+            ..default()
+        };
+
+        SectionBuilder::new(self.config).spawn(builder, (components, self.build()), |wrapper| {
+            TextButtonBuilder::default_with_text(section.header_text())
+                .spawn(wrapper, CollapsibleSectionHeader);
+
+            wrapper
+                .spawn((body_node, CollapsibleSectionBody))
+                .with_children(children);
+        })
+    }
+
+    fn build(&self) -> impl Bundle {
+        self.build_collapsible_section()
+    }
+}
+
+fn toggle_section_on_header_press(
+    mut section_query: Query<&mut CollapsibleSection>,
+    header_query: Query<(Ref<Interaction>, &Parent), With<CollapsibleSectionHeader>>,
+) {
+    for (_, header_parent) in header_query.iter().filter(|(interaction, _)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        // The header is a direct child of its section, so nested sections toggle independently
+        let Ok(mut section) = section_query.get_mut(header_parent.get()) else {
+            continue;
+        };
+
+        section.is_expanded = !section.is_expanded;
+    }
+}
+
+fn update_collapsible_section_content(
+    section_query: Query<(Entity, &CollapsibleSection), Changed<CollapsibleSection>>,
+    mut body_query: Query<(Entity, &mut Style), With<CollapsibleSectionBody>>,
+    mut header_text_query: Query<(Entity, &mut Text)>,
+    header_query: Query<Entity, With<CollapsibleSectionHeader>>,
+    children_query: Query<&Children>,
+) {
+    for (section_entity, section) in section_query.iter() {
+        // Only direct children, so nested collapsible sections are left alone
+        let Ok(section_children) = children_query.get(section_entity) else {
+            continue;
+        };
+
+        for &child in section_children.iter() {
+            if let Ok((_, mut body_style)) = body_query.get_mut(child) {
+                body_style.display = match section.is_expanded {
+                    true => Display::Flex,
+                    false => Display::None,
+                };
+            }
+
+            if header_query.contains(child) {
+                if let Some((_, mut header_text)) = find_descendant_of_entity_mut(
+                    child,
+                    &mut header_text_query,
+                    |item| item.0,
+                    &children_query,
+                ) {
+                    header_text.sections[0].value = section.header_text();
+                }
+            }
+        }
+    }
+}
@@ -12,6 +12,8 @@ pub struct ContentSizeConfig {
     pub min_width: Val,
     /// The minimum height this component has to be, use only pixel values for consistency.
     pub min_height: Val,
+    /// The maximum height this component can be, use only percentage values for consistency.
+    pub max_height: Val,
 }
 
 impl ContentSizeConfig {
@@ -65,6 +67,12 @@ impl ContentSizeConfig {
         self.min_height = Val::Px(min_height);
         self
     }
+
+    /// Returns this component but with the given maximum height in percentages.
+    pub fn with_max_height(mut self, max_height: f32) -> Self {
+        self.max_height = Val::Percent(max_height);
+        self
+    }
 }
 
 impl Default for ContentSizeConfig {
@@ -74,6 +82,7 @@ impl Default for ContentSizeConfig {
             height: Val::Auto,
             min_width: Val::Auto,
             min_height: Val::Auto,
+            max_height: Val::Auto,
         }
     }
 }
@@ -92,6 +101,7 @@ impl UiComponentWithChildrenBuilder for ContentSizeBuilder {
                 height: self.config.height,
                 min_width: self.config.min_width,
                 min_height: self.config.min_height,
+                max_height: self.config.max_height,
                 ..default()
             },
             ..default()
@@ -1,13 +1,15 @@
-use bevy::{color::palettes::tailwind::*, prelude::*, ui::FocusPolicy};
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use crate::ui::theme::{ThemeColor, ThemeSpacing, Themed, ThemedColor, ThemedPadding};
 
 use super::UiComponentWithChildrenBuilder;
 
 #[derive(Clone, Copy)]
 pub struct ContentWrapConfig {
-    pub padding: UiRect,
-    pub background_color: BackgroundColor,
+    pub padding: ThemedPadding,
+    pub background_color: ThemedColor,
     pub border_size: UiRect,
-    pub border_color: BorderColor,
+    pub border_color: ThemedColor,
     pub border_radius: BorderRadius,
     pub focus_policy: FocusPolicy,
 }
@@ -15,7 +17,7 @@ pub struct ContentWrapConfig {
 impl ContentWrapConfig {
     pub fn empty() -> Self {
         Self {
-            padding: UiRect::ZERO,
+            padding: UiRect::ZERO.into(),
             background_color: Color::NONE.into(),
             ..default()
         }
@@ -23,13 +25,13 @@ impl ContentWrapConfig {
 
     pub fn wide_element() -> Self {
         Self {
-            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)),
+            padding: UiRect::axes(Val::Px(12.0), Val::Px(4.0)).into(),
             ..default()
         }
     }
 
-    pub fn with_padding(mut self, padding: UiRect) -> Self {
-        self.padding = padding;
+    pub fn with_padding(mut self, padding: impl Into<ThemedPadding>) -> Self {
+        self.padding = padding.into();
         self
     }
 
@@ -42,7 +44,7 @@ impl ContentWrapConfig {
         self.with_padding(UiRect::ZERO)
     }
 
-    pub fn with_background_color(mut self, background_color: impl Into<BackgroundColor>) -> Self {
+    pub fn with_background_color(mut self, background_color: impl Into<ThemedColor>) -> Self {
         self.background_color = background_color.into();
         self
     }
@@ -69,13 +71,21 @@ impl ContentWrapConfig {
         self.focus_policy = focus_policy;
         self
     }
+
+    /// Returns the theme parts of this wrap, to add to the node it's applied to.
+    pub fn themed(&self) -> Themed {
+        Themed::default()
+            .with_padding(self.padding)
+            .with_background_color(self.background_color)
+            .with_border_color(self.border_color)
+    }
 }
 
 impl Default for ContentWrapConfig {
     fn default() -> Self {
         Self {
-            padding: UiRect::all(Val::Px(12.0)),
-            background_color: NEUTRAL_700.into(),
+            padding: ThemeSpacing::Large.into(),
+            background_color: ThemeColor::Surface.into(),
             border_size: UiRect::ZERO,
             border_color: Color::NONE.into(),
             border_radius: BorderRadius::ZERO,
@@ -92,17 +102,20 @@ pub struct ContentWrapBuilder {
 
 impl UiComponentWithChildrenBuilder for ContentWrapBuilder {
     fn build(&self) -> impl Bundle {
-        NodeBundle {
-            style: Style {
-                padding: self.config.padding,
-                border: self.config.border_size,
+        (
+            NodeBundle {
+                style: Style {
+                    padding: self.config.padding.initial_padding(),
+                    border: self.config.border_size,
+                    ..default()
+                },
+                background_color: self.config.background_color.initial_color().into(),
+                border_color: self.config.border_color.initial_color().into(),
+                border_radius: self.config.border_radius,
+                focus_policy: self.config.focus_policy,
                 ..default()
             },
-            background_color: self.config.background_color,
-            border_color: self.config.border_color,
-            border_radius: self.config.border_radius,
-            focus_policy: self.config.focus_policy,
-            ..default()
-        }
+            self.config.themed(),
+        )
     }
 }
@@ -24,7 +24,6 @@ impl FlexboxConfig {
         }
     }
 
-    #[expect(unused)]
     pub fn vertically_centered_row() -> Self {
         Self {
             align_items: AlignItems::Center,
@@ -1,10 +1,14 @@
+pub mod checkbox;
 pub mod color_input;
+pub mod dropdown;
 pub mod number_input;
 pub mod slider_input;
 pub mod labeled_element;
 
 use bevy::prelude::*;
+use checkbox::CheckboxPlugin;
 use color_input::ColorInputPlugin;
+use dropdown::DropdownPlugin;
 use number_input::NumberInputPlugin;
 use slider_input::SliderInputPlugin;
 
@@ -12,6 +16,12 @@ pub struct InputComponentsPlugin;
 
 impl Plugin for InputComponentsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((NumberInputPlugin, SliderInputPlugin, ColorInputPlugin));
+        app.add_plugins((
+            NumberInputPlugin,
+            SliderInputPlugin,
+            ColorInputPlugin,
+            DropdownPlugin,
+            CheckboxPlugin,
+        ));
     }
 }
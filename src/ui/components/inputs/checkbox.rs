@@ -0,0 +1,342 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use crate::{
+    ui::{
+        components::{
+            buttons::{ButtonBuilder, ButtonConfig},
+            content_size::ContentSizeConfig,
+            content_wrap::ContentWrapConfig,
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        inputs::text_input::TextInput,
+        theme::{ThemeColor, Themed, UiTheme},
+    },
+    utility::find_descendant_of_entity_mut,
+    GameRunningSet,
+};
+
+const CHECKBOX_SIZE: f32 = 24.0;
+const TOGGLE_WIDTH: f32 = 44.0;
+const UNCHECKED_COLOR: ThemeColor = ThemeColor::SunkenSurface;
+const CHECKED_COLOR: ThemeColor = ThemeColor::Accent;
+
+pub struct CheckboxPlugin;
+
+impl Plugin for CheckboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnCheckboxValueChanged>().add_systems(
+            Update,
+            (
+                (toggle_checkbox_on_press, toggle_hovered_checkbox_on_space)
+                    .in_set(GameRunningSet::GetUserInput),
+                update_checkbox_display.in_set(GameRunningSet::UpdateEntities),
+            ),
+        );
+    }
+}
+
+// Start of new UiComponent code
+
+/// How a checkbox shows whether it's checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CheckboxAppearance {
+    /// A square that is filled when checked.
+    #[default]
+    Box,
+    /// A switch that slides to the right when checked.
+    Switch,
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct CheckboxConfig {
+    is_checked: bool,
+    appearance: CheckboxAppearance,
+}
+
+impl CheckboxConfig {
+    pub fn with_checked(mut self, is_checked: bool) -> Self {
+        self.is_checked = is_checked;
+        self
+    }
+
+    pub fn with_appearance(mut self, appearance: CheckboxAppearance) -> Self {
+        self.appearance = appearance;
+        self
+    }
+}
+
+/// A checkbox UiComponent to toggle a boolean value.
+#[derive(Default)]
+pub struct CheckboxBuilder {
+    config: CheckboxConfig,
+}
+
+impl CheckboxBuilder {
+    pub fn new(config: CheckboxConfig) -> Self {
+        Self { config }
+    }
+
+    fn build_checkbox(&self) -> Checkbox {
+        Checkbox {
+            is_checked: self.config.is_checked,
+            appearance: self.config.appearance,
+        }
+    }
+}
+
+impl UiComponentBuilder for CheckboxBuilder {
+    fn spawn(&self, builder: &mut ChildBuilder, components: impl Bundle) -> Entity {
+        let checkbox = self.build_checkbox();
+
+        let width = match self.config.appearance {
+            CheckboxAppearance::Box => CHECKBOX_SIZE,
+            CheckboxAppearance::Switch => TOGGLE_WIDTH,
+        };
+
+        let border_radius = match self.config.appearance {
+            CheckboxAppearance::Box => 4.0,
+            CheckboxAppearance::Switch => CHECKBOX_SIZE / 2.0,
+        };
+
+        ButtonBuilder::new(ButtonConfig {
+            wrap: ContentWrapConfig::empty()
+                .with_all_px_padding(4.0)
+                .with_all_px_border_radius(border_radius),
+            size: ContentSizeConfig {
+                width: Val::Px(width),
+                height: Val::Px(CHECKBOX_SIZE),
+                ..ContentSizeConfig::empty()
+            },
+            ..ButtonConfig::empty()
+        })
+        .spawn(builder, (components, self.build()), |checkbox_button| {
+            checkbox_button.spawn((
+                NodeBundle {
+                    style: checkbox.indicator_style(),
+                    border_radius: BorderRadius::all(Val::Px(border_radius / 2.0)),
+                    ..default()
+                },
+                Themed::default().with_background_color(ThemeColor::Text.into()),
+                CheckboxIndicator,
+            ));
+        })
+    }
+
+    fn build(&self) -> impl Bundle {
+        self.build_checkbox()
+    }
+}
+
+/// A toggle switch UiComponent, a checkbox with the switch appearance.
+#[derive(Default)]
+pub struct ToggleBuilder {
+    config: CheckboxConfig,
+}
+
+impl ToggleBuilder {
+    pub fn new(config: CheckboxConfig) -> Self {
+        Self { config }
+    }
+
+    fn switch_config(&self) -> CheckboxConfig {
+        self.config.with_appearance(CheckboxAppearance::Switch)
+    }
+}
+
+impl UiComponentBuilder for ToggleBuilder {
+    fn spawn(&self, builder: &mut ChildBuilder, components: impl Bundle) -> Entity {
+        CheckboxBuilder::new(self.switch_config()).spawn(builder, components)
+    }
+
+    fn build(&self) -> impl Bundle {
+        CheckboxBuilder::new(self.switch_config()).build_checkbox()
+    }
+}
+
+// End of new UiComponent code
+
+#[derive(Component, Debug)]
+pub struct Checkbox {
+    is_checked: bool,
+    appearance: CheckboxAppearance,
+}
+
+impl Checkbox {
+    #[expect(unused)]
+    pub fn is_checked(&self) -> bool {
+        self.is_checked
+    }
+
+    fn toggle(&mut self) {
+        self.is_checked = !self.is_checked;
+    }
+
+    fn background_color(&self) -> ThemeColor {
+        match (self.appearance, self.is_checked) {
+            (CheckboxAppearance::Box, _) | (CheckboxAppearance::Switch, false) => UNCHECKED_COLOR,
+            (CheckboxAppearance::Switch, true) => CHECKED_COLOR,
+        }
+    }
+
+    fn indicator_style(&self) -> Style {
+        let indicator_size = Val::Px(CHECKBOX_SIZE - 8.0);
+
+        match self.appearance {
+            CheckboxAppearance::Box => Style {
+                display: match self.is_checked {
+                    true => Display::Flex,
+                    false => Display::None,
+                },
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                ..default()
+            },
+            CheckboxAppearance::Switch => Style {
+                width: indicator_size,
+                height: indicator_size,
+                // An automatic left margin pushes the indicator to the right side
+                margin: match self.is_checked {
+                    true => UiRect::left(Val::Auto),
+                    false => UiRect::ZERO,
+                },
+                ..default()
+            },
+        }
+    }
+}
+
+#[derive(Component)]
+struct CheckboxIndicator;
+
+#[derive(Event)]
+pub struct OnCheckboxValueChanged {
+    checkbox_entity: Entity,
+    is_checked: bool,
+}
+
+impl OnCheckboxValueChanged {
+    pub fn new(checkbox_entity: Entity, is_checked: bool) -> Self {
+        Self {
+            checkbox_entity,
+            is_checked,
+        }
+    }
+
+    pub fn checkbox_entity(&self) -> Entity {
+        self.checkbox_entity
+    }
+
+    pub fn is_checked(&self) -> bool {
+        self.is_checked
+    }
+}
+
+fn toggle_checkbox_on_press(
+    mut on_changed: EventWriter<OnCheckboxValueChanged>,
+    mut checkbox_query: Query<(Entity, &mut Checkbox, Ref<Interaction>)>,
+) {
+    for (checkbox_entity, mut checkbox, _) in
+        checkbox_query.iter_mut().filter(|(_, _, interaction)| {
+            interaction.is_changed() && **interaction == Interaction::Pressed
+        })
+    {
+        checkbox.toggle();
+
+        on_changed.send(OnCheckboxValueChanged::new(
+            checkbox_entity,
+            checkbox.is_checked,
+        ));
+    }
+}
+
+fn toggle_hovered_checkbox_on_space(
+    mut on_changed: EventWriter<OnCheckboxValueChanged>,
+    mut checkbox_query: Query<(Entity, &mut Checkbox, &RelativeCursorPosition)>,
+    text_input_query: Query<&TextInput>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if !keyboard_input.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    // Space is typed into the selected text input instead
+    if text_input_query.iter().any(TextInput::is_selected) {
+        return;
+    }
+
+    for (checkbox_entity, mut checkbox, _) in checkbox_query
+        .iter_mut()
+        .filter(|(_, _, cursor_position)| cursor_position.mouse_over())
+    {
+        checkbox.toggle();
+
+        on_changed.send(OnCheckboxValueChanged::new(
+            checkbox_entity,
+            checkbox.is_checked,
+        ));
+    }
+}
+
+fn update_checkbox_display(
+    mut checkbox_query: Query<(Entity, Ref<Checkbox>, &mut BackgroundColor)>,
+    mut indicator_query: Query<(Entity, &mut Style), With<CheckboxIndicator>>,
+    children_query: Query<&Children>,
+    theme: Res<UiTheme>,
+) {
+    for (checkbox_entity, checkbox, mut background_color) in checkbox_query
+        .iter_mut()
+        .filter(|(_, checkbox, _)| checkbox.is_changed() || theme.is_changed())
+    {
+        background_color.0 = theme.color(checkbox.background_color());
+
+        if let Some((_, mut indicator_style)) = find_descendant_of_entity_mut(
+            checkbox_entity,
+            &mut indicator_query,
+            |item| item.0,
+            &children_query,
+        ) {
+            *indicator_style = checkbox.indicator_style();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_flips_checked_state() {
+        let mut checkbox = CheckboxBuilder::default().build_checkbox();
+
+        checkbox.toggle();
+        assert!(checkbox.is_checked);
+
+        checkbox.toggle();
+        assert!(!checkbox.is_checked);
+    }
+
+    #[test]
+    fn toggle_builder_uses_switch_appearance() {
+        let checkbox =
+            CheckboxBuilder::new(ToggleBuilder::default().switch_config()).build_checkbox();
+
+        assert_eq!(checkbox.appearance, CheckboxAppearance::Switch);
+        assert_eq!(checkbox.background_color(), UNCHECKED_COLOR);
+    }
+
+    #[test]
+    fn only_checked_switches_are_highlighted() {
+        let mut checkbox = CheckboxBuilder::new(
+            CheckboxConfig::default().with_appearance(CheckboxAppearance::Switch),
+        )
+        .build_checkbox();
+        checkbox.toggle();
+
+        assert_eq!(checkbox.background_color(), CHECKED_COLOR);
+
+        let mut checkbox = CheckboxBuilder::default().build_checkbox();
+        checkbox.toggle();
+
+        assert_eq!(checkbox.background_color(), UNCHECKED_COLOR);
+    }
+}
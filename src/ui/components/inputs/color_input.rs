@@ -1,33 +1,68 @@
-use bevy::{color::palettes::tailwind::*, prelude::*};
+pub mod color_mode;
+pub mod color_palette;
+pub mod eyedropper;
+
+use bevy::prelude::*;
+use color_mode::{ColorChannel, ColorMode};
+use color_palette::{spawn_color_palette, ColorPalettePlugin};
+use eyedropper::{EyedropperButton, EyedropperPlugin};
 
 use crate::{
-    ui::components::{
-        content_size::ContentSizeConfig,
-        content_wrap::ContentWrapConfig,
-        flexbox::FlexboxConfig,
-        inputs::slider_input::{OnSliderInputValueChanged, SliderInputBuilder, SliderInputConfig},
-        section::{SectionBuilder, SectionConfig},
-        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    ui::{
+        components::{
+            buttons::TextButtonBuilder,
+            content_size::ContentSizeConfig,
+            content_wrap::ContentWrapConfig,
+            flexbox::{FlexboxBuilder, FlexboxConfig},
+            inputs::slider_input::{
+                OnSliderInputValueChanged, SliderInput, SliderInputBuilder, SliderInputConfig,
+            },
+            section::{SectionBuilder, SectionConfig},
+            text::TextConfig,
+            tooltip::Tooltip,
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        inputs::text_input::{
+            default_text_config, spawn_configured_text_input_node, text_editor::TextConstraints,
+            OnTextInputValueChanged, TextInput, TextInputConfig,
+        },
+        theme::{ThemeColor, ThemeFontSize, Themed},
     },
     utility::{
-        filter_descendants_of_entity, find_ancestor_of_entity_mut, find_descendant_of_entity_mut,
+        find_ancestor_of_entity, find_ancestor_of_entity_mut, find_descendant_of_entity_mut,
         texture_builder::TextureBuilder,
     },
     GameRunningSet,
 };
 
+/// The amount of colors in each slider gradient, hue gradients need several steps to show every hue.
+const SLIDER_GRADIENT_STEPS: usize = 12;
+/// The maximum length of a hex color, for example `#RRGGBBAA`.
+const MAX_HEX_LENGTH: usize = 9;
+const COLOR_MODE_BUTTON_COLOR: ThemeColor = ThemeColor::RaisedSurface;
+const SELECTED_COLOR_MODE_BUTTON_COLOR: ThemeColor = ThemeColor::SunkenSurface;
+
 pub struct ColorInputPlugin;
 
 impl Plugin for ColorInputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<OnColorInputValueChanged>().add_systems(
-            Update,
-            (
-                send_color_input_changed_events.in_set(GameRunningSet::SendEvents),
-                (update_color_input_textures, update_color_input_display)
-                    .in_set(GameRunningSet::UpdateEntities),
-            ),
-        );
+        app.add_plugins((ColorPalettePlugin, EyedropperPlugin))
+            .add_event::<OnColorInputValueChanged>()
+            .add_systems(
+                Update,
+                (
+                    change_color_mode_on_button_press.in_set(GameRunningSet::GetUserInput),
+                    (send_color_input_changed_events, apply_typed_hex_colors)
+                        .in_set(GameRunningSet::SendEvents),
+                    (
+                        update_color_input_sliders,
+                        update_color_input_display,
+                        update_color_input_hex_field,
+                        update_color_mode_buttons,
+                    )
+                        .in_set(GameRunningSet::UpdateEntities),
+                ),
+            );
     }
 }
 
@@ -53,7 +88,7 @@ impl Default for ColorInputConfig {
             section: SectionConfig {
                 wrap: ContentWrapConfig::default()
                     .with_all_px_border_radius(8.0)
-                    .with_background_color(NEUTRAL_500),
+                    .with_background_color(ThemeColor::Control),
                 flexbox: FlexboxConfig::horizontally_centered_column().with_px_gap(4.0),
                 size: ContentSizeConfig::empty()
                     .with_full_width()
@@ -66,18 +101,17 @@ impl Default for ColorInputConfig {
 /// A color selector UiComponent.
 #[derive(Default)]
 pub struct ColorInputBuilder {
-    slider_image_a: Handle<Image>,
-    slider_image_b: Handle<Image>,
-    slider_image_c: Handle<Image>,
+    slider_images: [Handle<Image>; 3],
     config: ColorInputConfig,
 }
 
 impl ColorInputBuilder {
     pub fn new(config: ColorInputConfig, images: &mut Assets<Image>) -> Self {
+        let color_input = ColorInput::new(config.start_color);
+
         Self {
-            slider_image_a: images.add(generate_slider_image(config.start_color, ColorChannel::A)),
-            slider_image_b: images.add(generate_slider_image(config.start_color, ColorChannel::B)),
-            slider_image_c: images.add(generate_slider_image(config.start_color, ColorChannel::C)),
+            slider_images: ColorChannel::ALL
+                .map(|channel| images.add(generate_slider_image(&color_input, channel))),
             config,
         }
     }
@@ -87,7 +121,7 @@ impl ColorInputBuilder {
             wrap: ContentWrapConfig {
                 background_color: self.config.start_color.into(),
                 border_size: UiRect::all(Val::Px(4.0)),
-                border_color: NEUTRAL_700.into(),
+                border_color: ThemeColor::Border.into(),
                 ..ContentWrapConfig::empty()
             },
             flexbox: FlexboxConfig::default(),
@@ -104,32 +138,68 @@ impl UiComponentBuilder for ColorInputBuilder {
             builder,
             (components, self.build()),
             |color_input| {
-                SectionBuilder::new(self.get_color_input_display_config()).spawn(
+                FlexboxBuilder::new(FlexboxConfig::row().with_px_gap(4.0)).spawn(
                     color_input,
-                    ColorInputDisplay,
-                    |_| {},
+                    (),
+                    |mode_buttons| {
+                        for mode in ColorMode::ALL {
+                            spawn_small_text_button(
+                                mode_buttons,
+                                mode.label(),
+                                ColorModeButton::new(mode),
+                            );
+                        }
+                    },
                 );
 
-                spawn_slider_input(
-                    color_input,
-                    self.config.start_color,
-                    ColorChannel::A,
-                    self.slider_image_a.clone(),
-                );
-
-                spawn_slider_input(
+                SectionBuilder::new(self.get_color_input_display_config()).spawn(
                     color_input,
-                    self.config.start_color,
-                    ColorChannel::B,
-                    self.slider_image_b.clone(),
+                    ColorInputDisplay,
+                    |_| {},
                 );
 
-                spawn_slider_input(
-                    color_input,
-                    self.config.start_color,
-                    ColorChannel::C,
-                    self.slider_image_c.clone(),
-                );
+                FlexboxBuilder::new(
+                    FlexboxConfig::row()
+                        .with_align(AlignItems::Center)
+                        .with_px_gap(4.0),
+                )
+                .spawn(color_input, (), |hex_row| {
+                    spawn_configured_text_input_node(
+                        hex_row,
+                        ColorInputHexField,
+                        TextInputConfig {
+                            text: TextConfig {
+                                font_size: ThemeFontSize::Small.into(),
+                                ..default_text_config(color_to_hex(self.config.start_color))
+                            },
+                            constraints: TextConstraints::default()
+                                .with_max_length(MAX_HEX_LENGTH)
+                                .with_character_filter(is_hex_character),
+                        },
+                    );
+
+                    spawn_small_text_button(
+                        hex_row,
+                        "Pick",
+                        (
+                            EyedropperButton,
+                            Tooltip::new("Pick a color by clicking on the road, Esc to cancel"),
+                        ),
+                    );
+                });
+
+                for (channel, slider_image) in
+                    ColorChannel::ALL.into_iter().zip(&self.slider_images)
+                {
+                    spawn_slider_input(
+                        color_input,
+                        self.config.start_color,
+                        channel,
+                        slider_image.clone(),
+                    );
+                }
+
+                spawn_color_palette(color_input);
             },
         )
     }
@@ -144,11 +214,49 @@ impl UiComponentBuilder for ColorInputBuilder {
 #[derive(Component)]
 pub struct ColorInput {
     value: Color,
+    mode: ColorMode,
+    /// The channels of the value in the current mode, kept separately so hue isn't lost for grey colors.
+    channels: [f32; 3],
 }
 
 impl ColorInput {
     fn new(value: Color) -> Self {
-        Self { value }
+        let mode = ColorMode::default();
+
+        Self {
+            value,
+            mode,
+            channels: mode.channels_from_color(value),
+        }
+    }
+
+    pub fn value(&self) -> Color {
+        self.value
+    }
+
+    fn set_value(&mut self, value: Color) {
+        self.value = value;
+        self.channels = self.mode.channels_from_color(value);
+    }
+
+    fn set_mode(&mut self, mode: ColorMode) {
+        self.mode = mode;
+        self.channels = mode.channels_from_color(self.value);
+    }
+
+    fn set_channel(&mut self, channel: ColorChannel, new_value: f32) {
+        self.channels[channel.index()] = new_value;
+        self.value = self
+            .mode
+            .color_from_channels(self.channels, self.value.alpha());
+    }
+
+    /// Returns the color this input would have if the given channel had the given value.
+    fn color_with_channel(&self, channel: ColorChannel, new_value: f32) -> Color {
+        let mut channels = self.channels;
+        channels[channel.index()] = new_value;
+
+        self.mode.color_from_channels(channels, self.value.alpha())
     }
 }
 
@@ -163,30 +271,33 @@ impl ColorInputSlider {
     }
 }
 
-/// The different channels of a full color representation.  
-/// For example: red, green, and blue for RGB colors.
-#[derive(Clone, Copy, PartialEq, PartialOrd)]
-enum ColorChannel {
-    A,
-    B,
-    C,
+#[derive(Component)]
+struct ColorModeButton {
+    mode: ColorMode,
+}
+
+impl ColorModeButton {
+    fn new(mode: ColorMode) -> Self {
+        Self { mode }
+    }
 }
 
 #[derive(Component)]
 struct ColorInputDisplay;
 
+#[derive(Component)]
+struct ColorInputHexField;
+
 #[derive(Event)]
 pub struct OnColorInputValueChanged {
     color_input_entity: Entity,
-    color_channel: ColorChannel,
     new_color: Color,
 }
 
 impl OnColorInputValueChanged {
-    fn new(color_input_entity: Entity, color_channel: ColorChannel, new_color: Color) -> Self {
+    fn new(color_input_entity: Entity, new_color: Color) -> Self {
         Self {
             color_input_entity,
-            color_channel,
             new_color,
         }
     }
@@ -214,7 +325,6 @@ fn send_color_input_changed_events(
             continue;
         };
 
-        let color_channel = color_input_slider.color_channel;
         let (color_input_entity, mut color_input) = find_ancestor_of_entity_mut(
             color_input_slider_entity,
             &mut color_input_query,
@@ -223,62 +333,191 @@ fn send_color_input_changed_events(
         )
         .unwrap();
 
-        let new_color =
-            get_rgba_color_with_channel(color_input.value, color_channel, event.new_value());
-
-        color_input.value = new_color;
+        color_input.set_channel(color_input_slider.color_channel, event.new_value());
 
         on_color_changed.send(OnColorInputValueChanged::new(
             color_input_entity,
-            color_channel,
-            new_color,
+            color_input.value,
         ));
     }
 }
 
-fn update_color_input_textures(
-    mut on_color_changed: EventReader<OnColorInputValueChanged>,
+fn apply_typed_hex_colors(
+    mut on_text_changed: EventReader<OnTextInputValueChanged>,
+    mut on_color_changed: EventWriter<OnColorInputValueChanged>,
+    mut hex_field_query: Query<&mut TextInput, With<ColorInputHexField>>,
+    mut color_input_query: Query<(Entity, &mut ColorInput)>,
+    parent_query: Query<&Parent>,
+) {
+    for event in on_text_changed.read() {
+        let hex_field_entity = event.text_input_entity();
+
+        let Ok(mut hex_field) = hex_field_query.get_mut(hex_field_entity) else {
+            continue;
+        };
+
+        let Some((color_input_entity, mut color_input)) = find_ancestor_of_entity_mut(
+            hex_field_entity,
+            &mut color_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        match Srgba::hex(event.text()) {
+            Ok(color) => {
+                color_input.set_value(color.into());
+
+                on_color_changed.send(OnColorInputValueChanged::new(
+                    color_input_entity,
+                    color_input.value,
+                ));
+            }
+            // Show the current color again, so the field never shows a color that isn't applied
+            Err(_) => hex_field.set_text(color_to_hex(color_input.value)),
+        }
+    }
+}
+
+fn change_color_mode_on_button_press(
+    mut color_input_query: Query<(Entity, &mut ColorInput)>,
+    button_query: Query<(Entity, &Interaction, &ColorModeButton), Changed<Interaction>>,
+    parent_query: Query<&Parent>,
+) {
+    for (button_entity, _, mode_button) in button_query
+        .iter()
+        .filter(|(_, interaction, _)| **interaction == Interaction::Pressed)
+    {
+        let Some((_, mut color_input)) = find_ancestor_of_entity_mut(
+            button_entity,
+            &mut color_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        if color_input.mode != mode_button.mode {
+            color_input.set_mode(mode_button.mode);
+        }
+    }
+}
+
+fn update_color_input_sliders(
     mut images: ResMut<Assets<Image>>,
-    color_input_slider_query: Query<(Entity, &UiImage, &ColorInputSlider)>,
-    children_query: Query<&Children>,
+    color_input_query: Query<(Entity, &ColorInput), Changed<ColorInput>>,
+    mut color_input_slider_query: Query<(Entity, &UiImage, &ColorInputSlider, &mut SliderInput)>,
+    parent_query: Query<&Parent>,
 ) {
-    for event in on_color_changed.read() {
-        for (_, ui_image, slider) in filter_descendants_of_entity(
-            event.color_input_entity(),
-            &color_input_slider_query,
+    if color_input_query.is_empty() {
+        return;
+    }
+
+    for (slider_entity, ui_image, slider, mut slider_input) in color_input_slider_query.iter_mut() {
+        let Some((_, color_input)) = find_ancestor_of_entity(
+            slider_entity,
+            &color_input_query,
             |item| item.0,
-            &children_query,
-        )
-        .into_iter()
-        .filter(|(_, _, slider)| slider.color_channel != event.color_channel)
-        {
-            let image = images.get_mut(&ui_image.texture).unwrap();
+            &parent_query,
+        ) else {
+            continue;
+        };
 
-            *image = generate_slider_image(event.new_color, slider.color_channel);
+        if let Some(image) = images.get_mut(&ui_image.texture) {
+            *image = generate_slider_image(color_input, slider.color_channel);
+        }
+
+        let channel_value = color_input.channels[slider.color_channel.index()];
+        if slider_input.value() != channel_value {
+            slider_input.set_value(channel_value);
         }
     }
 }
 
 fn update_color_input_display(
-    mut on_color_changed: EventReader<OnColorInputValueChanged>,
+    color_input_query: Query<(Entity, &ColorInput), Changed<ColorInput>>,
     mut color_input_display_query: Query<(Entity, &mut BackgroundColor), With<ColorInputDisplay>>,
     children_query: Query<&Children>,
 ) {
-    for event in on_color_changed.read() {
-        let (_, mut background_color) = find_descendant_of_entity_mut(
-            event.color_input_entity(),
+    for (color_input_entity, color_input) in color_input_query.iter() {
+        let Some((_, mut background_color)) = find_descendant_of_entity_mut(
+            color_input_entity,
             &mut color_input_display_query,
             |item| item.0,
             &children_query,
-        )
-        .unwrap();
+        ) else {
+            continue;
+        };
 
-        *background_color = event.new_color.into();
+        *background_color = color_input.value.into();
+    }
+}
+
+fn update_color_input_hex_field(
+    color_input_query: Query<(Entity, &ColorInput), Changed<ColorInput>>,
+    mut hex_field_query: Query<(Entity, &mut TextInput), With<ColorInputHexField>>,
+    children_query: Query<&Children>,
+) {
+    for (color_input_entity, color_input) in color_input_query.iter() {
+        let Some((_, mut hex_field)) = find_descendant_of_entity_mut(
+            color_input_entity,
+            &mut hex_field_query,
+            |item| item.0,
+            &children_query,
+        ) else {
+            continue;
+        };
+
+        let hex = color_to_hex(color_input.value);
+
+        if hex_field.current_text() != hex && !hex_field.is_selected() {
+            hex_field.set_text(hex);
+        }
+    }
+}
+
+fn update_color_mode_buttons(
+    color_input_query: Query<(Entity, &ColorInput), Changed<ColorInput>>,
+    mut button_query: Query<(Entity, &ColorModeButton, &mut Themed)>,
+    parent_query: Query<&Parent>,
+) {
+    if color_input_query.is_empty() {
+        return;
+    }
+
+    for (button_entity, mode_button, mut themed) in button_query.iter_mut() {
+        let Some((_, color_input)) = find_ancestor_of_entity(
+            button_entity,
+            &color_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        let background_color = match color_input.mode == mode_button.mode {
+            true => SELECTED_COLOR_MODE_BUTTON_COLOR,
+            false => COLOR_MODE_BUTTON_COLOR,
+        };
+
+        // The theme applies the color, also when the theme changes later on
+        *themed = themed.with_background_color(background_color.into());
     }
 }
 
 // Utility
 
+fn spawn_small_text_button(builder: &mut ChildBuilder, text: &str, components: impl Bundle) {
+    TextButtonBuilder::default()
+        .with_text_config(TextConfig {
+            text: text.to_string(),
+            font_size: ThemeFontSize::Small.into(),
+            ..default()
+        })
+        .spawn(builder, components);
+}
+
 fn spawn_slider_input(
     builder: &mut ChildBuilder,
     start_color: Color,
@@ -287,32 +526,31 @@ fn spawn_slider_input(
 ) {
     SliderInputBuilder::new(
         SliderInputConfig::default()
-            .with_start_value(get_rgba_color_channel(start_color, color_channel))
+            .with_start_value(
+                ColorMode::default().channels_from_color(start_color)[color_channel.index()],
+            )
             .with_background_image(slider_image)
             .clone(),
     )
     .spawn(builder, ColorInputSlider::new(color_channel));
 }
 
-fn generate_slider_image(color: Color, channel: ColorChannel) -> Image {
-    TextureBuilder::image_from_colors(vec![
-        get_rgba_color_with_channel(color, channel, 0.0),
-        get_rgba_color_with_channel(color, channel, 1.0),
-    ])
+fn generate_slider_image(color_input: &ColorInput, channel: ColorChannel) -> Image {
+    TextureBuilder::image_from_colors(
+        (0..SLIDER_GRADIENT_STEPS)
+            .map(|step| {
+                let channel_value = step as f32 / (SLIDER_GRADIENT_STEPS - 1) as f32;
+
+                color_input.color_with_channel(channel, channel_value)
+            })
+            .collect(),
+    )
 }
 
-fn get_rgba_color_with_channel(color: Color, channel: ColorChannel, new_value: f32) -> Color {
-    match channel {
-        ColorChannel::A => color.to_srgba().with_red(new_value).into(),
-        ColorChannel::B => color.to_srgba().with_green(new_value).into(),
-        ColorChannel::C => color.to_srgba().with_blue(new_value).into(),
-    }
+fn color_to_hex(color: Color) -> String {
+    color.to_srgba().to_hex()
 }
 
-fn get_rgba_color_channel(color: Color, channel: ColorChannel) -> f32 {
-    match channel {
-        ColorChannel::A => color.to_srgba().red,
-        ColorChannel::B => color.to_srgba().green,
-        ColorChannel::C => color.to_srgba().blue,
-    }
+fn is_hex_character(character: char) -> bool {
+    character.is_ascii_hexdigit() || character == '#'
 }
@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+
+/// The color representation in which a color input shows its channels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorMode {
+    #[default]
+    Rgb,
+    Hsv,
+    Hsl,
+}
+
+impl ColorMode {
+    pub const ALL: [ColorMode; 3] = [ColorMode::Rgb, ColorMode::Hsv, ColorMode::Hsl];
+
+    pub fn label(&self) -> &str {
+        match self {
+            ColorMode::Rgb => "RGB",
+            ColorMode::Hsv => "HSV",
+            ColorMode::Hsl => "HSL",
+        }
+    }
+
+    /// Returns the channels of the given color in this mode, each in the range 0 to 1.
+    pub fn channels_from_color(&self, color: Color) -> [f32; 3] {
+        match self {
+            ColorMode::Rgb => {
+                let srgba = color.to_srgba();
+                [srgba.red, srgba.green, srgba.blue]
+            }
+            ColorMode::Hsv => {
+                let hsva = Hsva::from(color);
+                [hsva.hue / 360.0, hsva.saturation, hsva.value]
+            }
+            ColorMode::Hsl => {
+                let hsla = Hsla::from(color);
+                [hsla.hue / 360.0, hsla.saturation, hsla.lightness]
+            }
+        }
+    }
+
+    /// Returns the color with the given channels in this mode, each in the range 0 to 1.
+    pub fn color_from_channels(&self, [a, b, c]: [f32; 3], alpha: f32) -> Color {
+        match self {
+            ColorMode::Rgb => Color::srgba(a, b, c, alpha),
+            ColorMode::Hsv => Hsva::new(a * 360.0, b, c, alpha).into(),
+            ColorMode::Hsl => Hsla::new(a * 360.0, b, c, alpha).into(),
+        }
+    }
+}
+
+/// The different channels of a full color representation.
+/// For example: red, green, and blue for RGB colors.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum ColorChannel {
+    A,
+    B,
+    C,
+}
+
+impl ColorChannel {
+    pub const ALL: [ColorChannel; 3] = [ColorChannel::A, ColorChannel::B, ColorChannel::C];
+
+    pub fn index(&self) -> usize {
+        match self {
+            ColorChannel::A => 0,
+            ColorChannel::B => 1,
+            ColorChannel::C => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_colors_match(a: Color, b: Color) {
+        let [a, b] = [a.to_srgba(), b.to_srgba()];
+
+        assert!(
+            (a.red - b.red).abs() < 0.001
+                && (a.green - b.green).abs() < 0.001
+                && (a.blue - b.blue).abs() < 0.001
+                && (a.alpha - b.alpha).abs() < 0.001,
+            "{a:?} does not match {b:?}"
+        );
+    }
+
+    #[test]
+    fn channels_round_trip_in_every_mode() {
+        let color = Color::srgba(0.8, 0.4, 0.1, 0.5);
+
+        for mode in ColorMode::ALL {
+            let channels = mode.channels_from_color(color);
+
+            assert_colors_match(mode.color_from_channels(channels, 0.5), color);
+        }
+    }
+
+    #[test]
+    fn hue_channel_is_normalized() {
+        let channels = ColorMode::Hsv.channels_from_color(Color::srgb(0.0, 0.0, 1.0));
+
+        assert!((channels[0] - 240.0 / 360.0).abs() < 0.001);
+        assert!((channels[1] - 1.0).abs() < 0.001);
+        assert!((channels[2] - 1.0).abs() < 0.001);
+    }
+}
@@ -0,0 +1,290 @@
+use std::{fs, io};
+
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ui::{
+        components::{
+            buttons::{ButtonBuilder, ButtonConfig},
+            content_size::ContentSizeConfig,
+            content_wrap::ContentWrapConfig,
+            flexbox::FlexboxConfig,
+            section::{SectionBuilder, SectionConfig},
+            tooltip::Tooltip,
+            UiComponentWithChildrenBuilder,
+        },
+        theme::ThemeColor,
+    },
+    utility::{find_ancestor_of_entity, find_ancestor_of_entity_mut},
+    GameRunningSet,
+};
+
+use super::{spawn_small_text_button, ColorInput, OnColorInputValueChanged};
+
+const COLOR_PALETTE_PATH: &str = "assets/color_palette.ron";
+const SWATCH_SIZE: f32 = 20.0;
+
+pub struct ColorPalettePlugin;
+
+impl Plugin for ColorPalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorPalette>()
+            .add_systems(Startup, load_color_palette_on_startup)
+            .add_systems(
+                Update,
+                (
+                    (
+                        add_color_to_palette_on_button_press,
+                        remove_color_from_palette_on_right_click,
+                    )
+                        .in_set(GameRunningSet::GetUserInput),
+                    apply_swatch_color_on_press.in_set(GameRunningSet::SendEvents),
+                    rebuild_color_palette_swatches.in_set(GameRunningSet::UpdateEntities),
+                ),
+            );
+    }
+}
+
+/// The colors the user saved for this project, shown as swatches in every color input.
+#[derive(Resource, Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColorPalette {
+    colors: Vec<Color>,
+}
+
+impl ColorPalette {
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Adds the given color to the end of the palette, unless the palette already contains it.
+    pub fn add(&mut self, color: Color) {
+        if !self.colors.contains(&color) {
+            self.colors.push(color);
+        }
+    }
+
+    pub fn remove(&mut self, index: usize) {
+        if index < self.colors.len() {
+            self.colors.remove(index);
+        }
+    }
+}
+
+/// The container in a color input in which the palette swatches are shown.
+#[derive(Component)]
+struct ColorPaletteSwatches;
+
+#[derive(Component)]
+struct ColorPaletteSwatch {
+    index: usize,
+    color: Color,
+}
+
+#[derive(Component)]
+struct AddToPaletteButton;
+
+pub fn spawn_color_palette(builder: &mut ChildBuilder) {
+    SectionBuilder::new(SectionConfig {
+        wrap: ContentWrapConfig::empty(),
+        flexbox: FlexboxConfig {
+            flex_wrap: FlexWrap::Wrap,
+            ..FlexboxConfig::horizontally_centered_row()
+                .with_align(AlignItems::Center)
+                .with_px_gap(4.0)
+        },
+        size: ContentSizeConfig::full_width(),
+    })
+    .spawn(builder, ColorPaletteSwatches, |_| {});
+}
+
+fn load_color_palette_on_startup(mut color_palette: ResMut<ColorPalette>) {
+    match load_color_palette() {
+        Ok(Some(loaded_palette)) => *color_palette = loaded_palette,
+        Ok(None) => (),
+        Err(error) => warn!("Failed to load color palette: {error}"),
+    }
+}
+
+fn add_color_to_palette_on_button_press(
+    mut color_palette: ResMut<ColorPalette>,
+    color_input_query: Query<(Entity, &ColorInput)>,
+    button_query: Query<(Entity, Ref<Interaction>), With<AddToPaletteButton>>,
+    parent_query: Query<&Parent>,
+) {
+    for (button_entity, _) in button_query.iter().filter(|(_, interaction)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        let Some((_, color_input)) = find_ancestor_of_entity(
+            button_entity,
+            &color_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        color_palette.add(color_input.value());
+
+        save_color_palette_or_warn(&color_palette);
+    }
+}
+
+fn remove_color_from_palette_on_right_click(
+    mut color_palette: ResMut<ColorPalette>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    swatch_query: Query<(&ColorPaletteSwatch, &RelativeCursorPosition)>,
+) {
+    if !mouse_input.just_pressed(MouseButton::Right) {
+        return;
+    }
+
+    let Some((swatch, _)) = swatch_query
+        .iter()
+        .find(|(_, cursor_position)| cursor_position.mouse_over())
+    else {
+        return;
+    };
+
+    color_palette.remove(swatch.index);
+
+    save_color_palette_or_warn(&color_palette);
+}
+
+fn apply_swatch_color_on_press(
+    mut on_color_changed: EventWriter<OnColorInputValueChanged>,
+    mut color_input_query: Query<(Entity, &mut ColorInput)>,
+    swatch_query: Query<(Entity, &Interaction, &ColorPaletteSwatch), Changed<Interaction>>,
+    parent_query: Query<&Parent>,
+) {
+    for (swatch_entity, _, swatch) in swatch_query
+        .iter()
+        .filter(|(_, interaction, _)| **interaction == Interaction::Pressed)
+    {
+        let Some((color_input_entity, mut color_input)) = find_ancestor_of_entity_mut(
+            swatch_entity,
+            &mut color_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        color_input.set_value(swatch.color);
+
+        on_color_changed.send(OnColorInputValueChanged::new(
+            color_input_entity,
+            swatch.color,
+        ));
+    }
+}
+
+/// Fills newly spawned swatch containers, and all swatch containers when the palette changes.
+fn rebuild_color_palette_swatches(
+    mut commands: Commands,
+    color_palette: Res<ColorPalette>,
+    swatches_query: Query<(Entity, Ref<ColorPaletteSwatches>)>,
+) {
+    for (swatches_entity, swatches) in swatches_query.iter() {
+        if !color_palette.is_changed() && !swatches.is_added() {
+            continue;
+        }
+
+        commands
+            .entity(swatches_entity)
+            .despawn_descendants()
+            .with_children(|swatches| {
+                for (index, color) in color_palette.colors().iter().enumerate() {
+                    spawn_swatch(swatches, index, *color);
+                }
+
+                spawn_small_text_button(
+                    swatches,
+                    "+",
+                    (
+                        AddToPaletteButton,
+                        Tooltip::new("Save the current color, right click a swatch to remove it"),
+                    ),
+                );
+            });
+    }
+}
+
+fn spawn_swatch(builder: &mut ChildBuilder, index: usize, color: Color) {
+    ButtonBuilder::new(ButtonConfig {
+        wrap: ContentWrapConfig {
+            background_color: color.into(),
+            border_size: UiRect::all(Val::Px(2.0)),
+            border_color: ThemeColor::Border.into(),
+            ..ContentWrapConfig::empty().with_all_px_border_radius(4.0)
+        },
+        size: ContentSizeConfig::empty()
+            .with_min_width(SWATCH_SIZE)
+            .with_min_height(SWATCH_SIZE),
+        ..ButtonConfig::empty()
+    })
+    .spawn(builder, ColorPaletteSwatch { index, color }, |_| {});
+}
+
+// Persistence
+
+fn save_color_palette_or_warn(color_palette: &ColorPalette) {
+    if let Err(error) = save_color_palette(color_palette) {
+        warn!("Failed to save color palette: {error}");
+    }
+}
+
+fn save_color_palette(color_palette: &ColorPalette) -> Result<(), ron::Error> {
+    Ok(fs::write(
+        COLOR_PALETTE_PATH,
+        serialize_color_palette(color_palette)?,
+    )?)
+}
+
+/// Returns the saved color palette, or `None` if no palette has been saved yet.
+fn load_color_palette() -> Result<Option<ColorPalette>, ron::Error> {
+    match fs::read_to_string(COLOR_PALETTE_PATH) {
+        Ok(serialized_palette) => Ok(Some(deserialize_color_palette(&serialized_palette)?)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn serialize_color_palette(color_palette: &ColorPalette) -> Result<String, ron::Error> {
+    ron::ser::to_string_pretty(color_palette, PrettyConfig::new().struct_names(true))
+}
+
+fn deserialize_color_palette(serialized_palette: &str) -> Result<ColorPalette, ron::Error> {
+    Ok(ron::from_str::<ColorPalette>(serialized_palette)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_skips_duplicate_colors() {
+        let mut palette = ColorPalette::default();
+
+        palette.add(Color::WHITE);
+        palette.add(Color::BLACK);
+        palette.add(Color::WHITE);
+
+        assert_eq!(palette.colors(), &[Color::WHITE, Color::BLACK]);
+    }
+
+    #[test]
+    fn palette_survives_serialization() {
+        let mut palette = ColorPalette::default();
+        palette.add(Color::srgb(0.2, 0.4, 0.6));
+        palette.add(Color::srgba(1.0, 0.5, 0.0, 0.5));
+
+        let serialized_palette = serialize_color_palette(&palette).unwrap();
+
+        assert_eq!(
+            deserialize_color_palette(&serialized_palette).unwrap(),
+            palette
+        );
+    }
+}
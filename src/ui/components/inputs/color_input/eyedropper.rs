@@ -0,0 +1,153 @@
+use bevy::{
+    prelude::*,
+    window::{CursorIcon, PrimaryWindow},
+};
+
+use crate::{utility::find_ancestor_of_entity_mut, GameRunningSet};
+
+use super::{ColorInput, OnColorInputValueChanged};
+
+pub struct EyedropperPlugin;
+
+impl Plugin for EyedropperPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Eyedropper>()
+            .add_event::<OnEyedropperColorPicked>()
+            .add_systems(
+                Update,
+                (
+                    toggle_eyedropper_on_button_press.in_set(GameRunningSet::GetUserInput),
+                    // Runs after user input, so the pause menu still sees the eyedropper as active
+                    (cancel_eyedropper_on_escape, apply_picked_color)
+                        .in_set(GameRunningSet::SendEvents),
+                    (
+                        stop_eyedropper_when_color_input_is_despawned,
+                        update_cursor_icon_for_eyedropper,
+                    )
+                        .chain()
+                        .in_set(GameRunningSet::UpdateEntities),
+                ),
+            );
+    }
+}
+
+/// Tracks which color input, if any, is waiting for a color to be picked from elsewhere in the design.
+#[derive(Resource, Default)]
+pub struct Eyedropper {
+    color_input_entity: Option<Entity>,
+}
+
+impl Eyedropper {
+    pub fn is_active(&self) -> bool {
+        self.color_input_entity.is_some()
+    }
+}
+
+/// Run condition for systems that should only run while a color is being picked.
+pub fn eyedropper_is_active(eyedropper: Res<Eyedropper>) -> bool {
+    eyedropper.is_active()
+}
+
+/// Sent by anything in the design that can provide a color, when it is clicked while the eyedropper is active.
+#[derive(Event)]
+pub struct OnEyedropperColorPicked {
+    color: Color,
+}
+
+impl OnEyedropperColorPicked {
+    pub fn new(color: Color) -> Self {
+        Self { color }
+    }
+}
+
+#[derive(Component)]
+pub struct EyedropperButton;
+
+fn toggle_eyedropper_on_button_press(
+    mut eyedropper: ResMut<Eyedropper>,
+    mut color_input_query: Query<(Entity, &mut ColorInput)>,
+    button_query: Query<(Entity, Ref<Interaction>), With<EyedropperButton>>,
+    parent_query: Query<&Parent>,
+) {
+    for (button_entity, _) in button_query.iter().filter(|(_, interaction)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        let Some((color_input_entity, _)) = find_ancestor_of_entity_mut(
+            button_entity,
+            &mut color_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        eyedropper.color_input_entity = match eyedropper.color_input_entity {
+            Some(active_entity) if active_entity == color_input_entity => None,
+            _ => Some(color_input_entity),
+        };
+    }
+}
+
+fn cancel_eyedropper_on_escape(
+    mut eyedropper: ResMut<Eyedropper>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    if eyedropper.is_active() && keyboard_input.just_pressed(KeyCode::Escape) {
+        eyedropper.color_input_entity = None;
+    }
+}
+
+fn apply_picked_color(
+    mut on_picked: EventReader<OnEyedropperColorPicked>,
+    mut on_color_changed: EventWriter<OnColorInputValueChanged>,
+    mut eyedropper: ResMut<Eyedropper>,
+    mut color_input_query: Query<&mut ColorInput>,
+) {
+    for event in on_picked.read() {
+        let Some(color_input_entity) = eyedropper.color_input_entity.take() else {
+            continue;
+        };
+
+        let Ok(mut color_input) = color_input_query.get_mut(color_input_entity) else {
+            continue;
+        };
+
+        color_input.set_value(event.color);
+
+        on_color_changed.send(OnColorInputValueChanged::new(
+            color_input_entity,
+            event.color,
+        ));
+    }
+}
+
+fn stop_eyedropper_when_color_input_is_despawned(
+    mut eyedropper: ResMut<Eyedropper>,
+    color_input_query: Query<&ColorInput>,
+) {
+    let Some(color_input_entity) = eyedropper.color_input_entity else {
+        return;
+    };
+
+    if !color_input_query.contains(color_input_entity) {
+        eyedropper.color_input_entity = None;
+    }
+}
+
+fn update_cursor_icon_for_eyedropper(
+    eyedropper: Res<Eyedropper>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if !eyedropper.is_changed() {
+        return;
+    }
+
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
+
+    window.cursor.icon = match eyedropper.is_active() {
+        true => CursorIcon::Crosshair,
+        false => CursorIcon::Default,
+    };
+}
@@ -0,0 +1,500 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use crate::{
+    ui::{
+        components::{
+            buttons::{ButtonBuilder, ButtonConfig},
+            content_size::ContentSizeConfig,
+            content_wrap::ContentWrapConfig,
+            text::{TextBuilder, TextConfig},
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        theme::{ThemeColor, ThemeSpacing, Themed, UiTheme},
+    },
+    utility::{find_ancestor_of_entity_mut, find_descendant_of_entity_mut},
+    GameRunningSet,
+};
+
+const OPTION_LIST_COLOR: ThemeColor = ThemeColor::Surface;
+const HIGHLIGHTED_OPTION_COLOR: ThemeColor = ThemeColor::Control;
+const SELECTED_OPTION_COLOR: ThemeColor = ThemeColor::RaisedSurface;
+const OPEN_INDICATOR: &str = "^";
+const CLOSED_INDICATOR: &str = "v";
+
+pub struct DropdownPlugin;
+
+impl Plugin for DropdownPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnDropdownValueChanged>().add_systems(
+            Update,
+            (
+                (
+                    toggle_dropdown_on_button_press,
+                    select_dropdown_option_on_press,
+                    navigate_open_dropdowns_with_keyboard,
+                )
+                    .in_set(GameRunningSet::GetUserInput),
+                // Runs after user input, so pressing the dropdown button or an option isn't seen as a click outside
+                close_dropdowns_on_escape_or_click_outside.in_set(GameRunningSet::SendEvents),
+                update_dropdown_display.in_set(GameRunningSet::UpdateEntities),
+            ),
+        );
+    }
+}
+
+// Start of new UiComponent code
+
+#[derive(Clone, Default)]
+pub struct DropdownConfig {
+    options: Vec<String>,
+    selected_index: usize,
+}
+
+impl DropdownConfig {
+    pub fn new(options: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            selected_index: 0,
+        }
+    }
+
+    pub fn with_selected_index(mut self, selected_index: usize) -> Self {
+        self.selected_index = selected_index;
+        self
+    }
+}
+
+/// A UiComponent to pick one option out of a list, for example a variant of an enum.
+#[derive(Default)]
+pub struct DropdownBuilder {
+    config: DropdownConfig,
+}
+
+impl DropdownBuilder {
+    pub fn new(config: DropdownConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl UiComponentBuilder for DropdownBuilder {
+    fn spawn(&self, builder: &mut ChildBuilder, components: impl Bundle) -> Entity {
+        let dropdown = Dropdown::new(self.config.options.clone(), self.config.selected_index);
+
+        let container_node = NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ..default()
+        };
+
+        let option_list_node = NodeBundle {
+            style: Style {
+                display: Display::None,
+                flex_direction: FlexDirection::Column,
+                position_type: PositionType::Absolute,
+                top: Val::Percent(100.0),
+                min_width: Val::Percent(100.0),
+                row_gap: Val::Px(2.0),
+                ..default()
+            },
+            border_radius: BorderRadius::all(Val::Px(8.0)),
+            // Shown on top of the elements below the dropdown
+            z_index: ZIndex::Global(10),
+            ..default()
+        };
+
+        builder
+            .spawn((container_node, components, self.build()))
+            .with_children(|container| {
+                ButtonBuilder::spawn_default(container, DropdownButton, |button| {
+                    TextBuilder::new(
+                        TextConfig::default()
+                            .with_text(dropdown.button_text())
+                            .clone(),
+                    )
+                    .spawn(button, ());
+                });
+
+                container
+                    .spawn((
+                        option_list_node,
+                        Themed::default()
+                            .with_padding(ThemeSpacing::Small.into())
+                            .with_background_color(OPTION_LIST_COLOR.into()),
+                        DropdownOptionList,
+                    ))
+                    .with_children(|option_list| {
+                        for (index, option) in dropdown.options.iter().enumerate() {
+                            ButtonBuilder::new(ButtonConfig {
+                                wrap: ContentWrapConfig::wide_element()
+                                    .with_background_color(Color::NONE)
+                                    .with_all_px_border_radius(4.0),
+                                size: ContentSizeConfig::full_width(),
+                                ..ButtonConfig::empty()
+                            })
+                            .spawn(
+                                option_list,
+                                DropdownOption { index },
+                                |option_button| {
+                                    TextBuilder::default()
+                                        .with_text(option.clone())
+                                        .spawn(option_button, ());
+                                },
+                            );
+                        }
+                    });
+            })
+            .id()
+    }
+
+    fn build(&self) -> impl Bundle {
+        Dropdown::new(self.config.options.clone(), self.config.selected_index)
+    }
+}
+
+// End of new UiComponent code
+
+#[derive(Component, Debug)]
+pub struct Dropdown {
+    options: Vec<String>,
+    selected_index: usize,
+    /// The option that is selected when pressing enter, moved with the arrow keys while the dropdown is open.
+    highlighted_index: usize,
+    is_open: bool,
+}
+
+impl Dropdown {
+    fn new(options: Vec<String>, selected_index: usize) -> Self {
+        let selected_index = selected_index.min(options.len().saturating_sub(1));
+
+        Self {
+            options,
+            selected_index,
+            highlighted_index: selected_index,
+            is_open: false,
+        }
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+
+    pub fn selected_option(&self) -> Option<&str> {
+        self.options.get(self.selected_index).map(String::as_str)
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    fn open(&mut self) {
+        self.is_open = true;
+        self.highlighted_index = self.selected_index;
+    }
+
+    fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Selects the option at the given index, returns true if the selection changed.
+    fn select(&mut self, index: usize) -> bool {
+        if index >= self.options.len() || index == self.selected_index {
+            return false;
+        }
+
+        self.selected_index = index;
+        self.highlighted_index = index;
+
+        true
+    }
+
+    fn highlight_next(&mut self) {
+        if !self.options.is_empty() {
+            self.highlighted_index = (self.highlighted_index + 1) % self.options.len();
+        }
+    }
+
+    fn highlight_previous(&mut self) {
+        if !self.options.is_empty() {
+            self.highlighted_index =
+                (self.highlighted_index + self.options.len() - 1) % self.options.len();
+        }
+    }
+
+    fn button_text(&self) -> String {
+        let indicator = match self.is_open {
+            true => OPEN_INDICATOR,
+            false => CLOSED_INDICATOR,
+        };
+
+        format!("{} {indicator}", self.selected_option().unwrap_or_default())
+    }
+}
+
+#[derive(Component)]
+struct DropdownButton;
+
+#[derive(Component)]
+struct DropdownOptionList;
+
+#[derive(Component)]
+struct DropdownOption {
+    index: usize,
+}
+
+#[derive(Event)]
+pub struct OnDropdownValueChanged {
+    dropdown_entity: Entity,
+    selected_index: usize,
+}
+
+impl OnDropdownValueChanged {
+    pub fn new(dropdown_entity: Entity, selected_index: usize) -> Self {
+        Self {
+            dropdown_entity,
+            selected_index,
+        }
+    }
+
+    pub fn dropdown_entity(&self) -> Entity {
+        self.dropdown_entity
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected_index
+    }
+}
+
+/// Run condition that is true while any dropdown shows its options.
+pub fn dropdown_is_open(dropdown_query: Query<&Dropdown>) -> bool {
+    dropdown_query.iter().any(Dropdown::is_open)
+}
+
+fn toggle_dropdown_on_button_press(
+    mut dropdown_query: Query<(Entity, &mut Dropdown)>,
+    button_query: Query<(Entity, Ref<Interaction>), With<DropdownButton>>,
+    parent_query: Query<&Parent>,
+) {
+    for (button_entity, _) in button_query.iter().filter(|(_, interaction)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        let Some((_, mut dropdown)) = find_ancestor_of_entity_mut(
+            button_entity,
+            &mut dropdown_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        match dropdown.is_open {
+            true => dropdown.close(),
+            false => dropdown.open(),
+        }
+    }
+}
+
+fn select_dropdown_option_on_press(
+    mut on_changed: EventWriter<OnDropdownValueChanged>,
+    mut dropdown_query: Query<(Entity, &mut Dropdown)>,
+    option_query: Query<(Entity, &Interaction, &DropdownOption), Changed<Interaction>>,
+    parent_query: Query<&Parent>,
+) {
+    for (option_entity, _, option) in option_query
+        .iter()
+        .filter(|(_, interaction, _)| **interaction == Interaction::Pressed)
+    {
+        let Some((dropdown_entity, mut dropdown)) = find_ancestor_of_entity_mut(
+            option_entity,
+            &mut dropdown_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        dropdown.close();
+
+        if dropdown.select(option.index) {
+            on_changed.send(OnDropdownValueChanged::new(
+                dropdown_entity,
+                dropdown.selected_index,
+            ));
+        }
+    }
+}
+
+fn navigate_open_dropdowns_with_keyboard(
+    mut on_changed: EventWriter<OnDropdownValueChanged>,
+    mut dropdown_query: Query<(Entity, &mut Dropdown)>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    for (dropdown_entity, mut dropdown) in dropdown_query
+        .iter_mut()
+        .filter(|(_, dropdown)| dropdown.is_open)
+    {
+        if keyboard_input.just_pressed(KeyCode::ArrowDown) {
+            dropdown.highlight_next();
+        }
+
+        if keyboard_input.just_pressed(KeyCode::ArrowUp) {
+            dropdown.highlight_previous();
+        }
+
+        if keyboard_input.just_pressed(KeyCode::Enter) {
+            let highlighted_index = dropdown.highlighted_index;
+
+            dropdown.close();
+
+            if dropdown.select(highlighted_index) {
+                on_changed.send(OnDropdownValueChanged::new(
+                    dropdown_entity,
+                    highlighted_index,
+                ));
+            }
+        }
+    }
+}
+
+fn close_dropdowns_on_escape_or_click_outside(
+    mut dropdown_query: Query<(Entity, &mut Dropdown, &RelativeCursorPosition)>,
+    option_list_query: Query<(Entity, &RelativeCursorPosition), With<DropdownOptionList>>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    children_query: Query<&Children>,
+) {
+    let escape_pressed = keyboard_input.just_pressed(KeyCode::Escape);
+    let clicked = mouse_input.just_pressed(MouseButton::Left);
+
+    if !escape_pressed && !clicked {
+        return;
+    }
+
+    for (dropdown_entity, mut dropdown, cursor_position) in dropdown_query
+        .iter_mut()
+        .filter(|(_, dropdown, _)| dropdown.is_open)
+    {
+        // The option list is positioned outside of the dropdown node, so it has to be checked separately
+        let option_list_hovered = children_query
+            .iter_descendants(dropdown_entity)
+            .filter_map(|descendant| option_list_query.get(descendant).ok())
+            .any(|(_, option_list_cursor)| option_list_cursor.mouse_over());
+
+        if escape_pressed || !(cursor_position.mouse_over() || option_list_hovered) {
+            dropdown.close();
+        }
+    }
+}
+
+fn update_dropdown_display(
+    dropdown_query: Query<(Entity, Ref<Dropdown>)>,
+    mut option_list_query: Query<(Entity, &mut Style), With<DropdownOptionList>>,
+    mut option_query: Query<(&DropdownOption, &mut BackgroundColor)>,
+    mut button_text_query: Query<(Entity, &mut Text)>,
+    button_query: Query<Entity, With<DropdownButton>>,
+    children_query: Query<&Children>,
+    theme: Res<UiTheme>,
+) {
+    for (dropdown_entity, dropdown) in dropdown_query
+        .iter()
+        .filter(|(_, dropdown)| dropdown.is_changed() || theme.is_changed())
+    {
+        if let Some((option_list_entity, mut option_list_style)) = find_descendant_of_entity_mut(
+            dropdown_entity,
+            &mut option_list_query,
+            |item| item.0,
+            &children_query,
+        ) {
+            option_list_style.display = match dropdown.is_open {
+                true => Display::Flex,
+                false => Display::None,
+            };
+
+            for &option_entity in children_query.get(option_list_entity).into_iter().flatten() {
+                let Ok((option, mut background_color)) = option_query.get_mut(option_entity) else {
+                    continue;
+                };
+
+                background_color.0 = if option.index == dropdown.highlighted_index {
+                    theme.color(HIGHLIGHTED_OPTION_COLOR)
+                } else if option.index == dropdown.selected_index {
+                    theme.color(SELECTED_OPTION_COLOR)
+                } else {
+                    Color::NONE
+                };
+            }
+        }
+
+        let Some(button_entity) = children_query
+            .iter_descendants(dropdown_entity)
+            .find(|descendant| button_query.contains(*descendant))
+        else {
+            continue;
+        };
+
+        if let Some((_, mut button_text)) = find_descendant_of_entity_mut(
+            button_entity,
+            &mut button_text_query,
+            |item| item.0,
+            &children_query,
+        ) {
+            button_text.sections[0].value = dropdown.button_text();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dropdown_with_options(selected_index: usize) -> Dropdown {
+        Dropdown::new(
+            vec![
+                "Solid".to_string(),
+                "Dashed".to_string(),
+                "Dotted".to_string(),
+            ],
+            selected_index,
+        )
+    }
+
+    #[test]
+    fn highlight_wraps_around() {
+        let mut dropdown = dropdown_with_options(0);
+
+        dropdown.highlight_previous();
+        assert_eq!(dropdown.highlighted_index, 2);
+
+        dropdown.highlight_next();
+        assert_eq!(dropdown.highlighted_index, 0);
+    }
+
+    #[test]
+    fn select_reports_changes_only() {
+        let mut dropdown = dropdown_with_options(1);
+
+        assert!(!dropdown.select(1));
+        assert!(!dropdown.select(3));
+        assert!(dropdown.select(2));
+        assert_eq!(dropdown.selected_option(), Some("Dotted"));
+    }
+
+    #[test]
+    fn opening_highlights_selected_option() {
+        let mut dropdown = dropdown_with_options(1);
+
+        dropdown.highlight_next();
+        dropdown.close();
+        dropdown.open();
+
+        assert_eq!(dropdown.highlighted_index, 1);
+    }
+
+    #[test]
+    fn selected_index_is_clamped_to_options() {
+        let dropdown = dropdown_with_options(10);
+
+        assert_eq!(dropdown.selected_option(), Some("Dotted"));
+    }
+}
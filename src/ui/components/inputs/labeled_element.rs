@@ -106,20 +106,6 @@ impl UiComponentWithChildrenBuilder for LabeledElementBuilder {
     }
 
     fn build(&self) -> impl Bundle {
-        TextBundle {
-            text: Text {
-                sections: vec![TextSection {
-                    value: self.config.text.text.clone(),
-                    style: TextStyle {
-                        color: self.config.text.color,
-                        font_size: self.config.text.font_size,
-                        ..default()
-                    },
-                }],
-                justify: self.config.text.justify,
-                linebreak_behavior: self.config.text.linebreak_behavior,
-            },
-            ..default()
-        }
+        self.config.text.build_text_bundle()
     }
 }
@@ -1,21 +1,38 @@
+pub mod expression;
+
 use std::ops::Range;
 
-use bevy::{prelude::*, text::BreakLineOn};
+use bevy::{input::mouse::MouseWheel, prelude::*, text::BreakLineOn, ui::RelativeCursorPosition};
+use expression::{evaluate_expression, ExpressionError};
 
 use crate::{
-    ui::components::{
-        buttons::ButtonBuilder,
-        content_size::ContentSizeConfig,
-        content_wrap::ContentWrapConfig,
-        flexbox::FlexboxConfig,
-        section::{SectionBuilder, SectionConfig},
-        text::{TextBuilder, TextConfig},
-        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    settings::Settings,
+    ui::{
+        components::{
+            buttons::ButtonBuilder,
+            content_size::ContentSizeConfig,
+            content_wrap::ContentWrapConfig,
+            flexbox::FlexboxConfig,
+            scroll_container::BlocksScrolling,
+            section::{SectionBuilder, SectionConfig},
+            text::{TextBuilder, TextConfig},
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        inputs::text_input::{
+            spawn_configured_text_input_node, text_editor::TextConstraints,
+            text_input_border_color, OnTextInputValueChanged, TextInput, TextInputConfig,
+        },
+        theme::{ThemeColor, ThemeFontSize, ThemedColor, ThemedFontSize, UiTheme},
     },
-    utility::{entity_is_descendant_of, find_ancestor_of_entity_mut},
+    utility::{distance::LengthUnit, find_ancestor_of_entity_mut, find_descendant_of_entity_mut},
     GameRunningSet,
 };
 
+/// Multiplies the step size while the fine step modifier is held.
+const FINE_STEP_MULTIPLIER: f32 = 0.1;
+/// Multiplies the step size while the coarse step modifier is held.
+const COARSE_STEP_MULTIPLIER: f32 = 10.0;
+
 pub struct NumberInputPlugin;
 
 impl Plugin for NumberInputPlugin {
@@ -23,8 +40,17 @@ impl Plugin for NumberInputPlugin {
         app.add_event::<OnNumberInputValueChanged>().add_systems(
             Update,
             (
-                update_number_input_value_on_button_press.in_set(GameRunningSet::GetUserInput),
-                update_number_display.in_set(GameRunningSet::UpdateEntities),
+                (
+                    update_number_input_value_on_button_press,
+                    step_selected_number_input_on_arrow_keys,
+                    step_hovered_number_input_on_scroll,
+                )
+                    .in_set(GameRunningSet::GetUserInput),
+                apply_typed_number_input_values.in_set(GameRunningSet::SendEvents),
+                (update_number_display, show_lengths_in_length_unit)
+                    .in_set(GameRunningSet::UpdateEntities),
+                // Runs after the text input updates its border color, so it can be overridden
+                update_number_display_border_color.after(GameRunningSet::UpdateEntities),
             ),
         );
     }
@@ -32,13 +58,10 @@ impl Plugin for NumberInputPlugin {
 
 // Start of new UiComponent code
 
-// TODO: make generic text input UiComponent
-// TODO: make number input build on top of the text input UiComponent, (so it also supports keyboard input, but limited to numbers within a range)
-
 #[derive(Clone, Copy)]
 pub struct NumberInputDisplayConfig {
-    pub color: Color,
-    pub font_size: f32,
+    pub color: ThemedColor,
+    pub font_size: ThemedFontSize,
     pub justify: JustifyText,
     pub linebreak_behavior: BreakLineOn,
 }
@@ -46,8 +69,8 @@ pub struct NumberInputDisplayConfig {
 impl Default for NumberInputDisplayConfig {
     fn default() -> Self {
         Self {
-            color: Color::WHITE,
-            font_size: 24.0,
+            color: ThemeColor::Text.into(),
+            font_size: ThemeFontSize::Normal.into(),
             justify: JustifyText::Center,
             linebreak_behavior: BreakLineOn::NoWrap,
         }
@@ -69,8 +92,8 @@ impl From<NumberInputDisplayConfig> for TextConfig {
 #[derive(Clone)]
 pub struct NumberInputButtonConfig {
     pub text: String,
-    pub color: Color,
-    pub font_size: f32,
+    pub color: ThemedColor,
+    pub font_size: ThemedFontSize,
 }
 
 impl NumberInputButtonConfig {
@@ -84,8 +107,8 @@ impl Default for NumberInputButtonConfig {
     fn default() -> Self {
         Self {
             text: String::new(),
-            color: Color::WHITE,
-            font_size: 24.0,
+            color: ThemeColor::Text.into(),
+            font_size: ThemeFontSize::Normal.into(),
         }
     }
 }
@@ -106,6 +129,10 @@ pub struct NumberInputConfig {
     start_value: f32,
     // TODO: change to inclusive range
     value_range: Range<f32>,
+    /// The amount the value changes with each button press, arrow key press or scroll step.
+    step: f32,
+    /// Whether the value is a length in meters, which is shown and typed in the [LengthUnit] from the [Settings].
+    is_length: bool,
     display: NumberInputDisplayConfig,
     up_button: NumberInputButtonConfig,
     down_button: NumberInputButtonConfig,
@@ -116,6 +143,8 @@ impl Default for NumberInputConfig {
         Self {
             start_value: 0.0,
             value_range: 0.0..1.0,
+            step: 0.1,
+            is_length: false,
             display: NumberInputDisplayConfig::default(),
             up_button: NumberInputButtonConfig::default().with_text(">"),
             down_button: NumberInputButtonConfig::default().with_text("<"),
@@ -135,6 +164,17 @@ impl NumberInputBuilder {
         self.config.value_range = value_range;
         self
     }
+
+    pub fn with_step(&mut self, step: f32) -> &mut Self {
+        self.config.step = step;
+        self
+    }
+
+    /// Shows the value in the [LengthUnit] from the [Settings], the value itself stays in meters.
+    pub fn as_length(&mut self) -> &mut Self {
+        self.config.is_length = true;
+        self
+    }
 }
 
 impl UiComponentBuilder for NumberInputBuilder {
@@ -160,9 +200,27 @@ impl UiComponentBuilder for NumberInputBuilder {
                 size: ContentSizeConfig::empty().with_full_width(),
             })
             .spawn(number_input, (), |display_wrap| {
-                TextBuilder::new(TextConfig::from(self.config.display))
-                    .with_text(format_display_value(self.config.start_value))
-                    .spawn(display_wrap, NumberInputDisplay);
+                spawn_configured_text_input_node(
+                    display_wrap,
+                    NumberInputDisplay::default(),
+                    TextInputConfig {
+                        text: TextConfig {
+                            text: format_display_value(self.config.start_value),
+                            ..TextConfig::from(self.config.display)
+                        },
+                        constraints: TextConstraints::default()
+                            .with_character_filter(is_expression_character),
+                    },
+                );
+
+                if self.config.is_length {
+                    TextBuilder::new(TextConfig {
+                        color: ThemeColor::MutedText.into(),
+                        font_size: ThemeFontSize::Small.into(),
+                        ..default()
+                    })
+                    .spawn(display_wrap, NumberInputUnitLabel);
+                }
             });
 
             // Up button
@@ -178,7 +236,12 @@ impl UiComponentBuilder for NumberInputBuilder {
     }
 
     fn build(&self) -> impl Bundle {
-        NumberInput::new(self.config.start_value, self.config.value_range.clone())
+        (
+            NumberInput::new(self.config.start_value, self.config.value_range.clone())
+                .with_step(self.config.step)
+                .with_is_length(self.config.is_length),
+            BlocksScrolling,
+        )
     }
 }
 
@@ -188,6 +251,8 @@ impl UiComponentBuilder for NumberInputBuilder {
 pub struct NumberInput {
     value: f32,
     allowed_range: Range<f32>,
+    step: f32,
+    is_length: bool,
 }
 
 impl NumberInput {
@@ -195,14 +260,78 @@ impl NumberInput {
         Self {
             value,
             allowed_range,
+            step: 0.1,
+            is_length: false,
+        }
+    }
+
+    pub fn with_step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    pub fn with_is_length(mut self, is_length: bool) -> Self {
+        self.is_length = is_length;
+        self
+    }
+
+    /// Returns the unit the value is shown in, or `None` if the value isn't a length.
+    pub fn length_unit(&self, settings: &Settings) -> Option<LengthUnit> {
+        self.is_length.then_some(settings.length_unit)
+    }
+
+    /// Returns the value as it's shown to the user.
+    pub fn display_value(&self, settings: &Settings) -> f32 {
+        match self.length_unit(settings) {
+            Some(length_unit) => length_unit.convert_from_meters(self.value),
+            None => self.value,
+        }
+    }
+
+    /// Sets the value from a value as it's shown to the user, clamped to the allowed range.
+    pub fn set_display_value(&mut self, display_value: f32, settings: &Settings) {
+        match self.length_unit(settings) {
+            Some(length_unit) => self.set_value(length_unit.convert_to_meters(display_value)),
+            None => self.set_value(display_value),
         }
     }
 
     pub fn change_value(&mut self, delta_value: f32) {
-        let new_value = self.value + delta_value;
-        let clamped = new_value.clamp(self.allowed_range.start, self.allowed_range.end);
+        self.set_value(self.value + delta_value);
+    }
 
-        self.value = clamped;
+    /// Sets the value, clamped to the allowed range.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value.clamp(self.allowed_range.start, self.allowed_range.end);
+    }
+
+    /// Sets the value from typed text, which is evaluated as an expression in the shown unit.
+    ///
+    /// The value is kept when the text isn't a valid number.
+    pub fn set_typed_value(
+        &mut self,
+        text: &str,
+        settings: &Settings,
+    ) -> Result<(), ExpressionError> {
+        let display_value = evaluate_expression(text)?;
+        self.set_display_value(display_value, settings);
+
+        Ok(())
+    }
+
+    /// Returns the text that shows the value to the user.
+    pub fn display_text(&self, settings: &Settings) -> String {
+        format_display_value(self.display_value(settings))
+    }
+
+    /// Changes the value by a number of steps, the step size depends on the held modifier keys.
+    pub fn step_value(&mut self, steps: f32, keyboard_input: &ButtonInput<KeyCode>) {
+        let multiplier = step_multiplier(
+            keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            keyboard_input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+        );
+
+        self.change_value(steps * self.step * multiplier);
     }
 }
 
@@ -217,8 +346,16 @@ impl NumberInputButton {
     }
 }
 
+/// Marker for the text input that displays the value of a NumberInput and allows typing a new value.
+#[derive(Component, Default)]
+pub struct NumberInputDisplay {
+    /// Whether the last typed value was rejected because it wasn't a valid number.
+    has_rejected_value: bool,
+}
+
+/// Shows the [LengthUnit] next to the value of a NumberInput that holds a length.
 #[derive(Component)]
-pub struct NumberInputDisplay;
+struct NumberInputUnitLabel;
 
 #[derive(Clone, Copy)]
 pub enum NumberInputDirection {
@@ -254,6 +391,7 @@ fn update_number_input_value_on_button_press(
     mut button_query: Query<(Entity, &Interaction, &mut NumberInputButton), Changed<Interaction>>,
     mut number_input_query: Query<(Entity, &mut NumberInput)>,
     parent_query: Query<&Parent>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
 ) {
     for (button_entity, _, button) in button_query
         .iter_mut()
@@ -268,12 +406,50 @@ fn update_number_input_value_on_button_press(
             continue;
         };
 
-        let delta_value = match button.direction {
-            NumberInputDirection::Up => 0.1,
-            NumberInputDirection::Down => -0.1,
+        let steps = match button.direction {
+            NumberInputDirection::Up => 1.0,
+            NumberInputDirection::Down => -1.0,
+        };
+
+        number_input.step_value(steps, &keyboard_input);
+
+        on_changed.send(OnNumberInputValueChanged::new(
+            number_input_entity,
+            number_input.value,
+        ));
+    }
+}
+
+fn step_selected_number_input_on_arrow_keys(
+    mut on_changed: EventWriter<OnNumberInputValueChanged>,
+    mut number_input_query: Query<(Entity, &mut NumberInput)>,
+    display_query: Query<(Entity, &TextInput), With<NumberInputDisplay>>,
+    parent_query: Query<&Parent>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    let steps = match (
+        keyboard_input.just_pressed(KeyCode::ArrowUp),
+        keyboard_input.just_pressed(KeyCode::ArrowDown),
+    ) {
+        (true, false) => 1.0,
+        (false, true) => -1.0,
+        _ => return,
+    };
+
+    for (display_entity, _) in display_query
+        .iter()
+        .filter(|(_, text_input)| text_input.is_selected())
+    {
+        let Some((number_input_entity, mut number_input)) = find_ancestor_of_entity_mut(
+            display_entity,
+            &mut number_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
         };
 
-        number_input.change_value(delta_value);
+        number_input.step_value(steps, &keyboard_input);
 
         on_changed.send(OnNumberInputValueChanged::new(
             number_input_entity,
@@ -282,20 +458,257 @@ fn update_number_input_value_on_button_press(
     }
 }
 
+fn step_hovered_number_input_on_scroll(
+    mut on_changed: EventWriter<OnNumberInputValueChanged>,
+    mut on_mouse_wheel: EventReader<MouseWheel>,
+    mut number_input_query: Query<(Entity, &mut NumberInput, &RelativeCursorPosition)>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+) {
+    let delta_scroll: f32 = on_mouse_wheel.read().map(|event| event.y).sum();
+
+    if delta_scroll == 0.0 {
+        return;
+    }
+
+    for (number_input_entity, mut number_input, _) in number_input_query
+        .iter_mut()
+        .filter(|(_, _, cursor_position)| cursor_position.mouse_over())
+    {
+        number_input.step_value(delta_scroll.signum(), &keyboard_input);
+
+        on_changed.send(OnNumberInputValueChanged::new(
+            number_input_entity,
+            number_input.value,
+        ));
+    }
+}
+
+fn apply_typed_number_input_values(
+    mut on_text_changed: EventReader<OnTextInputValueChanged>,
+    mut on_changed: EventWriter<OnNumberInputValueChanged>,
+    mut number_input_query: Query<(Entity, &mut NumberInput)>,
+    mut display_query: Query<(&mut TextInput, &mut NumberInputDisplay)>,
+    parent_query: Query<&Parent>,
+    settings: Res<Settings>,
+) {
+    for event in on_text_changed.read() {
+        let display_entity = event.text_input_entity();
+
+        let Ok((mut text_input, mut display)) = display_query.get_mut(display_entity) else {
+            continue;
+        };
+
+        let Some((number_input_entity, mut number_input)) = find_ancestor_of_entity_mut(
+            display_entity,
+            &mut number_input_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        match number_input.set_typed_value(event.text(), &settings) {
+            Ok(()) => {
+                on_changed.send(OnNumberInputValueChanged::new(
+                    number_input_entity,
+                    number_input.value,
+                ));
+            }
+            Err(_) => {
+                display.has_rejected_value = true;
+
+                // Show the value that is still in use instead of the rejected text
+                text_input.set_text(number_input.display_text(&settings));
+            }
+        }
+    }
+}
+
 fn update_number_display(
     mut on_changed: EventReader<OnNumberInputValueChanged>,
-    mut number_display_query: Query<(Entity, &NumberInputDisplay, &mut Text)>,
-    parent_query: Query<&Parent>,
+    mut display_query: Query<(Entity, &mut TextInput, &mut NumberInputDisplay)>,
+    number_input_query: Query<&NumberInput>,
+    children_query: Query<&Children>,
+    settings: Res<Settings>,
 ) {
     for event in on_changed.read() {
-        let (_, _, mut text) = number_display_query.iter_mut()
-        .find(|(entity, _, _)| entity_is_descendant_of(&parent_query, *entity, event.number_input_entity()))
+        let Ok(number_input) = number_input_query.get(event.number_input_entity()) else {
+            continue;
+        };
+
+        let (_, mut text_input, mut display) = find_descendant_of_entity_mut(
+            event.number_input_entity(),
+            &mut display_query,
+            |item| item.0,
+            &children_query,
+        )
         .expect("NumberInputValueChanged event should always match NumberInput entity with a display node.");
 
-        text.sections[0].value = format_display_value(event.new_value());
+        display.has_rejected_value = false;
+
+        text_input.set_text(number_input.display_text(&settings));
     }
 }
 
+/// Shows the values of new length inputs, and of all length inputs when the [LengthUnit] changes.
+fn show_lengths_in_length_unit(
+    number_input_query: Query<(Entity, Ref<NumberInput>)>,
+    mut display_query: Query<(Entity, &mut TextInput), With<NumberInputDisplay>>,
+    mut unit_label_query: Query<(Entity, &mut Text), With<NumberInputUnitLabel>>,
+    children_query: Query<&Children>,
+    settings: Res<Settings>,
+) {
+    for (number_input_entity, number_input) in number_input_query
+        .iter()
+        .filter(|(_, number_input)| number_input.is_added() || settings.is_changed())
+    {
+        let Some(length_unit) = number_input.length_unit(&settings) else {
+            continue;
+        };
+
+        if let Some((_, mut text_input)) = find_descendant_of_entity_mut(
+            number_input_entity,
+            &mut display_query,
+            |item| item.0,
+            &children_query,
+        ) {
+            text_input.set_text(number_input.display_text(&settings));
+        }
+
+        if let Some((_, mut unit_label)) = find_descendant_of_entity_mut(
+            number_input_entity,
+            &mut unit_label_query,
+            |item| item.0,
+            &children_query,
+        ) {
+            unit_label.sections[0].value = length_unit.symbol().to_string();
+        }
+    }
+}
+
+/// Shows whether the text of a number input is a valid number, while typing and after a typed value is rejected.
+fn update_number_display_border_color(
+    // Rejecting a value always changes the TextInput as well, since its text is reset
+    mut display_query: Query<(Ref<TextInput>, &NumberInputDisplay, &mut BorderColor)>,
+    theme: Res<UiTheme>,
+) {
+    for (text_input, display, mut border_color) in display_query
+        .iter_mut()
+        .filter(|(text_input, _, _)| text_input.is_changed() || theme.is_changed())
+    {
+        let is_valid = match text_input.is_selected() {
+            true => evaluate_expression(text_input.text_being_edited()).is_ok(),
+            false => !display.has_rejected_value,
+        };
+
+        border_color.0 = match is_valid {
+            true => text_input_border_color(text_input.is_selected(), &theme),
+            false => theme.colors.danger,
+        };
+    }
+}
+
+/// Returns the multiplier for the step size, the coarse step wins when both modifiers are held.
+fn step_multiplier(is_coarse_step_held: bool, is_fine_step_held: bool) -> f32 {
+    match (is_coarse_step_held, is_fine_step_held) {
+        (true, _) => COARSE_STEP_MULTIPLIER,
+        (false, true) => FINE_STEP_MULTIPLIER,
+        (false, false) => 1.0,
+    }
+}
+
+/// Returns true for characters that can be used in number input expressions.
+fn is_expression_character(character: char) -> bool {
+    character.is_ascii_digit() || "+-*/(). ".contains(character)
+}
+
+/// Formats the value with up to 2 decimals, but always at least 1.
 fn format_display_value(value: f32) -> String {
-    format!("{:.1}", value)
+    let formatted_value = format!("{:.2}", value);
+
+    match formatted_value.strip_suffix('0') {
+        Some(trimmed_value) if !trimmed_value.ends_with('.') => trimmed_value.to_string(),
+        _ => formatted_value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings_with_length_unit(length_unit: LengthUnit) -> Settings {
+        Settings {
+            length_unit,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn values_are_clamped_to_the_allowed_range() {
+        let mut number_input = NumberInput::new(1.0, 0.0..5.0);
+
+        number_input.set_value(7.5);
+        assert_eq!(number_input.value, 5.0);
+
+        number_input.change_value(-10.0);
+        assert_eq!(number_input.value, 0.0);
+
+        number_input
+            .set_typed_value("2 * 4", &Settings::default())
+            .unwrap();
+        assert_eq!(number_input.value, 5.0);
+    }
+
+    #[test]
+    fn invalid_text_keeps_the_previous_value() {
+        let settings = Settings::default();
+        let mut number_input = NumberInput::new(1.5, 0.0..5.0);
+
+        assert!(number_input.set_typed_value("2 +", &settings).is_err());
+        assert_eq!(number_input.value, 1.5);
+        assert_eq!(number_input.display_text(&settings), "1.5");
+    }
+
+    #[test]
+    fn modifier_keys_change_the_step_size() {
+        assert_eq!(step_multiplier(false, false), 1.0);
+        assert_eq!(step_multiplier(true, false), 10.0);
+        assert_eq!(step_multiplier(false, true), 0.1);
+        assert_eq!(step_multiplier(true, true), 10.0);
+
+        let mut number_input = NumberInput::new(1.0, 0.0..100.0).with_step(0.5);
+        let mut keyboard_input = ButtonInput::<KeyCode>::default();
+
+        keyboard_input.press(KeyCode::ShiftLeft);
+        number_input.step_value(1.0, &keyboard_input);
+        assert_eq!(number_input.value, 6.0);
+
+        keyboard_input.release(KeyCode::ShiftLeft);
+        keyboard_input.press(KeyCode::ControlRight);
+        number_input.step_value(-1.0, &keyboard_input);
+        assert!((number_input.value - 5.95).abs() < 1e-5);
+    }
+
+    #[test]
+    fn lengths_are_shown_and_typed_in_the_length_unit() {
+        let centimeters = settings_with_length_unit(LengthUnit::Centimeters);
+        let mut length_input = NumberInput::new(1.25, 0.0..10.0).with_is_length(true);
+
+        assert_eq!(length_input.display_text(&centimeters), "125.0");
+
+        length_input.set_typed_value("50", &centimeters).unwrap();
+        assert!((length_input.value - 0.5).abs() < 1e-5);
+
+        // Values that aren't lengths are shown as they are
+        let number_input = NumberInput::new(1.25, 0.0..10.0);
+        assert_eq!(number_input.display_text(&centimeters), "1.25");
+    }
+
+    #[test]
+    fn displayed_values_have_at_most_2_decimals() {
+        assert_eq!(format_display_value(3.0), "3.0");
+        assert_eq!(format_display_value(0.5), "0.5");
+        assert_eq!(format_display_value(1.255), "1.25");
+        assert_eq!(format_display_value(-2.1), "-2.1");
+    }
 }
@@ -0,0 +1,179 @@
+use std::{iter::Peekable, str::Chars};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExpressionError {
+    UnexpectedCharacter(char),
+    UnexpectedEnd,
+    /// A number with more than one decimal point, like `1.2.3`.
+    InvalidNumber,
+    /// The expression results in an infinite or NaN value, for example when dividing by 0.
+    InvalidResult,
+}
+
+/// Evaluates a simple arithmetic expression like `3.5*2` or `(1 + 2) / -4`.
+///
+/// - Supports `+`, `-`, `*`, `/`, unary minus and parentheses.
+/// - Whitespace is ignored.
+pub fn evaluate_expression(expression: &str) -> Result<f32, ExpressionError> {
+    let mut parser = ExpressionParser {
+        characters: expression.chars().peekable(),
+    };
+
+    let value = parser.parse_sum()?;
+
+    if let Some(character) = parser.next_character() {
+        return Err(ExpressionError::UnexpectedCharacter(character));
+    }
+
+    match value.is_finite() {
+        true => Ok(value),
+        false => Err(ExpressionError::InvalidResult),
+    }
+}
+
+struct ExpressionParser<'a> {
+    characters: Peekable<Chars<'a>>,
+}
+
+impl ExpressionParser<'_> {
+    fn parse_sum(&mut self) -> Result<f32, ExpressionError> {
+        let mut value = self.parse_product()?;
+
+        while let Some(operator) =
+            self.next_character_if(|character| matches!(character, '+' | '-'))
+        {
+            let right_value = self.parse_product()?;
+
+            match operator {
+                '+' => value += right_value,
+                _ => value -= right_value,
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn parse_product(&mut self) -> Result<f32, ExpressionError> {
+        let mut value = self.parse_factor()?;
+
+        while let Some(operator) =
+            self.next_character_if(|character| matches!(character, '*' | '/'))
+        {
+            let right_value = self.parse_factor()?;
+
+            match operator {
+                '*' => value *= right_value,
+                _ => value /= right_value,
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn parse_factor(&mut self) -> Result<f32, ExpressionError> {
+        match self.next_character() {
+            Some('-') => Ok(-self.parse_factor()?),
+            Some('+') => self.parse_factor(),
+            Some('(') => {
+                let value = self.parse_sum()?;
+
+                match self.next_character() {
+                    Some(')') => Ok(value),
+                    Some(character) => Err(ExpressionError::UnexpectedCharacter(character)),
+                    None => Err(ExpressionError::UnexpectedEnd),
+                }
+            }
+            Some(character) if character.is_ascii_digit() || character == '.' => {
+                self.parse_number(character)
+            }
+            Some(character) => Err(ExpressionError::UnexpectedCharacter(character)),
+            None => Err(ExpressionError::UnexpectedEnd),
+        }
+    }
+
+    fn parse_number(&mut self, first_character: char) -> Result<f32, ExpressionError> {
+        let mut number = String::from(first_character);
+
+        while let Some(character) = self
+            .characters
+            .next_if(|character| character.is_ascii_digit() || *character == '.')
+        {
+            number.push(character);
+        }
+
+        number
+            .parse::<f32>()
+            .map_err(|_| ExpressionError::InvalidNumber)
+    }
+
+    /// Returns the next character that isn't whitespace.
+    fn next_character(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.characters.next()
+    }
+
+    fn next_character_if(&mut self, condition: impl Fn(char) -> bool) -> Option<char> {
+        self.skip_whitespace();
+        self.characters.next_if(|character| condition(*character))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .characters
+            .next_if(|character| character.is_whitespace())
+            .is_some()
+        {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evaluates_plain_numbers() {
+        assert_eq!(evaluate_expression("3"), Ok(3.0));
+        assert_eq!(evaluate_expression("0.25"), Ok(0.25));
+        assert_eq!(evaluate_expression(".5"), Ok(0.5));
+        assert_eq!(evaluate_expression(" -1.5 "), Ok(-1.5));
+    }
+
+    #[test]
+    fn evaluates_operators_with_precedence() {
+        assert_eq!(evaluate_expression("3.5*2"), Ok(7.0));
+        assert_eq!(evaluate_expression("1 + 2 * 3"), Ok(7.0));
+        assert_eq!(evaluate_expression("(1 + 2) * 3"), Ok(9.0));
+        assert_eq!(evaluate_expression("10 - 4 - 3"), Ok(3.0));
+        assert_eq!(evaluate_expression("8 / 2 / 2"), Ok(2.0));
+        assert_eq!(evaluate_expression("2 * -3"), Ok(-6.0));
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        assert_eq!(
+            evaluate_expression("3 +"),
+            Err(ExpressionError::UnexpectedEnd)
+        );
+        assert_eq!(
+            evaluate_expression("3a"),
+            Err(ExpressionError::UnexpectedCharacter('a'))
+        );
+        assert_eq!(
+            evaluate_expression("(1 + 2"),
+            Err(ExpressionError::UnexpectedEnd)
+        );
+        assert_eq!(
+            evaluate_expression("1.2.3"),
+            Err(ExpressionError::InvalidNumber)
+        );
+        assert_eq!(evaluate_expression(""), Err(ExpressionError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn rejects_division_by_zero() {
+        assert_eq!(
+            evaluate_expression("1 / 0"),
+            Err(ExpressionError::InvalidResult)
+        );
+    }
+}
@@ -1,11 +1,14 @@
-use bevy::{color::palettes::tailwind::*, prelude::*, ui::RelativeCursorPosition};
+use bevy::{prelude::*, ui::RelativeCursorPosition};
 
 use crate::{
-    ui::components::{
-        buttons::{ButtonBuilder, ButtonConfig},
-        content_size::ContentSizeConfig,
-        content_wrap::ContentWrapConfig,
-        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    ui::{
+        components::{
+            buttons::{ButtonBuilder, ButtonConfig},
+            content_size::ContentSizeConfig,
+            content_wrap::ContentWrapConfig,
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        theme::{ThemeColor, Themed},
     },
     utility::find_descendant_of_entity_mut,
     GameRunningSet,
@@ -57,7 +60,7 @@ impl Default for SliderInputConfig {
             handle_bar_width: 6.0,
             button: ButtonConfig {
                 wrap: ContentWrapConfig::empty()
-                    .with_background_color(NEUTRAL_800)
+                    .with_background_color(ThemeColor::SunkenSurface)
                     .rounded(),
                 size: ContentSizeConfig::full_width().with_min_height(12.0),
                 ..ButtonConfig::empty()
@@ -111,6 +114,15 @@ impl SliderInput {
     fn new(value: f32) -> Self {
         Self { value }
     }
+
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
+    /// Sets the value without sending a change event, used when the value is changed by something other than the user.
+    pub fn set_value(&mut self, value: f32) {
+        self.value = value.clamp(0.0, 1.0);
+    }
 }
 
 #[derive(Component)]
@@ -170,20 +182,21 @@ fn send_slider_value_changed_events(
 }
 
 fn update_slider_handle_position(
-    mut on_changed: EventReader<OnSliderInputValueChanged>,
+    slider_query: Query<(Entity, &SliderInput), Changed<SliderInput>>,
     mut slider_handle_query: Query<(Entity, &mut Style), With<SliderHandle>>,
     children_query: Query<&Children>,
 ) {
-    for event in on_changed.read() {
-        let (_, mut handle_style) = find_descendant_of_entity_mut(
-            event.slider_input_entity(),
+    for (slider_entity, slider_input) in slider_query.iter() {
+        let Some((_, mut handle_style)) = find_descendant_of_entity_mut(
+            slider_entity,
             &mut slider_handle_query,
             |item| item.0,
             &children_query,
-        )
-        .unwrap();
+        ) else {
+            continue;
+        };
 
-        handle_style.margin = calculate_handle_margin(event.new_value());
+        handle_style.margin = calculate_handle_margin(slider_input.value);
     }
 }
 
@@ -212,17 +225,19 @@ fn build_slider_handle_node(start_value: f32) -> impl Bundle {
 }
 
 fn build_slider_handle_bar_node(width: f32) -> impl Bundle {
-    (NodeBundle {
-        style: Style {
-            position_type: PositionType::Absolute,
-            height: Val::Percent(140.0),
-            width: Val::Px(width),
-            left: Val::Px(width / -2.0),
-            top: Val::Percent(-20.0),
+    (
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                height: Val::Percent(140.0),
+                width: Val::Px(width),
+                left: Val::Px(width / -2.0),
+                top: Val::Percent(-20.0),
+                ..default()
+            },
+            border_radius: BorderRadius::MAX,
             ..default()
         },
-        border_radius: BorderRadius::MAX,
-        background_color: NEUTRAL_100.into(),
-        ..default()
-    },)
+        Themed::default().with_background_color(ThemeColor::Text.into()),
+    )
 }
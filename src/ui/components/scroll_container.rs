@@ -0,0 +1,325 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+    ui::RelativeCursorPosition,
+    window::PrimaryWindow,
+};
+
+use crate::{
+    ui::theme::{ThemeColor, Themed},
+    utility::find_ancestor_of_entity_mut,
+    GameRunningSet,
+};
+
+use super::{flexbox::FlexboxConfig, UiComponentWithChildrenBuilder};
+
+/// How many pixels one line of mouse wheel scrolling moves the content.
+const SCROLL_LINE_HEIGHT: f32 = 24.0;
+const SCROLLBAR_WIDTH: f32 = 8.0;
+const SCROLLBAR_TRACK_COLOR: ThemeColor = ThemeColor::Surface;
+const SCROLLBAR_THUMB_COLOR: ThemeColor = ThemeColor::MutedText;
+
+pub struct ScrollContainerPlugin;
+
+impl Plugin for ScrollContainerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (
+                    scroll_hovered_container_on_mouse_wheel,
+                    drag_scrollbar_thumb,
+                )
+                    .in_set(GameRunningSet::GetUserInput),
+                update_scroll_container_layout.in_set(GameRunningSet::UpdateEntities),
+            ),
+        );
+    }
+}
+
+/// Marker component for elements that use the mouse wheel themselves, scroll containers don't scroll while they are hovered.
+#[derive(Component, Default)]
+pub struct BlocksScrolling;
+
+#[derive(Component, Default)]
+pub struct ScrollContainer {
+    /// How far the content is scrolled down in pixels.
+    scroll_offset: f32,
+    content_height: f32,
+    visible_height: f32,
+}
+
+impl ScrollContainer {
+    fn max_scroll_offset(&self) -> f32 {
+        (self.content_height - self.visible_height).max(0.0)
+    }
+
+    fn scroll_by(&mut self, delta: f32) {
+        self.scroll_offset = (self.scroll_offset + delta).clamp(0.0, self.max_scroll_offset());
+    }
+}
+
+#[derive(Component)]
+struct ScrollContent;
+
+#[derive(Component)]
+struct ScrollbarTrack;
+
+#[derive(Component, Default)]
+struct ScrollbarThumb {
+    /// The cursor height and scroll offset at the moment dragging started.
+    drag_start: Option<(f32, f32)>,
+}
+
+/// A container UiComponent that shows a scrollbar and clips its children when they are higher than the available space.
+#[derive(Default)]
+pub struct ScrollContainerBuilder {
+    content_flexbox: FlexboxConfig,
+}
+
+impl ScrollContainerBuilder {
+    /// Creates a scroll container that lays out its children with the given flexbox config.
+    pub fn new(content_flexbox: FlexboxConfig) -> Self {
+        Self { content_flexbox }
+    }
+}
+
+impl UiComponentWithChildrenBuilder for ScrollContainerBuilder {
+    fn spawn(
+        &self,
+        builder: &mut ChildBuilder,
+        components: impl Bundle,
+        children: impl FnOnce(&mut ChildBuilder),
+    ) -> Entity {
+        let conf = &self.content_flexbox;
+
+        let content_node = NodeBundle {
+            style: Style {
+                flex_direction: conf.flex_direction,
+                justify_content: conf.justify_content,
+                align_items: conf.align_items,
+                flex_wrap: conf.flex_wrap,
+                row_gap: conf.row_gap,
+                column_gap: conf.column_gap,
+                align_self: AlignSelf::FlexStart,
+                flex_shrink: 0.0,
+                ..default()
+            },
+            ..default()
+        };
+
+        let track_node = NodeBundle {
+            style: Style {
+                width: Val::Px(SCROLLBAR_WIDTH),
+                flex_shrink: 0.0,
+                ..default()
+            },
+            border_radius: BorderRadius::all(Val::Px(SCROLLBAR_WIDTH / 2.0)),
+            ..default()
+        };
+
+        let thumb_node = NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                ..default()
+            },
+            border_radius: BorderRadius::all(Val::Px(SCROLLBAR_WIDTH / 2.0)),
+            ..default()
+        };
+
+        builder
+            .spawn((self.build(), components))
+            .with_children(|container| {
+                container
+                    .spawn((content_node, ScrollContent))
+                    .with_children(children);
+
+                container
+                    .spawn((
+                        track_node,
+                        Themed::default().with_background_color(SCROLLBAR_TRACK_COLOR.into()),
+                        ScrollbarTrack,
+                    ))
+                    .with_children(|track| {
+                        track.spawn((
+                            thumb_node,
+                            Themed::default().with_background_color(SCROLLBAR_THUMB_COLOR.into()),
+                            ScrollbarThumb::default(),
+                            Interaction::default(),
+                        ));
+                    });
+            })
+            .id()
+    }
+
+    fn build(&self) -> impl Bundle {
+        (
+            NodeBundle {
+                style: Style {
+                    overflow: Overflow::clip_y(),
+                    column_gap: Val::Px(SCROLLBAR_WIDTH),
+                    min_height: Val::ZERO,
+                    max_height: Val::Percent(100.0),
+                    ..default()
+                },
+                ..default()
+            },
+            ScrollContainer::default(),
+        )
+    }
+}
+
+fn scroll_hovered_container_on_mouse_wheel(
+    mut on_mouse_wheel: EventReader<MouseWheel>,
+    mut container_query: Query<(&mut ScrollContainer, &RelativeCursorPosition)>,
+    blocking_query: Query<&RelativeCursorPosition, With<BlocksScrolling>>,
+) {
+    let delta_scroll: f32 = on_mouse_wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+
+    if delta_scroll == 0.0 {
+        return;
+    }
+
+    if blocking_query
+        .iter()
+        .any(|cursor_position| cursor_position.mouse_over())
+    {
+        return;
+    }
+
+    for (mut container, _) in container_query
+        .iter_mut()
+        .filter(|(_, cursor_position)| cursor_position.mouse_over())
+    {
+        container.scroll_by(-delta_scroll);
+    }
+}
+
+fn drag_scrollbar_thumb(
+    mut thumb_query: Query<(Entity, &mut ScrollbarThumb, &Interaction)>,
+    mut container_query: Query<(Entity, &mut ScrollContainer)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    parent_query: Query<&Parent>,
+) {
+    let Some(cursor_position) = window_query.single().cursor_position() else {
+        return;
+    };
+
+    for (thumb_entity, mut thumb, interaction) in thumb_query.iter_mut() {
+        let Some((_, mut container)) = find_ancestor_of_entity_mut(
+            thumb_entity,
+            &mut container_query,
+            |item| item.0,
+            &parent_query,
+        ) else {
+            continue;
+        };
+
+        if !mouse_input.pressed(MouseButton::Left) {
+            if thumb.drag_start.is_some() {
+                thumb.drag_start = None;
+            }
+            continue;
+        }
+
+        if *interaction == Interaction::Pressed && thumb.drag_start.is_none() {
+            thumb.drag_start = Some((cursor_position.y, container.scroll_offset));
+        }
+
+        let Some((start_cursor_y, start_offset)) = thumb.drag_start else {
+            continue;
+        };
+
+        if container.visible_height <= 0.0 {
+            continue;
+        }
+
+        // The track is as high as the visible area, so moving the thumb by one pixel scrolls the content by this ratio
+        let content_per_track_pixel = container.content_height / container.visible_height;
+
+        container.scroll_offset = start_offset;
+        container.scroll_by((cursor_position.y - start_cursor_y) * content_per_track_pixel);
+    }
+}
+
+fn update_scroll_container_layout(
+    mut container_query: Query<(&mut ScrollContainer, &Node, &Children)>,
+    mut style_query: Query<&mut Style>,
+    content_query: Query<&Node, With<ScrollContent>>,
+    track_query: Query<&Children, With<ScrollbarTrack>>,
+) {
+    for (mut container, container_node, container_children) in container_query.iter_mut() {
+        let Some((content_entity, content_node)) = container_children
+            .iter()
+            .find_map(|&child| Some((child, content_query.get(child).ok()?)))
+        else {
+            continue;
+        };
+
+        let visible_height = container_node.size().y;
+        let content_height = content_node.size().y;
+
+        if container.visible_height != visible_height || container.content_height != content_height
+        {
+            container.visible_height = visible_height;
+            container.content_height = content_height;
+        }
+
+        let scroll_offset = container
+            .scroll_offset
+            .clamp(0.0, container.max_scroll_offset());
+        if container.scroll_offset != scroll_offset {
+            container.scroll_offset = scroll_offset;
+        }
+
+        if let Ok(mut content_style) = style_query.get_mut(content_entity) {
+            let content_top = Val::Px(-scroll_offset);
+            if content_style.top != content_top {
+                content_style.top = content_top;
+            }
+        }
+
+        let Some((track_entity, track_children)) = container_children
+            .iter()
+            .find_map(|&child| Some((child, track_query.get(child).ok()?)))
+        else {
+            continue;
+        };
+
+        if let Ok(mut track_style) = style_query.get_mut(track_entity) {
+            let track_display = match container.max_scroll_offset() > 0.0 {
+                true => Display::Flex,
+                false => Display::None,
+            };
+            if track_style.display != track_display {
+                track_style.display = track_display;
+            }
+        }
+
+        if content_height <= 0.0 {
+            continue;
+        }
+
+        let thumb_height = Val::Percent((visible_height / content_height).min(1.0) * 100.0);
+        let thumb_top = Val::Percent(scroll_offset / content_height * 100.0);
+
+        for &thumb_entity in track_children.iter() {
+            let Ok(mut thumb_style) = style_query.get_mut(thumb_entity) else {
+                continue;
+            };
+
+            if thumb_style.height != thumb_height || thumb_style.top != thumb_top {
+                thumb_style.height = thumb_height;
+                thumb_style.top = thumb_top;
+            }
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 
+use crate::ui::theme::ThemedColor;
+
 use super::{
     content_size::ContentSizeConfig, content_wrap::ContentWrapConfig, flexbox::FlexboxConfig,
     UiComponentWithChildrenBuilder,
@@ -36,7 +38,7 @@ impl SectionConfig {
         self
     }
 
-    pub fn with_background_color(mut self, background_color: impl Into<BackgroundColor>) -> Self {
+    pub fn with_background_color(mut self, background_color: impl Into<ThemedColor>) -> Self {
         self.wrap = self.wrap.with_background_color(background_color);
         self
     }
@@ -68,27 +70,31 @@ impl SectionBuilder {
 
 impl UiComponentWithChildrenBuilder for SectionBuilder {
     fn build(&self) -> impl Bundle {
-        NodeBundle {
-            style: Style {
-                flex_direction: self.config.flexbox.flex_direction,
-                justify_content: self.config.flexbox.justify_content,
-                align_items: self.config.flexbox.align_items,
-                flex_wrap: self.config.flexbox.flex_wrap,
-                row_gap: self.config.flexbox.row_gap,
-                column_gap: self.config.flexbox.column_gap,
-                padding: self.config.wrap.padding,
-                border: self.config.wrap.border_size,
-                width: self.config.size.width,
-                height: self.config.size.height,
-                min_width: self.config.size.min_width,
-                min_height: self.config.size.min_height,
+        (
+            NodeBundle {
+                style: Style {
+                    flex_direction: self.config.flexbox.flex_direction,
+                    justify_content: self.config.flexbox.justify_content,
+                    align_items: self.config.flexbox.align_items,
+                    flex_wrap: self.config.flexbox.flex_wrap,
+                    row_gap: self.config.flexbox.row_gap,
+                    column_gap: self.config.flexbox.column_gap,
+                    padding: self.config.wrap.padding.initial_padding(),
+                    border: self.config.wrap.border_size,
+                    width: self.config.size.width,
+                    height: self.config.size.height,
+                    min_width: self.config.size.min_width,
+                    min_height: self.config.size.min_height,
+                    max_height: self.config.size.max_height,
+                    ..default()
+                },
+                background_color: self.config.wrap.background_color.initial_color().into(),
+                border_color: self.config.wrap.border_color.initial_color().into(),
+                border_radius: self.config.wrap.border_radius,
+                focus_policy: self.config.wrap.focus_policy,
                 ..default()
             },
-            background_color: self.config.wrap.background_color,
-            border_color: self.config.wrap.border_color,
-            border_radius: self.config.wrap.border_radius,
-            focus_policy: self.config.wrap.focus_policy,
-            ..default()
-        }
+            self.config.wrap.themed(),
+        )
     }
 }
@@ -1,12 +1,14 @@
 use bevy::{prelude::*, text::BreakLineOn};
 
+use crate::ui::theme::{ThemeColor, ThemeFontSize, Themed, ThemedColor, ThemedFontSize};
+
 use super::UiComponentBuilder;
 
 #[derive(Clone)]
 pub struct TextConfig {
     pub text: String,
-    pub color: Color,
-    pub font_size: f32,
+    pub color: ThemedColor,
+    pub font_size: ThemedFontSize,
     pub justify: JustifyText,
     pub linebreak_behavior: BreakLineOn,
 }
@@ -29,14 +31,38 @@ impl TextConfig {
         self.justify = justify;
         self
     }
+
+    /// Returns the text bundle for this config, with the theme parts of the text.
+    pub fn build_text_bundle(&self) -> impl Bundle {
+        (
+            TextBundle {
+                text: Text {
+                    sections: vec![TextSection {
+                        value: self.text.clone(),
+                        style: TextStyle {
+                            color: self.color.initial_color(),
+                            font_size: self.font_size.initial_font_size(),
+                            ..default()
+                        },
+                    }],
+                    justify: self.justify,
+                    linebreak_behavior: self.linebreak_behavior,
+                },
+                ..default()
+            },
+            Themed::default()
+                .with_text_color(self.color)
+                .with_font_size(self.font_size),
+        )
+    }
 }
 
 impl Default for TextConfig {
     fn default() -> Self {
         Self {
             text: String::new(),
-            color: Color::WHITE,
-            font_size: 24.0,
+            color: ThemeColor::Text.into(),
+            font_size: ThemeFontSize::Normal.into(),
             justify: JustifyText::Left,
             linebreak_behavior: BreakLineOn::WordBoundary,
         }
@@ -62,20 +88,6 @@ impl TextBuilder {
 
 impl UiComponentBuilder for TextBuilder {
     fn build(&self) -> impl Bundle {
-        TextBundle {
-            text: Text {
-                sections: vec![TextSection {
-                    value: self.config.text.clone(),
-                    style: TextStyle {
-                        color: self.config.color,
-                        font_size: self.config.font_size,
-                        ..default()
-                    },
-                }],
-                justify: self.config.justify,
-                linebreak_behavior: self.config.linebreak_behavior,
-            },
-            ..default()
-        }
+        self.config.build_text_bundle()
     }
 }
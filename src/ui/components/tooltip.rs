@@ -0,0 +1,249 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition, window::PrimaryWindow};
+
+use crate::{
+    ui::theme::{ThemeColor, ThemeFontSize, ThemeSpacing, Themed},
+    GameRunningSet,
+};
+
+use super::{
+    text::{TextBuilder, TextConfig},
+    UiComponentBuilder,
+};
+
+/// How long the cursor needs to rest on a node before its tooltip is shown.
+const TOOLTIP_DELAY_SECONDS: f32 = 0.6;
+const TOOLTIP_CURSOR_OFFSET: f32 = 16.0;
+const TOOLTIP_MAX_WIDTH: f32 = 280.0;
+
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipHover>().add_systems(
+            Update,
+            (
+                track_hovered_tooltip_target.in_set(GameRunningSet::GetUserInput),
+                update_tooltip_popup.in_set(GameRunningSet::UpdateEntities),
+            ),
+        );
+    }
+}
+
+/// Shows the given text in a small popup when the cursor rests on this node, can be added to any node.
+#[derive(Component, Clone, Debug)]
+pub struct Tooltip {
+    text: String,
+}
+
+impl Tooltip {
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into() }
+    }
+}
+
+/// Tracks which node with a [Tooltip] is hovered, and for how long.
+#[derive(Resource, Default, Debug)]
+struct TooltipHover {
+    target: Option<Entity>,
+    hovered_seconds: f32,
+    /// Clicking hides the tooltip until another node is hovered.
+    is_dismissed: bool,
+}
+
+impl TooltipHover {
+    fn update(&mut self, hovered_entity: Option<Entity>, delta_seconds: f32) {
+        if hovered_entity != self.target {
+            self.target = hovered_entity;
+            self.hovered_seconds = 0.0;
+            self.is_dismissed = false;
+            return;
+        }
+
+        self.hovered_seconds += delta_seconds;
+    }
+
+    fn dismiss(&mut self) {
+        self.is_dismissed = true;
+    }
+
+    fn should_show(&self) -> bool {
+        self.target.is_some() && !self.is_dismissed && self.hovered_seconds >= TOOLTIP_DELAY_SECONDS
+    }
+}
+
+/// The floating node that displays the text of the hovered [Tooltip].
+#[derive(Component)]
+struct TooltipPopup {
+    target: Entity,
+}
+
+fn track_hovered_tooltip_target(
+    mut tooltip_hover: ResMut<TooltipHover>,
+    tooltip_query: Query<(Entity, &Node, &RelativeCursorPosition), With<Tooltip>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    time: Res<Time>,
+) {
+    // Nested nodes can both have a tooltip, the smallest one is the most specific
+    let hovered_entity = tooltip_query
+        .iter()
+        .filter(|(_, _, cursor_position)| cursor_position.mouse_over())
+        .min_by(|(_, node_a, _), (_, node_b, _)| {
+            let area_a = node_a.size().x * node_a.size().y;
+            let area_b = node_b.size().x * node_b.size().y;
+            area_a.total_cmp(&area_b)
+        })
+        .map(|(entity, _, _)| entity);
+
+    tooltip_hover.update(hovered_entity, time.delta_seconds());
+
+    if mouse_input.get_just_pressed().next().is_some() {
+        tooltip_hover.dismiss();
+    }
+}
+
+fn update_tooltip_popup(
+    mut commands: Commands,
+    tooltip_hover: Res<TooltipHover>,
+    tooltip_query: Query<&Tooltip>,
+    popup_query: Query<(Entity, &TooltipPopup)>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    let target_to_show = tooltip_hover
+        .should_show()
+        .then_some(tooltip_hover.target)
+        .flatten();
+
+    let mut is_shown = false;
+
+    for (popup_entity, popup) in popup_query.iter() {
+        match Some(popup.target) == target_to_show {
+            true => is_shown = true,
+            false => commands.entity(popup_entity).despawn_recursive(),
+        }
+    }
+
+    let Some(target_entity) = target_to_show else {
+        return;
+    };
+
+    if is_shown {
+        return;
+    }
+
+    let Ok(tooltip) = tooltip_query.get(target_entity) else {
+        return;
+    };
+
+    let Ok(window) = window_query.get_single() else {
+        return;
+    };
+
+    let Some(cursor_position) = window.cursor_position() else {
+        return;
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: build_popup_style(cursor_position, window.size()),
+                border_radius: BorderRadius::all(Val::Px(4.0)),
+                z_index: ZIndex::Global(100),
+                ..default()
+            },
+            Themed::default()
+                .with_padding(ThemeSpacing::Medium.into())
+                .with_background_color(ThemeColor::SunkenSurface.into())
+                .with_border_color(ThemeColor::Border.into()),
+            TooltipPopup {
+                target: target_entity,
+            },
+        ))
+        .with_children(|popup| {
+            TextBuilder::new(TextConfig {
+                text: tooltip.text.clone(),
+                font_size: ThemeFontSize::Small.into(),
+                ..default()
+            })
+            .spawn(popup, ());
+        });
+}
+
+/// Places the popup next to the cursor, on the side of the cursor that has the most space left.
+fn build_popup_style(cursor_position: Vec2, window_size: Vec2) -> Style {
+    let (left, right) = match cursor_position.x < window_size.x / 2.0 {
+        true => (
+            Val::Px(cursor_position.x + TOOLTIP_CURSOR_OFFSET),
+            Val::Auto,
+        ),
+        false => (
+            Val::Auto,
+            Val::Px(window_size.x - cursor_position.x + TOOLTIP_CURSOR_OFFSET),
+        ),
+    };
+
+    let (top, bottom) = match cursor_position.y < window_size.y / 2.0 {
+        true => (
+            Val::Px(cursor_position.y + TOOLTIP_CURSOR_OFFSET),
+            Val::Auto,
+        ),
+        false => (
+            Val::Auto,
+            Val::Px(window_size.y - cursor_position.y + TOOLTIP_CURSOR_OFFSET),
+        ),
+    };
+
+    Style {
+        position_type: PositionType::Absolute,
+        left,
+        right,
+        top,
+        bottom,
+        max_width: Val::Px(TOOLTIP_MAX_WIDTH),
+        border: UiRect::all(Val::Px(1.0)),
+        ..default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tooltip_is_shown_after_delay() {
+        let entity = Entity::from_raw(1);
+        let mut tooltip_hover = TooltipHover::default();
+
+        tooltip_hover.update(Some(entity), 0.1);
+        assert!(!tooltip_hover.should_show());
+
+        tooltip_hover.update(Some(entity), TOOLTIP_DELAY_SECONDS);
+        assert!(tooltip_hover.should_show());
+    }
+
+    #[test]
+    fn hovering_another_node_restarts_delay() {
+        let mut tooltip_hover = TooltipHover::default();
+
+        tooltip_hover.update(Some(Entity::from_raw(1)), 0.0);
+        tooltip_hover.update(Some(Entity::from_raw(1)), TOOLTIP_DELAY_SECONDS);
+        tooltip_hover.update(Some(Entity::from_raw(2)), TOOLTIP_DELAY_SECONDS);
+
+        assert!(!tooltip_hover.should_show());
+    }
+
+    #[test]
+    fn dismissed_tooltip_stays_hidden_until_target_changes() {
+        let entity = Entity::from_raw(1);
+        let mut tooltip_hover = TooltipHover::default();
+
+        tooltip_hover.update(Some(entity), 0.0);
+        tooltip_hover.dismiss();
+        tooltip_hover.update(Some(entity), TOOLTIP_DELAY_SECONDS);
+        assert!(!tooltip_hover.should_show());
+
+        tooltip_hover.update(None, 0.0);
+        tooltip_hover.update(Some(entity), 0.0);
+        tooltip_hover.update(Some(entity), TOOLTIP_DELAY_SECONDS);
+        assert!(tooltip_hover.should_show());
+    }
+}
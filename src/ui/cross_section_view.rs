@@ -0,0 +1,615 @@
+use bevy::{prelude::*, ui::FocusPolicy};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    game_modes::GameMode,
+    keybindings::{ActionInput, InputAction},
+    road::{
+        active_road::ActiveRoad, road_builder::calculate_road_design_slice, road_data::RoadData,
+    },
+    settings::{OnSettingChangeRequested, SettingChange, Settings},
+    utility::distance::LengthUnit,
+    GameRunningSet,
+};
+
+use super::{
+    components::{
+        content_size::ContentSizeConfig,
+        flexbox::FlexboxConfig,
+        section::{SectionBuilder, SectionConfig},
+        text::{TextBuilder, TextConfig},
+        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    },
+    inputs::text_input::TextInput,
+    theme::{ThemeColor, ThemeFontSize, Themed, ThemedColor},
+};
+
+/// The space around the drawing for the dimension lines and labels, in pixels.
+const CANVAS_SIDE_MARGIN: f32 = 48.0;
+const CANVAS_TOP_MARGIN: f32 = 56.0;
+const CANVAS_BOTTOM_MARGIN: f32 = 72.0;
+const OUTLINE_THICKNESS: f32 = 2.0;
+const DIMENSION_LINE_THICKNESS: f32 = 1.0;
+const DIMENSION_TICK_LENGTH: f32 = 10.0;
+/// The distance between the rows of dimension lines below the road, in pixels.
+const DIMENSION_ROW_SPACING: f32 = 32.0;
+const MARKING_THICKNESS: f32 = 4.0;
+const MARKING_MIN_WIDTH: f32 = 2.0;
+const LABEL_SIZE: Vec2 = Vec2::new(96.0, 18.0);
+
+pub struct CrossSectionViewPlugin;
+
+impl Plugin for CrossSectionViewPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                cycle_view_mode_on_input.in_set(GameRunningSet::SendCommands),
+                (apply_view_mode, redraw_cross_section)
+                    .chain()
+                    .in_set(GameRunningSet::UpdateEntities),
+            )
+                .run_if(in_state(GameMode::RoadEditor)),
+        );
+    }
+}
+
+/// Where the cross-section of the road is shown in the road editor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CrossSectionViewMode {
+    #[default]
+    Hidden,
+    /// Shown in a panel over the 3D preview.
+    Alongside,
+    /// Shown in place of the 3D preview, which is hidden.
+    Instead,
+}
+
+impl CrossSectionViewMode {
+    pub const ALL: [CrossSectionViewMode; 3] = [
+        CrossSectionViewMode::Hidden,
+        CrossSectionViewMode::Alongside,
+        CrossSectionViewMode::Instead,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            CrossSectionViewMode::Hidden => "Hidden",
+            CrossSectionViewMode::Alongside => "Next to the preview",
+            CrossSectionViewMode::Instead => "Instead of the preview",
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            CrossSectionViewMode::Hidden => CrossSectionViewMode::Alongside,
+            CrossSectionViewMode::Alongside => CrossSectionViewMode::Instead,
+            CrossSectionViewMode::Instead => CrossSectionViewMode::Hidden,
+        }
+    }
+}
+
+/// Holds the cross-section panel, its size follows the [CrossSectionViewMode].
+#[derive(Component)]
+struct CrossSectionView;
+
+/// The node that the shapes of the cross-section are drawn in.
+#[derive(Component)]
+struct CrossSectionCanvas;
+
+pub fn spawn_cross_section_view(builder: &mut ChildBuilder) {
+    let container_node = NodeBundle {
+        style: Style {
+            display: Display::None,
+            ..default()
+        },
+        focus_policy: FocusPolicy::Block,
+        ..default()
+    };
+
+    builder
+        .spawn((container_node, CrossSectionView))
+        .with_children(|container| {
+            SectionBuilder::new(
+                SectionConfig::default()
+                    .with_flexbox_config(FlexboxConfig::column().with_px_gap(4.0))
+                    .with_content_size_config(ContentSizeConfig::full())
+                    .with_background_color(ThemeColor::SunkenSurface),
+            )
+            .spawn(container, (), |panel| {
+                TextBuilder::default()
+                    .with_text("Cross-section")
+                    .spawn(panel, ());
+
+                panel.spawn((
+                    NodeBundle {
+                        style: Style {
+                            flex_grow: 1.0,
+                            width: Val::Percent(100.0),
+                            min_height: Val::ZERO,
+                            overflow: Overflow::clip(),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    CrossSectionCanvas,
+                ));
+            });
+        });
+}
+
+fn cycle_view_mode_on_input(
+    mut on_change_request: EventWriter<OnSettingChangeRequested>,
+    action_input: ActionInput,
+    settings: Res<Settings>,
+    text_input_query: Query<&TextInput>,
+) {
+    if !action_input.just_pressed(InputAction::CycleCrossSectionView)
+        || text_input_query
+            .iter()
+            .any(|text_input| text_input.is_selected())
+    {
+        return;
+    }
+
+    on_change_request.send(OnSettingChangeRequested::new(
+        SettingChange::CrossSectionView(settings.cross_section_view.next()),
+    ));
+}
+
+/// Sizes the cross-section panel and hides the 3D preview when the cross-section is shown instead of it.
+fn apply_view_mode(
+    mut view_query: Query<&mut Style, With<CrossSectionView>>,
+    mut visibility_query: Query<&mut Visibility>,
+    settings: Res<Settings>,
+    active_road: Res<ActiveRoad>,
+) {
+    let view_mode = settings.cross_section_view;
+
+    for mut style in view_query.iter_mut() {
+        let target_style = build_view_style(view_mode);
+
+        if *style != target_style {
+            *style = target_style;
+        }
+    }
+
+    let Some(mut preview_visibility) = active_road
+        .road_preview_entity()
+        .and_then(|preview_entity| visibility_query.get_mut(preview_entity).ok())
+    else {
+        return;
+    };
+
+    preview_visibility.set_if_neq(match view_mode {
+        CrossSectionViewMode::Instead => Visibility::Hidden,
+        _ => Visibility::Inherited,
+    });
+}
+
+fn build_view_style(view_mode: CrossSectionViewMode) -> Style {
+    match view_mode {
+        CrossSectionViewMode::Hidden => Style {
+            display: Display::None,
+            ..default()
+        },
+        CrossSectionViewMode::Alongside => Style {
+            width: Val::Percent(45.0),
+            height: Val::Percent(50.0),
+            min_height: Val::Px(240.0),
+            align_self: AlignSelf::FlexEnd,
+            margin: UiRect::horizontal(Val::Auto),
+            ..default()
+        },
+        CrossSectionViewMode::Instead => Style {
+            flex_grow: 1.0,
+            height: Val::Percent(100.0),
+            margin: UiRect::horizontal(Val::Px(20.0)),
+            ..default()
+        },
+    }
+}
+
+/// Draws the cross-section again whenever the road, the settings or the size of the canvas change.
+fn redraw_cross_section(
+    mut commands: Commands,
+    canvas_query: Query<(Entity, Ref<Node>), With<CrossSectionCanvas>>,
+    active_road: Res<ActiveRoad>,
+    settings: Res<Settings>,
+) {
+    for (canvas_entity, canvas_node) in canvas_query.iter() {
+        if !active_road.is_changed() && !settings.is_changed() && !canvas_node.is_changed() {
+            continue;
+        }
+
+        commands.entity(canvas_entity).despawn_descendants();
+
+        if settings.cross_section_view == CrossSectionViewMode::Hidden {
+            continue;
+        }
+
+        let shapes = build_cross_section_shapes(
+            active_road.road_data(),
+            canvas_node.size(),
+            settings.length_unit,
+        );
+
+        commands.entity(canvas_entity).with_children(|canvas| {
+            for shape in shapes {
+                spawn_shape(canvas, shape);
+            }
+        });
+    }
+}
+
+fn spawn_shape(canvas: &mut ChildBuilder, shape: CrossSectionShape) {
+    match shape {
+        CrossSectionShape::Rect { rect, color } => {
+            canvas.spawn((
+                NodeBundle {
+                    style: absolute_style(rect),
+                    background_color: color.initial_color().into(),
+                    ..default()
+                },
+                Themed::default().with_background_color(color),
+            ));
+        }
+        CrossSectionShape::Label { center, text } => {
+            let rect = Rect::from_center_size(center, LABEL_SIZE);
+
+            canvas
+                .spawn(NodeBundle {
+                    style: Style {
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..absolute_style(rect)
+                    },
+                    ..default()
+                })
+                .with_children(|label| {
+                    TextBuilder::new(TextConfig {
+                        text,
+                        color: ThemeColor::MutedText.into(),
+                        font_size: ThemeFontSize::Small.into(),
+                        ..TextConfig::centered()
+                    })
+                    .spawn(label, ());
+                });
+        }
+    }
+}
+
+fn absolute_style(rect: Rect) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(rect.min.x),
+        top: Val::Px(rect.min.y),
+        width: Val::Px(rect.width()),
+        height: Val::Px(rect.height()),
+        ..default()
+    }
+}
+
+/// A part of the cross-section drawing, positioned in pixels from the top-left of the canvas.
+#[derive(Clone, Debug, PartialEq)]
+enum CrossSectionShape {
+    Rect { rect: Rect, color: ThemedColor },
+    Label { center: Vec2, text: String },
+}
+
+/// Converts positions on the road's cross-section in meters to positions on the canvas.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct CanvasTransform {
+    /// Pixels per meter, the same horizontally and vertically.
+    scale: f32,
+    /// The position of the road's reference line at ground level.
+    origin: Vec2,
+}
+
+impl CanvasTransform {
+    /// Fits the road and its structures within the canvas, leaving room for the dimension lines and labels.
+    fn fit(road_data: &RoadData, canvas_size: Vec2) -> Self {
+        let available_size = (canvas_size
+            - Vec2::new(
+                CANVAS_SIDE_MARGIN * 2.0,
+                CANVAS_TOP_MARGIN + CANVAS_BOTTOM_MARGIN,
+            ))
+        .max(Vec2::ONE);
+
+        let max_height = road_data
+            .components()
+            .iter()
+            .map(|road_component| road_component.height())
+            .fold(0.0, f32::max);
+
+        let bounds = road_data.structures().iter().fold(
+            Rect::new(
+                road_data.left_edge(),
+                0.0,
+                road_data.right_edge(),
+                max_height,
+            ),
+            |bounds, road_structure| bounds.union(road_structure.bounds()),
+        );
+
+        let road_size = Vec2::new(bounds.width(), bounds.max.y).max(Vec2::splat(0.01));
+        let scale = (available_size / road_size).min_element();
+
+        Self {
+            scale,
+            origin: Vec2::new(
+                canvas_size.x / 2.0 - bounds.center().x * scale,
+                CANVAS_TOP_MARGIN + (available_size.y + road_size.y * scale) / 2.0,
+            ),
+        }
+    }
+
+    fn to_canvas(self, point: Vec2) -> Vec2 {
+        Vec2::new(
+            self.origin.x + point.x * self.scale,
+            self.origin.y - point.y * self.scale,
+        )
+    }
+}
+
+/// Returns the shapes that draw the outline of the road, the dimensions of its components and its markings.
+fn build_cross_section_shapes(
+    road_data: &RoadData,
+    canvas_size: Vec2,
+    length_unit: LengthUnit,
+) -> Vec<CrossSectionShape> {
+    let mut shapes = Vec::new();
+
+    if road_data.component_count() == 0 {
+        return shapes;
+    }
+
+    let transform = CanvasTransform::fit(road_data, canvas_size);
+
+    for (road_component, position) in road_data
+        .components()
+        .iter()
+        .zip(road_data.component_positions())
+    {
+        let top_left = transform.to_canvas(Vec2::new(position.left, road_component.height()));
+        let bottom_right = transform.to_canvas(Vec2::new(position.right, 0.0));
+
+        shapes.push(CrossSectionShape::Rect {
+            rect: Rect::from_corners(top_left, bottom_right),
+            color: ThemedColor::Fixed(road_component.color),
+        });
+    }
+
+    let slice = calculate_road_design_slice(road_data, road_data.component_count());
+    let outline_points: Vec<Vec2> = slice
+        .vertices
+        .iter()
+        .map(|vertex| transform.to_canvas(vertex.truncate()))
+        .collect();
+
+    for line in outline_points.windows(2) {
+        shapes.push(line_shape(
+            line[0],
+            line[1],
+            OUTLINE_THICKNESS,
+            ThemeColor::Text,
+        ));
+    }
+
+    for road_structure in road_data.structures() {
+        let bounds = road_structure.bounds();
+
+        shapes.push(CrossSectionShape::Rect {
+            rect: Rect::from_corners(
+                transform.to_canvas(bounds.min),
+                transform.to_canvas(bounds.max),
+            ),
+            color: ThemedColor::Fixed(road_structure.color),
+        });
+    }
+
+    let ground_left = transform.to_canvas(Vec2::new(road_data.left_edge(), 0.0));
+    let ground_right = transform.to_canvas(Vec2::new(road_data.right_edge(), 0.0));
+    shapes.push(line_shape(
+        ground_left,
+        ground_right,
+        OUTLINE_THICKNESS,
+        ThemeColor::Text,
+    ));
+
+    let component_row_y = ground_left.y + DIMENSION_ROW_SPACING / 2.0;
+
+    for (road_component, position) in road_data
+        .components()
+        .iter()
+        .zip(road_data.component_positions())
+    {
+        push_dimension_shapes(
+            &mut shapes,
+            transform.to_canvas(Vec2::new(position.left, 0.0)).x,
+            transform.to_canvas(Vec2::new(position.right, 0.0)).x,
+            component_row_y,
+            length_unit.format_meters(road_component.size.x),
+        );
+
+        if road_component.height() > 0.0 {
+            let top_center =
+                transform.to_canvas(Vec2::new(position.center, road_component.height()));
+
+            shapes.push(CrossSectionShape::Label {
+                center: top_center - Vec2::Y * LABEL_SIZE.y,
+                text: format!("h {}", length_unit.format_meters(road_component.height())),
+            });
+        }
+    }
+
+    push_dimension_shapes(
+        &mut shapes,
+        ground_left.x,
+        ground_right.x,
+        component_row_y + DIMENSION_ROW_SPACING,
+        format!(
+            "Total {}",
+            length_unit.format_meters(road_data.total_width())
+        ),
+    );
+
+    for road_marking in road_data.markings() {
+        let surface_height = road_data
+            .find_road_component_under_point(road_marking.x_position)
+            .map(|component_under_point| {
+                road_data.components()[component_under_point.road_component_index].height()
+            })
+            .unwrap_or_default();
+
+        let surface_center =
+            transform.to_canvas(Vec2::new(road_marking.x_position, surface_height));
+        let marking_size = Vec2::new(
+            (road_marking.segment_width * transform.scale).max(MARKING_MIN_WIDTH),
+            MARKING_THICKNESS,
+        );
+
+        shapes.push(CrossSectionShape::Rect {
+            rect: Rect::from_center_size(
+                surface_center - Vec2::Y * MARKING_THICKNESS / 2.0,
+                marking_size,
+            ),
+            color: ThemedColor::Fixed(road_marking.color),
+        });
+
+        shapes.push(CrossSectionShape::Label {
+            center: Vec2::new(surface_center.x, CANVAS_TOP_MARGIN / 2.0),
+            text: length_unit.format_meters(road_marking.x_position),
+        });
+
+        shapes.push(line_shape(
+            Vec2::new(
+                surface_center.x,
+                CANVAS_TOP_MARGIN / 2.0 + LABEL_SIZE.y / 2.0,
+            ),
+            surface_center - Vec2::Y * MARKING_THICKNESS,
+            DIMENSION_LINE_THICKNESS,
+            ThemeColor::MutedText,
+        ));
+    }
+
+    shapes
+}
+
+/// Adds a horizontal dimension line with ticks at both ends, and its label below it.
+fn push_dimension_shapes(
+    shapes: &mut Vec<CrossSectionShape>,
+    left: f32,
+    right: f32,
+    y: f32,
+    text: String,
+) {
+    let tick_offset = Vec2::Y * DIMENSION_TICK_LENGTH / 2.0;
+
+    shapes.push(line_shape(
+        Vec2::new(left, y),
+        Vec2::new(right, y),
+        DIMENSION_LINE_THICKNESS,
+        ThemeColor::MutedText,
+    ));
+
+    for x in [left, right] {
+        shapes.push(line_shape(
+            Vec2::new(x, y) - tick_offset,
+            Vec2::new(x, y) + tick_offset,
+            DIMENSION_LINE_THICKNESS,
+            ThemeColor::MutedText,
+        ));
+    }
+
+    shapes.push(CrossSectionShape::Label {
+        center: Vec2::new((left + right) / 2.0, y + LABEL_SIZE.y / 2.0 + 2.0),
+        text,
+    });
+}
+
+/// Returns a horizontal or vertical line between two points, as a thin rect.
+fn line_shape(start: Vec2, end: Vec2, thickness: f32, color: ThemeColor) -> CrossSectionShape {
+    let half_thickness = Vec2::splat(thickness / 2.0);
+
+    CrossSectionShape::Rect {
+        rect: Rect::from_corners(
+            start.min(end) - half_thickness,
+            start.max(end) + half_thickness,
+        ),
+        color: color.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::road::{road_data::road_with_raised_sidewalk, road_marking::RoadMarking};
+
+    use super::*;
+
+    fn road_with_marked_lane() -> RoadData {
+        let mut road_data = road_with_raised_sidewalk();
+        road_data
+            .markings_mut()
+            .push(RoadMarking::new(-1.0, 0.2, Color::WHITE));
+        road_data
+    }
+
+    fn labels(shapes: &[CrossSectionShape]) -> Vec<&str> {
+        shapes
+            .iter()
+            .filter_map(|shape| match shape {
+                CrossSectionShape::Label { text, .. } => Some(text.as_str()),
+                CrossSectionShape::Rect { .. } => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn road_fits_within_the_canvas_margins() {
+        let road_data = road_with_marked_lane();
+        let canvas_size = Vec2::new(696.0, 400.0);
+        let transform = CanvasTransform::fit(&road_data, canvas_size);
+
+        assert_eq!(transform.scale, 100.0);
+        assert_eq!(
+            transform
+                .to_canvas(Vec2::new(-road_data.half_width(), 0.0))
+                .x,
+            CANVAS_SIDE_MARGIN
+        );
+        assert_eq!(
+            transform
+                .to_canvas(Vec2::new(road_data.half_width(), 0.0))
+                .x,
+            canvas_size.x - CANVAS_SIDE_MARGIN
+        );
+    }
+
+    #[test]
+    fn dimensions_are_labelled_in_the_length_unit() {
+        let shapes = build_cross_section_shapes(
+            &road_with_marked_lane(),
+            Vec2::new(696.0, 400.0),
+            LengthUnit::Centimeters,
+        );
+
+        let labels = labels(&shapes);
+
+        assert!(labels.contains(&"400.00 cm"));
+        assert!(labels.contains(&"200.00 cm"));
+        assert!(labels.contains(&"h 40.00 cm"));
+        assert!(labels.contains(&"Total 600.00 cm"));
+        assert!(labels.contains(&"-100.00 cm"));
+    }
+
+    #[test]
+    fn empty_roads_draw_nothing() {
+        let road_data = RoadData::new(String::from("Empty road"), Vec::new(), Vec::new());
+
+        assert!(
+            build_cross_section_shapes(&road_data, Vec2::splat(400.0), LengthUnit::Meters)
+                .is_empty()
+        );
+    }
+}
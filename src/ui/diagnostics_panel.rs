@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::road_validation::{DiagnosticSeverity, RoadDiagnostics},
+    GameRunningSet,
+};
+
+use super::{
+    components::{
+        flexbox::FlexboxConfig,
+        section::{SectionBuilder, SectionConfig},
+        text::{TextBuilder, TextConfig},
+        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    },
+    theme::{ThemeColor, ThemeFontSize},
+};
+
+pub struct DiagnosticsPanelPlugin;
+
+impl Plugin for DiagnosticsPanelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnExit(GameMode::RoadEditor), reset_road_diagnostics)
+            .add_systems(
+                Update,
+                (
+                    rebuild_diagnostics_panel
+                        .in_set(GameRunningSet::DespawnEntities)
+                        .run_if(resource_changed::<RoadDiagnostics>),
+                    toggle_panel_visibility_based_on_content.after(GameRunningSet::DespawnEntities),
+                )
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
+    }
+}
+
+/// Marker component for the panel that lists the problems in the active road.
+#[derive(Component)]
+pub struct DiagnosticsPanel;
+
+pub fn spawn_diagnostics_panel(builder: &mut ChildBuilder) {
+    SectionBuilder::new(
+        SectionConfig::default()
+            .with_flexbox_config(FlexboxConfig::column().with_px_gap(4.0))
+            .with_background_color(ThemeColor::SunkenSurface),
+    )
+    .spawn(builder, DiagnosticsPanel, |_| {});
+}
+
+/// Returns the theme color that is used to show diagnostics of the given severity.
+pub fn severity_color(severity: DiagnosticSeverity) -> ThemeColor {
+    match severity {
+        DiagnosticSeverity::Warning => ThemeColor::Warning,
+        DiagnosticSeverity::Error => ThemeColor::Danger,
+    }
+}
+
+fn reset_road_diagnostics(mut road_diagnostics: ResMut<RoadDiagnostics>) {
+    *road_diagnostics = RoadDiagnostics::default();
+}
+
+fn rebuild_diagnostics_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<DiagnosticsPanel>>,
+    road_diagnostics: Res<RoadDiagnostics>,
+) {
+    let Ok(panel_entity) = panel_query.get_single() else {
+        return;
+    };
+
+    let diagnostics = road_diagnostics.diagnostics();
+
+    commands.entity(panel_entity).despawn_descendants();
+
+    if diagnostics.is_empty() {
+        return;
+    }
+
+    commands.entity(panel_entity).with_children(|panel| {
+        TextBuilder::default()
+            .with_text(format!("Problems ({})", diagnostics.len()))
+            .spawn(panel, ());
+
+        for diagnostic in diagnostics {
+            TextBuilder::new(TextConfig {
+                text: diagnostic.message.clone(),
+                color: severity_color(diagnostic.severity).into(),
+                font_size: ThemeFontSize::Small.into(),
+                ..default()
+            })
+            .spawn(panel, ());
+        }
+    });
+}
+
+fn toggle_panel_visibility_based_on_content(
+    mut panel_query: Query<(Option<&Children>, &mut Style), With<DiagnosticsPanel>>,
+) {
+    let Ok((children, mut style)) = panel_query.get_single_mut() else {
+        return;
+    };
+
+    let target_display = match children {
+        Some(_) => Display::Flex,
+        None => Display::None,
+    };
+
+    if style.display != target_display {
+        style.display = target_display;
+    }
+}
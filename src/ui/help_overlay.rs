@@ -0,0 +1,402 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    keybindings::{ActionInput, InputAction, Keybindings},
+    road_drawer::road_drawer_tool::RoadDrawerTool,
+    GameRunningSet,
+};
+
+use super::{
+    components::{
+        content_size::ContentSizeConfig,
+        content_wrap::ContentWrapConfig,
+        flexbox::{FlexboxBuilder, FlexboxConfig},
+        section::{SectionBuilder, SectionConfig},
+        text::{TextBuilder, TextConfig},
+        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    },
+    keybindings_menu::binding_capture_is_active,
+    theme::{ThemeColor, ThemeFontSize},
+};
+
+const KEYS_COLUMN_WIDTH: f32 = 260.0;
+const SHORTCUT_FONT_SIZE: ThemeFontSize = ThemeFontSize::Small;
+
+pub struct HelpOverlayPlugin;
+
+impl Plugin for HelpOverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                toggle_help_overlay_on_input
+                    .in_set(GameRunningSet::GetUserInput)
+                    .run_if(not(binding_capture_is_active)),
+                rebuild_help_overlay_on_change.in_set(GameRunningSet::UpdateEntities),
+            ),
+        );
+    }
+}
+
+/// Marker component for the overlay that lists the currently active shortcuts.
+#[derive(Component)]
+pub struct HelpOverlay;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Shortcut {
+    keys: String,
+    description: &'static str,
+}
+
+impl Shortcut {
+    fn new(keys: impl Into<String>, description: &'static str) -> Self {
+        Self {
+            keys: keys.into(),
+            description,
+        }
+    }
+
+    /// A shortcut that uses the current binding of the action.
+    fn from_action(
+        keybindings: &Keybindings,
+        action: InputAction,
+        description: &'static str,
+    ) -> Self {
+        Self::new(keybindings.label(action), description)
+    }
+}
+
+#[derive(Debug)]
+struct ShortcutGroup {
+    title: &'static str,
+    shortcuts: Vec<Shortcut>,
+}
+
+/// Returns the shortcuts that can be used in the given mode, grouped by what they act on.
+///
+/// - `drawer_tool` is only used in [GameMode::RoadDrawer], to list the shortcuts of the active tool.
+fn active_shortcut_groups(
+    game_mode: GameMode,
+    drawer_tool: RoadDrawerTool,
+    keybindings: &Keybindings,
+) -> Vec<ShortcutGroup> {
+    let mut general = vec![Shortcut::from_action(
+        keybindings,
+        InputAction::ToggleHelp,
+        "Show or hide this help",
+    )];
+
+    if game_mode != GameMode::MainMenu {
+        general.push(Shortcut::new("Esc", "Open the pause menu"));
+    }
+
+    general.push(Shortcut::from_action(
+        keybindings,
+        InputAction::Quit,
+        match game_mode {
+            GameMode::RoadEditor => "Quit, asking about unsaved changes",
+            _ => "Quit",
+        },
+    ));
+
+    let mut groups = vec![ShortcutGroup {
+        title: "General",
+        shortcuts: general,
+    }];
+
+    if game_mode == GameMode::MainMenu {
+        return groups;
+    }
+
+    let activate_camera = keybindings.label(InputAction::ActivateCamera);
+
+    let mut camera = vec![
+        Shortcut::new(format!("{activate_camera} + drag"), "Rotate the camera"),
+        Shortcut::new("Scroll", "Zoom the camera"),
+    ];
+
+    if game_mode == GameMode::RoadDrawer {
+        let movement_keys = [
+            InputAction::MoveCameraForward,
+            InputAction::MoveCameraLeft,
+            InputAction::MoveCameraBack,
+            InputAction::MoveCameraRight,
+        ]
+        .map(|action| keybindings.label(action))
+        .join("/");
+
+        camera.push(Shortcut::new(
+            format!("{activate_camera} + {movement_keys}"),
+            "Move the camera",
+        ));
+    }
+
+    groups.push(ShortcutGroup {
+        title: "Camera",
+        shortcuts: camera,
+    });
+
+    match game_mode {
+        GameMode::RoadEditor => {
+            let edit_preview = keybindings.label(InputAction::EditPreview);
+
+            groups.push(ShortcutGroup {
+                title: "Preview",
+                shortcuts: vec![
+                    Shortcut::new(edit_preview.clone(), "Select the clicked component"),
+                    Shortcut::new(
+                        format!("{edit_preview} + drag an edge"),
+                        "Change the width of a component",
+                    ),
+                    Shortcut::new(
+                        format!("{edit_preview} + drag a marking"),
+                        "Move a marking, snapping to component edges",
+                    ),
+                    Shortcut::new(
+                        "Ctrl while dragging a marking",
+                        "Move the marking without snapping",
+                    ),
+                    Shortcut::new(
+                        keybindings.label(InputAction::CycleCrossSectionView),
+                        "Show the cross-section next to or instead of the preview",
+                    ),
+                ],
+            });
+
+            groups.push(ShortcutGroup {
+                title: "Text fields",
+                shortcuts: vec![
+                    Shortcut::new("Enter", "Confirm the typed text"),
+                    Shortcut::new("Esc", "Cancel the typed text"),
+                    Shortcut::new("Ctrl+A", "Select all text"),
+                    Shortcut::new("Ctrl+C / Ctrl+X / Ctrl+V", "Copy, cut or paste"),
+                ],
+            });
+
+            groups.push(ShortcutGroup {
+                title: "Inputs",
+                shortcuts: vec![
+                    Shortcut::new("Up / Down or scroll", "Step the hovered number"),
+                    Shortcut::new(
+                        "Shift / Ctrl while stepping",
+                        "Take larger or smaller steps",
+                    ),
+                    Shortcut::new("Space", "Toggle the hovered checkbox"),
+                    Shortcut::new("Up / Down + Enter", "Choose an option in an open dropdown"),
+                ],
+            });
+        }
+        GameMode::RoadDrawer => {
+            let mut tool_shortcuts = vec![Shortcut::from_action(
+                keybindings,
+                InputAction::ToggleBulldozer,
+                "Switch between drawing and the bulldozer",
+            )];
+
+            tool_shortcuts.extend(match drawer_tool {
+                RoadDrawerTool::Drawer => vec![
+                    Shortcut::from_action(
+                        keybindings,
+                        InputAction::UseDrawerTool,
+                        "Place the next point of the road",
+                    ),
+                    Shortcut::from_action(
+                        keybindings,
+                        InputAction::CancelDrawing,
+                        "Cancel the section being drawn",
+                    ),
+                ],
+                RoadDrawerTool::Bulldozer => vec![Shortcut::from_action(
+                    keybindings,
+                    InputAction::UseDrawerTool,
+                    "Remove the clicked road section",
+                )],
+            });
+
+            groups.push(ShortcutGroup {
+                title: match drawer_tool {
+                    RoadDrawerTool::Drawer => "Drawer",
+                    RoadDrawerTool::Bulldozer => "Bulldozer",
+                },
+                shortcuts: tool_shortcuts,
+            });
+        }
+        GameMode::MainMenu => (),
+    }
+
+    groups
+}
+
+fn toggle_help_overlay_on_input(
+    mut commands: Commands,
+    action_input: ActionInput,
+    keybindings: Res<Keybindings>,
+    game_mode: Res<State<GameMode>>,
+    drawer_tool: Res<State<RoadDrawerTool>>,
+    overlay_query: Query<Entity, With<HelpOverlay>>,
+) {
+    if !action_input.just_pressed(InputAction::ToggleHelp) {
+        return;
+    }
+
+    match overlay_query.get_single() {
+        Ok(overlay_entity) => commands.entity(overlay_entity).despawn_recursive(),
+        Err(_) => spawn_help_overlay(
+            &mut commands,
+            active_shortcut_groups(*game_mode.get(), *drawer_tool.get(), &keybindings),
+        ),
+    }
+}
+
+/// Keeps an open overlay in sync with the shortcuts of the current mode, tool and keybindings.
+fn rebuild_help_overlay_on_change(
+    mut commands: Commands,
+    keybindings: Res<Keybindings>,
+    game_mode: Res<State<GameMode>>,
+    drawer_tool: Res<State<RoadDrawerTool>>,
+    overlay_query: Query<Entity, With<HelpOverlay>>,
+) {
+    if !game_mode.is_changed() && !drawer_tool.is_changed() && !keybindings.is_changed() {
+        return;
+    }
+
+    let Ok(overlay_entity) = overlay_query.get_single() else {
+        return;
+    };
+
+    commands.entity(overlay_entity).despawn_recursive();
+    spawn_help_overlay(
+        &mut commands,
+        active_shortcut_groups(*game_mode.get(), *drawer_tool.get(), &keybindings),
+    );
+}
+
+fn spawn_help_overlay(commands: &mut Commands, shortcut_groups: Vec<ShortcutGroup>) {
+    let container_node = NodeBundle {
+        style: Style {
+            // Doesn't cover the whole screen, so the cursor is only on the UI when it's on the panel
+            position_type: PositionType::Absolute,
+            top: Val::Px(20.0),
+            right: Val::Px(20.0),
+            max_height: Val::Percent(90.0),
+            ..default()
+        },
+        z_index: ZIndex::Global(50),
+        ..default()
+    };
+
+    commands
+        .spawn((container_node, HelpOverlay))
+        .with_children(|container| {
+            SectionBuilder::new(SectionConfig {
+                wrap: ContentWrapConfig::default().with_background_color(ThemeColor::SunkenSurface),
+                flexbox: FlexboxConfig::column().with_px_gap(16.0),
+                size: ContentSizeConfig::empty().with_max_height(100.0),
+            })
+            .spawn(container, (), |panel| {
+                TextBuilder::default()
+                    .with_text("Shortcuts")
+                    .spawn(panel, ());
+
+                for group in shortcut_groups {
+                    spawn_shortcut_group(panel, group);
+                }
+            });
+        });
+}
+
+fn spawn_shortcut_group(builder: &mut ChildBuilder, group: ShortcutGroup) {
+    FlexboxBuilder::new(FlexboxConfig::column().with_px_gap(4.0)).spawn(builder, (), |section| {
+        TextBuilder::new(TextConfig {
+            text: group.title.to_string(),
+            color: ThemeColor::Accent.into(),
+            font_size: SHORTCUT_FONT_SIZE.into(),
+            ..default()
+        })
+        .spawn(section, ());
+
+        for shortcut in group.shortcuts {
+            FlexboxBuilder::new(FlexboxConfig::row()).spawn(section, (), |row| {
+                row.spawn(NodeBundle {
+                    style: Style {
+                        width: Val::Px(KEYS_COLUMN_WIDTH),
+                        ..default()
+                    },
+                    ..default()
+                })
+                .with_children(|keys_column| {
+                    TextBuilder::new(TextConfig {
+                        text: shortcut.keys,
+                        color: ThemeColor::MutedText.into(),
+                        font_size: SHORTCUT_FONT_SIZE.into(),
+                        ..default()
+                    })
+                    .spawn(keys_column, ());
+                });
+
+                TextBuilder::new(TextConfig {
+                    text: shortcut.description.to_string(),
+                    font_size: SHORTCUT_FONT_SIZE.into(),
+                    ..default()
+                })
+                .spawn(row, ());
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contains_shortcut(groups: &[ShortcutGroup], action: InputAction) -> bool {
+        let keys = action.default_binding().label();
+
+        groups
+            .iter()
+            .flat_map(|group| &group.shortcuts)
+            .any(|shortcut| shortcut.keys == keys)
+    }
+
+    #[test]
+    fn quit_and_help_are_listed_in_every_mode() {
+        for game_mode in [
+            GameMode::MainMenu,
+            GameMode::RoadEditor,
+            GameMode::RoadDrawer,
+        ] {
+            let groups =
+                active_shortcut_groups(game_mode, RoadDrawerTool::Drawer, &Keybindings::default());
+
+            assert!(contains_shortcut(&groups, InputAction::Quit));
+            assert!(contains_shortcut(&groups, InputAction::ToggleHelp));
+        }
+    }
+
+    #[test]
+    fn bulldozer_key_is_only_listed_in_road_drawer() {
+        let keybindings = Keybindings::default();
+
+        assert!(contains_shortcut(
+            &active_shortcut_groups(GameMode::RoadDrawer, RoadDrawerTool::Drawer, &keybindings),
+            InputAction::ToggleBulldozer
+        ));
+        assert!(!contains_shortcut(
+            &active_shortcut_groups(GameMode::RoadEditor, RoadDrawerTool::Drawer, &keybindings),
+            InputAction::ToggleBulldozer
+        ));
+    }
+
+    #[test]
+    fn drawer_tool_shortcuts_follow_the_active_tool() {
+        let groups = active_shortcut_groups(
+            GameMode::RoadDrawer,
+            RoadDrawerTool::Bulldozer,
+            &Keybindings::default(),
+        );
+
+        assert!(groups.iter().any(|group| group.title == "Bulldozer"));
+        assert!(!contains_shortcut(&groups, InputAction::CancelDrawing));
+    }
+}
@@ -1,25 +1,39 @@
+mod clipboard;
+pub mod text_editor;
+
 use bevy::{
-    color::palettes::tailwind::*,
     input::{
         keyboard::{Key, KeyboardInput},
         ButtonState,
     },
     prelude::*,
-    ui::FocusPolicy,
+    text::TextLayoutInfo,
+    ui::{FocusPolicy, RelativeCursorPosition, UiSystem},
 };
+use clipboard::Clipboard;
+use text_editor::{CaretMovement, DeleteDirection, TextConstraints, TextEditor};
 
 use crate::{
+    ui::{
+        components::{
+            text::{TextBuilder, TextConfig},
+            UiComponentBuilder,
+        },
+        theme::{ThemeFontSize, UiTheme, UiThemeSet},
+    },
     utility::find_descendant_of_entity_mut,
     GameRunningSet,
 };
 
+const CARET: &str = "|";
+
 pub struct TextInputPlugin;
 
 impl Plugin for TextInputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<OnTextInputSelected>()
+        app.init_non_send_resource::<Clipboard>()
+            .add_event::<OnTextInputSelected>()
             .add_event::<OnTextInputDeselected>()
-            .add_event::<OnTextInputDisplayTextChanged>()
             .add_event::<OnTextInputValueChanged>()
             .add_systems(
                 Update,
@@ -28,13 +42,23 @@ impl Plugin for TextInputPlugin {
                         select_input_when_clicked,
                         deselect_input_on_click,
                         deselect_input_on_esc,
-                        handle_keyboard_input,
-                        confirm_text_input_on_enter,
+                        (
+                            place_caret_with_mouse,
+                            handle_keyboard_input,
+                            confirm_text_input_on_enter,
+                        )
+                            .chain(),
                     )
                         .in_set(GameRunningSet::GetUserInput),
-                    (update_input_border_color, update_input_display_text)
-                        .in_set(GameRunningSet::UpdateEntities),
+                    update_input_border_color.in_set(GameRunningSet::UpdateEntities),
                 ),
+            )
+            .add_systems(
+                PostUpdate,
+                // The caret and selection keep their color when the theme recolors the text
+                update_input_display_text
+                    .after(UiThemeSet)
+                    .before(UiSystem::Layout),
             );
     }
 }
@@ -43,36 +67,35 @@ impl Plugin for TextInputPlugin {
 pub struct TextInput {
     is_selected: bool,
     current_text: String,
-    text_being_edited: String,
+    editor: TextEditor,
+    constraints: TextConstraints,
+    /// True while the left mouse button is held after pressing it on this input, dragging selects text.
+    is_mouse_selecting: bool,
 }
 
 impl TextInput {
     pub fn new(text: String) -> Self {
         Self {
-            is_selected: false,
             current_text: text,
-            text_being_edited: String::default(),
+            ..default()
         }
     }
 
+    pub fn with_constraints(mut self, constraints: TextConstraints) -> Self {
+        self.constraints = constraints;
+        self
+    }
+
     pub fn select(&mut self) {
         self.is_selected = true;
 
-        self.text_being_edited = self.current_text.clone();
+        self.editor = TextEditor::new(self.current_text.clone());
     }
 
-    pub fn cancel_edit(
-        &mut self,
-        on_changed: &mut EventWriter<OnTextInputDisplayTextChanged>,
-        text_input_entity: Entity,
-    ) {
+    pub fn cancel_edit(&mut self) {
         self.is_selected = false;
-        self.text_being_edited.clear();
-
-        on_changed.send(OnTextInputDisplayTextChanged::new(
-            text_input_entity,
-            self.current_text.clone(),
-        ));
+        self.is_mouse_selecting = false;
+        self.editor = TextEditor::default();
     }
 
     pub fn confirm_edit(
@@ -80,8 +103,8 @@ impl TextInput {
         on_changed: &mut EventWriter<OnTextInputValueChanged>,
         text_input_entity: Entity,
     ) {
-        self.is_selected = false;
-        self.current_text = self.text_being_edited.clone();
+        self.current_text = self.editor.text().to_string();
+        self.cancel_edit();
 
         on_changed.send(OnTextInputValueChanged::new(
             text_input_entity,
@@ -89,24 +112,178 @@ impl TextInput {
         ));
     }
 
-    pub fn update_text_being_edited(
-        &mut self,
-        keyboard_input: &Key,
-        on_changed: &mut EventWriter<OnTextInputDisplayTextChanged>,
-        text_input_entity: Entity,
-    ) {
-        self.text_being_edited =
-            parse_keyboard_input(self.text_being_edited.clone(), keyboard_input);
+    /// Replaces the current text, this also replaces the text being edited if the input is selected.
+    pub fn set_text(&mut self, text: String) {
+        if self.is_selected {
+            self.editor = TextEditor::new(text.clone());
+        }
 
-        on_changed.send(OnTextInputDisplayTextChanged::new(
-            text_input_entity,
-            self.text_being_edited.clone(),
-        ));
+        self.current_text = text;
     }
 
     pub fn current_text(&self) -> &str {
         &self.current_text
     }
+
+    pub fn text_being_edited(&self) -> &str {
+        self.editor.text()
+    }
+
+    pub fn is_selected(&self) -> bool {
+        self.is_selected
+    }
+
+    /// Applies a key press to the text being edited.
+    fn handle_key(
+        &mut self,
+        event: &KeyboardInput,
+        modifiers: KeyModifiers,
+        clipboard: &mut Clipboard,
+    ) {
+        let editor = &mut self.editor;
+
+        if modifiers.control {
+            match event.key_code {
+                KeyCode::KeyA => editor.select_all(),
+                KeyCode::KeyC => {
+                    if let Some(selected_text) = editor.selected_text() {
+                        clipboard.set_text(selected_text.to_string());
+                    }
+                }
+                KeyCode::KeyX => {
+                    if let Some(cut_text) = editor.cut() {
+                        clipboard.set_text(cut_text);
+                    }
+                }
+                KeyCode::KeyV => editor.insert(&clipboard.text(), &self.constraints),
+                _ => (),
+            }
+        }
+
+        let word_wise = modifiers.control;
+
+        match &event.logical_key {
+            Key::Character(characters) if !modifiers.control => {
+                editor.insert(characters, &self.constraints);
+            }
+            Key::Space => editor.insert(" ", &self.constraints),
+            Key::Backspace => editor.delete(DeleteDirection::Backward, word_wise),
+            Key::Delete => editor.delete(DeleteDirection::Forward, word_wise),
+            Key::ArrowLeft => editor.move_caret(
+                match word_wise {
+                    true => CaretMovement::WordLeft,
+                    false => CaretMovement::Left,
+                },
+                modifiers.shift,
+            ),
+            Key::ArrowRight => editor.move_caret(
+                match word_wise {
+                    true => CaretMovement::WordRight,
+                    false => CaretMovement::Right,
+                },
+                modifiers.shift,
+            ),
+            Key::Home => editor.move_caret(CaretMovement::Start, modifiers.shift),
+            Key::End => editor.move_caret(CaretMovement::End, modifiers.shift),
+            _ => (),
+        }
+    }
+
+    /// Returns the text of each section of the display.
+    ///
+    /// - The display always has 5 sections: text before the selection, caret, selected text, caret, text after the selection.
+    /// - Only 1 of the caret sections is filled, depending on which side of the selection the caret is on.
+    fn display_sections(&self) -> [String; 5] {
+        if !self.is_selected {
+            return [
+                self.current_text.clone(),
+                String::new(),
+                String::new(),
+                String::new(),
+                String::new(),
+            ];
+        }
+
+        let text = self.editor.text();
+        let caret = self.editor.caret();
+        let selection = self.editor.selection().unwrap_or(caret..caret);
+        let [start_offset, selection_offset, end_offset] = self.display_section_offsets();
+
+        let substring = |from: usize, to: usize| -> String {
+            text.chars().skip(from).take(to - from).collect()
+        };
+
+        let (caret_before, caret_after) = match caret == selection.start {
+            true => (CARET, ""),
+            false => ("", CARET),
+        };
+
+        [
+            substring(start_offset, selection_offset),
+            caret_before.to_string(),
+            substring(selection_offset, end_offset),
+            caret_after.to_string(),
+            substring(end_offset, text.chars().count()),
+        ]
+    }
+
+    /// Returns the character offsets of the text sections of the display (sections 0, 2 and 4).
+    fn display_section_offsets(&self) -> [usize; 3] {
+        let caret = self.editor.caret();
+        let selection = self.editor.selection().unwrap_or(caret..caret);
+
+        [0, selection.start, selection.end]
+    }
+
+    /// Returns the caret position that is closest to the given x position in the layout of the display.
+    fn caret_position_in_layout(&self, layout_info: &TextLayoutInfo, x: f32) -> usize {
+        let sections = self.display_sections();
+        let [start_offset, selection_offset, end_offset] = self.display_section_offsets();
+
+        layout_info
+            .glyphs
+            .iter()
+            .filter(|glyph| glyph.position.x < x)
+            .filter_map(|glyph| {
+                // Caret sections don't contain any text
+                let section_offset = match glyph.section_index {
+                    0 => start_offset,
+                    2 => selection_offset,
+                    4 => end_offset,
+                    _ => return None,
+                };
+
+                let section = &sections[glyph.section_index];
+                let character_index = section[..glyph.byte_index.min(section.len())]
+                    .chars()
+                    .count();
+
+                // The caret goes after the glyph that is left of the given position
+                Some(section_offset + character_index + 1)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct KeyModifiers {
+    control: bool,
+    shift: bool,
+}
+
+impl KeyModifiers {
+    fn from_input(key_input: &ButtonInput<KeyCode>) -> Self {
+        Self {
+            control: key_input.any_pressed([
+                KeyCode::ControlLeft,
+                KeyCode::ControlRight,
+                KeyCode::SuperLeft,
+                KeyCode::SuperRight,
+            ]),
+            shift: key_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+        }
+    }
 }
 
 #[derive(Component)]
@@ -134,21 +311,6 @@ impl OnTextInputDeselected {
     }
 }
 
-#[derive(Event)]
-pub struct OnTextInputDisplayTextChanged {
-    text_input_entity: Entity,
-    text: String,
-}
-
-impl OnTextInputDisplayTextChanged {
-    pub fn new(text_input_entity: Entity, text: String) -> Self {
-        Self {
-            text_input_entity,
-            text,
-        }
-    }
-}
-
 #[derive(Event)]
 pub struct OnTextInputValueChanged {
     text_input_entity: Entity,
@@ -172,21 +334,52 @@ impl OnTextInputValueChanged {
     }
 }
 
+#[derive(Default)]
+pub struct TextInputConfig {
+    pub text: TextConfig,
+    pub constraints: TextConstraints,
+}
+
 pub fn spawn_text_input_node(
     builder: &mut ChildBuilder,
     root_components: impl Bundle,
     text: impl Into<String>,
 ) -> Entity {
-    let text = text.into();
-    let mut text_input = builder.spawn(build_text_input_node(root_components, text.clone()));
+    spawn_configured_text_input_node(
+        builder,
+        root_components,
+        TextInputConfig {
+            text: default_text_config(text),
+            ..default()
+        },
+    )
+}
 
-    text_input
+/// Spawns a text input node of which the displayed text and allowed input are set by the given config.
+pub fn spawn_configured_text_input_node(
+    builder: &mut ChildBuilder,
+    root_components: impl Bundle,
+    config: TextInputConfig,
+) -> Entity {
+    let text_input = TextInput::new(config.text.text.clone()).with_constraints(config.constraints);
+
+    builder
+        .spawn(build_text_input_node(root_components, text_input))
         .with_children(|text_input| {
-            text_input.spawn(build_text_display_node(text));
+            TextBuilder::new(config.text).spawn(text_input, TextDisplay);
         })
         .id()
 }
 
+/// Returns the text config that is used by text inputs by default.
+pub fn default_text_config(text: impl Into<String>) -> TextConfig {
+    TextConfig {
+        text: text.into(),
+        font_size: ThemeFontSize::Large.into(),
+        ..default()
+    }
+}
+
 // Systems
 
 fn select_input_when_clicked(
@@ -204,7 +397,6 @@ fn select_input_when_clicked(
 
 fn deselect_input_on_click(
     mut on_deselected: EventWriter<OnTextInputDeselected>,
-    mut on_changed: EventWriter<OnTextInputDisplayTextChanged>,
     mouse_input: Res<ButtonInput<MouseButton>>,
     mut input_query: Query<(&Interaction, &mut TextInput, Entity)>,
 ) {
@@ -212,7 +404,7 @@ fn deselect_input_on_click(
         for (_, mut input, entity) in input_query.iter_mut().filter(|(interaction, input, _)| {
             **interaction == Interaction::None && input.is_selected
         }) {
-            input.cancel_edit(&mut on_changed, entity);
+            input.cancel_edit();
 
             on_deselected.send(OnTextInputDeselected::new(entity));
         }
@@ -221,7 +413,6 @@ fn deselect_input_on_click(
 
 fn deselect_input_on_esc(
     mut on_deselected: EventWriter<OnTextInputDeselected>,
-    mut on_changed: EventWriter<OnTextInputDisplayTextChanged>,
     key_input: Res<ButtonInput<KeyCode>>,
     mut input_query: Query<(&mut TextInput, Entity)>,
 ) {
@@ -230,27 +421,76 @@ fn deselect_input_on_esc(
             .iter_mut()
             .filter(|(input, _)| input.is_selected)
         {
-            input.cancel_edit(&mut on_changed, entity);
+            input.cancel_edit();
 
             on_deselected.send(OnTextInputDeselected::new(entity));
         }
     }
 }
 
+/// Places the caret where the input is clicked, dragging the mouse selects text.
+fn place_caret_with_mouse(
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    key_input: Res<ButtonInput<KeyCode>>,
+    mut input_query: Query<(Entity, &Interaction, &mut TextInput)>,
+    display_query: Query<(Entity, &RelativeCursorPosition, &TextLayoutInfo), With<TextDisplay>>,
+    children_query: Query<&Children>,
+) {
+    let extend_selection = KeyModifiers::from_input(&key_input).shift;
+
+    for (input_entity, interaction, mut input) in input_query.iter_mut() {
+        if !input.is_selected {
+            continue;
+        }
+
+        let is_placing_caret =
+            mouse_input.just_pressed(MouseButton::Left) && *interaction == Interaction::Pressed;
+
+        if is_placing_caret {
+            input.is_mouse_selecting = true;
+        } else if !mouse_input.pressed(MouseButton::Left) {
+            input.is_mouse_selecting = false;
+        }
+
+        if !input.is_mouse_selecting {
+            continue;
+        }
+
+        let Some((_, cursor_position, layout_info)) = children_query
+            .iter_descendants(input_entity)
+            .find_map(|entity| display_query.get(entity).ok())
+        else {
+            continue;
+        };
+
+        let Some(normalized_cursor_position) = cursor_position.normalized else {
+            continue;
+        };
+
+        // Glyph positions use the same units as the size of the layout
+        let cursor_x = normalized_cursor_position.x * layout_info.logical_size.x;
+        let caret_position = input.caret_position_in_layout(layout_info, cursor_x);
+
+        input
+            .editor
+            .set_caret(caret_position, extend_selection || !is_placing_caret);
+    }
+}
+
 fn handle_keyboard_input(
     mut on_keyboard_input: EventReader<KeyboardInput>,
-    mut on_changed: EventWriter<OnTextInputDisplayTextChanged>,
-    mut input_query: Query<(&mut TextInput, Entity)>,
+    mut input_query: Query<&mut TextInput>,
+    mut clipboard: NonSendMut<Clipboard>,
+    key_input: Res<ButtonInput<KeyCode>>,
 ) {
+    let modifiers = KeyModifiers::from_input(&key_input);
+
     for event in on_keyboard_input
         .read()
         .filter(|event| event.state == ButtonState::Pressed)
     {
-        for (mut input, text_input_entity) in input_query
-            .iter_mut()
-            .filter(|(input, _)| input.is_selected)
-        {
-            input.update_text_being_edited(&event.logical_key, &mut on_changed, text_input_entity);
+        for mut input in input_query.iter_mut().filter(|input| input.is_selected) {
+            input.handle_key(event, modifiers, &mut clipboard);
         }
     }
 }
@@ -274,44 +514,86 @@ fn confirm_text_input_on_enter(
 }
 
 fn update_input_display_text(
-    mut on_changed: EventReader<OnTextInputDisplayTextChanged>,
+    input_query: Query<(Entity, Ref<TextInput>)>,
     mut text_display_query: Query<(Entity, &mut Text), With<TextDisplay>>,
     children_query: Query<&Children>,
+    theme: Res<UiTheme>,
 ) {
-    for event in on_changed.read() {
-        let (_, mut text) = find_descendant_of_entity_mut(
-            event.text_input_entity,
+    for (input_entity, input) in input_query
+        .iter()
+        .filter(|(_, input)| input.is_changed() || theme.is_changed())
+    {
+        let Some((_, mut text)) = find_descendant_of_entity_mut(
+            input_entity,
             &mut text_display_query,
             |item| item.0,
             &children_query,
-        ).unwrap();
+        ) else {
+            continue;
+        };
+
+        let base_style = text.sections[0].style.clone();
+
+        text.sections = input
+            .display_sections()
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let color = match index {
+                    1..=3 => theme.colors.accent,
+                    _ => base_style.color,
+                };
 
-        text.sections[0].value = event.text.clone();
+                TextSection {
+                    value,
+                    style: TextStyle {
+                        color,
+                        ..base_style.clone()
+                    },
+                }
+            })
+            .collect();
     }
 }
 
 fn update_input_border_color(
     mut on_selected: EventReader<OnTextInputSelected>,
     mut on_deselected: EventReader<OnTextInputDeselected>,
-    mut input_query: Query<&mut BorderColor, (With<TextInput>, Changed<TextInput>)>,
+    mut input_query: Query<(Ref<TextInput>, &mut BorderColor)>,
+    theme: Res<UiTheme>,
 ) {
     for event in on_selected.read() {
-        if let Ok(mut border_color) = input_query.get_mut(event.text_input_entity) {
-            border_color.0 = CYAN_300.into();
+        if let Ok((_, mut border_color)) = input_query.get_mut(event.text_input_entity) {
+            border_color.0 = text_input_border_color(true, &theme);
         };
     }
 
     for event in on_deselected.read() {
-        if let Ok(mut border_color) = input_query.get_mut(event.text_input_entity) {
-            border_color.0 = NEUTRAL_900.into();
+        if let Ok((_, mut border_color)) = input_query.get_mut(event.text_input_entity) {
+            border_color.0 = text_input_border_color(false, &theme);
         };
     }
+
+    for (input, mut border_color) in input_query
+        .iter_mut()
+        .filter(|(input, _)| input.is_added() || theme.is_changed())
+    {
+        border_color.0 = text_input_border_color(input.is_selected(), &theme);
+    }
+}
+
+/// Returns the border color of a text input, which is highlighted while the input is selected.
+pub fn text_input_border_color(is_selected: bool, theme: &UiTheme) -> Color {
+    match is_selected {
+        true => theme.colors.accent,
+        false => theme.colors.border,
+    }
 }
 
-fn build_text_input_node(root_components: impl Bundle, text: String) -> impl Bundle {
+fn build_text_input_node(root_components: impl Bundle, text_input: TextInput) -> impl Bundle {
     (
         root_components,
-        TextInput::new(text),
+        text_input,
         NodeBundle {
             style: Style {
                 justify_content: JustifyContent::Center,
@@ -320,47 +602,9 @@ fn build_text_input_node(root_components: impl Bundle, text: String) -> impl Bun
                 border: UiRect::all(Val::Px(2.0)),
                 ..default()
             },
-            border_color: BorderColor(NEUTRAL_900.into()),
             focus_policy: FocusPolicy::Block,
             ..default()
         },
         Interaction::default(),
     )
 }
-
-fn build_text_display_node(text: String) -> impl Bundle {
-    (
-        TextDisplay,
-        TextBundle {
-            text: Text {
-                sections: vec![TextSection {
-                    value: text,
-                    style: TextStyle {
-                        color: Color::WHITE,
-                        font_size: 30.0,
-                        ..default()
-                    },
-                }],
-                ..default()
-            },
-            ..default()
-        },
-    )
-}
-
-fn parse_keyboard_input(mut text: String, keyboard_input: &Key) -> String {
-    match keyboard_input {
-        Key::Character(character) => {
-            text.push_str(&character);
-        }
-        Key::Space => {
-            text.push(' ');
-        }
-        Key::Backspace => {
-            text.pop();
-        }
-        _ => (),
-    }
-
-    text
-}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+/// Gives access to the system clipboard, falls back to a clipboard that only works within the app if the system clipboard is unavailable.
+///
+/// - The system clipboard is kept alive for the lifetime of the app, since on some platforms copied text is lost when it's dropped.
+pub struct Clipboard {
+    system_clipboard: Option<arboard::Clipboard>,
+    fallback_text: String,
+}
+
+impl Default for Clipboard {
+    fn default() -> Self {
+        let system_clipboard = arboard::Clipboard::new()
+            .inspect_err(|error| warn!("System clipboard is unavailable: {error}"))
+            .ok();
+
+        Self {
+            system_clipboard,
+            fallback_text: String::new(),
+        }
+    }
+}
+
+impl Clipboard {
+    pub fn text(&mut self) -> String {
+        self.system_clipboard
+            .as_mut()
+            .and_then(|clipboard| clipboard.get_text().ok())
+            .unwrap_or_else(|| self.fallback_text.clone())
+    }
+
+    pub fn set_text(&mut self, text: String) {
+        if let Some(clipboard) = self.system_clipboard.as_mut() {
+            if let Err(error) = clipboard.set_text(text.clone()) {
+                warn!("Failed to copy to the system clipboard: {error}");
+            }
+        }
+
+        self.fallback_text = text;
+    }
+}
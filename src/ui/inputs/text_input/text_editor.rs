@@ -0,0 +1,334 @@
+use std::ops::Range;
+
+/// Limits what text can be entered in a TextInput.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TextConstraints {
+    /// The maximum amount of characters, or None for no limit.
+    pub max_length: Option<usize>,
+    /// Returns true for characters that are allowed, or None to allow all characters.
+    pub character_filter: Option<fn(char) -> bool>,
+}
+
+impl TextConstraints {
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    pub fn with_character_filter(mut self, character_filter: fn(char) -> bool) -> Self {
+        self.character_filter = Some(character_filter);
+        self
+    }
+
+    fn allows(&self, character: char) -> bool {
+        !character.is_control()
+            && self
+                .character_filter
+                .is_none_or(|is_allowed| is_allowed(character))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CaretMovement {
+    Left,
+    Right,
+    WordLeft,
+    WordRight,
+    Start,
+    End,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeleteDirection {
+    Backward,
+    Forward,
+}
+
+/// Text that is being edited, with a caret and an optional selection.
+///
+/// - Positions are in characters, not bytes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextEditor {
+    text: String,
+    caret: usize,
+    /// The position where the selection started, the selection goes from here to the caret.
+    selection_anchor: Option<usize>,
+}
+
+impl TextEditor {
+    /// Returns a new TextEditor with the caret at the end of the given text.
+    pub fn new(text: impl Into<String>) -> Self {
+        let text = text.into();
+
+        Self {
+            caret: text.chars().count(),
+            text,
+            selection_anchor: None,
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn caret(&self) -> usize {
+        self.caret
+    }
+
+    /// Returns the selected range of characters, or None if nothing is selected.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?;
+
+        match anchor.cmp(&self.caret) {
+            std::cmp::Ordering::Less => Some(anchor..self.caret),
+            std::cmp::Ordering::Equal => None,
+            std::cmp::Ordering::Greater => Some(self.caret..anchor),
+        }
+    }
+
+    pub fn selected_text(&self) -> Option<&str> {
+        let selection = self.selection()?;
+
+        Some(&self.text[self.byte_index(selection.start)..self.byte_index(selection.end)])
+    }
+
+    pub fn select_all(&mut self) {
+        self.selection_anchor = Some(0);
+        self.caret = self.length();
+    }
+
+    /// Moves the caret to the given position, extending the selection to it if `extend_selection` is true.
+    pub fn set_caret(&mut self, position: usize, extend_selection: bool) {
+        let position = position.min(self.length());
+
+        match extend_selection {
+            true => {
+                self.selection_anchor.get_or_insert(self.caret);
+            }
+            false => self.selection_anchor = None,
+        }
+
+        self.caret = position;
+    }
+
+    pub fn move_caret(&mut self, movement: CaretMovement, extend_selection: bool) {
+        // Moving without extending the selection collapses it to the side you're moving towards
+        if let (Some(selection), false) = (self.selection(), extend_selection) {
+            match movement {
+                CaretMovement::Left => return self.set_caret(selection.start, false),
+                CaretMovement::Right => return self.set_caret(selection.end, false),
+                _ => (),
+            }
+        }
+
+        let position = match movement {
+            CaretMovement::Left => self.caret.saturating_sub(1),
+            CaretMovement::Right => self.caret + 1,
+            CaretMovement::WordLeft => self.previous_word_boundary(),
+            CaretMovement::WordRight => self.next_word_boundary(),
+            CaretMovement::Start => 0,
+            CaretMovement::End => self.length(),
+        };
+
+        self.set_caret(position, extend_selection);
+    }
+
+    /// Inserts text at the caret, replacing the selection.
+    ///
+    /// - Characters that aren't allowed by the constraints are left out.
+    /// - Text that doesn't fit within the max length is cut off.
+    pub fn insert(&mut self, text: &str, constraints: &TextConstraints) {
+        self.delete_selection();
+
+        let available_length = constraints.max_length.map_or(usize::MAX, |max_length| {
+            max_length.saturating_sub(self.length())
+        });
+
+        let inserted_text: String = text
+            .chars()
+            .filter(|character| constraints.allows(*character))
+            .take(available_length)
+            .collect();
+
+        let byte_index = self.byte_index(self.caret);
+        self.text.insert_str(byte_index, &inserted_text);
+        self.caret += inserted_text.chars().count();
+    }
+
+    /// Deletes the selection, or a character or word in the given direction if nothing is selected.
+    pub fn delete(&mut self, direction: DeleteDirection, whole_word: bool) {
+        if self.delete_selection() {
+            return;
+        }
+
+        let range = match (direction, whole_word) {
+            (DeleteDirection::Backward, false) => self.caret.saturating_sub(1)..self.caret,
+            (DeleteDirection::Backward, true) => self.previous_word_boundary()..self.caret,
+            (DeleteDirection::Forward, false) => self.caret..(self.caret + 1).min(self.length()),
+            (DeleteDirection::Forward, true) => self.caret..self.next_word_boundary(),
+        };
+
+        self.delete_range(range);
+    }
+
+    /// Removes the selected text and returns it, or None if nothing is selected.
+    pub fn cut(&mut self) -> Option<String> {
+        let selected_text = self.selected_text()?.to_string();
+
+        self.delete_selection();
+
+        Some(selected_text)
+    }
+
+    /// Deletes the selected text, returns false if nothing was selected.
+    fn delete_selection(&mut self) -> bool {
+        let Some(selection) = self.selection() else {
+            return false;
+        };
+
+        self.delete_range(selection);
+
+        true
+    }
+
+    fn delete_range(&mut self, range: Range<usize>) {
+        let byte_range = self.byte_index(range.start)..self.byte_index(range.end);
+
+        self.text.replace_range(byte_range, "");
+        self.caret = range.start;
+        self.selection_anchor = None;
+    }
+
+    fn previous_word_boundary(&self) -> usize {
+        let characters: Vec<char> = self.text.chars().collect();
+        let mut position = self.caret;
+
+        while position > 0 && !is_word_character(characters[position - 1]) {
+            position -= 1;
+        }
+
+        while position > 0 && is_word_character(characters[position - 1]) {
+            position -= 1;
+        }
+
+        position
+    }
+
+    fn next_word_boundary(&self) -> usize {
+        let characters: Vec<char> = self.text.chars().collect();
+        let mut position = self.caret;
+
+        while position < characters.len() && !is_word_character(characters[position]) {
+            position += 1;
+        }
+
+        while position < characters.len() && is_word_character(characters[position]) {
+            position += 1;
+        }
+
+        position
+    }
+
+    fn length(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte_index(&self, position: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(position)
+            .map_or(self.text.len(), |(byte_index, _)| byte_index)
+    }
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_at_caret() {
+        let mut editor = TextEditor::new("road");
+
+        editor.move_caret(CaretMovement::Start, false);
+        editor.insert("main ", &TextConstraints::default());
+
+        assert_eq!(editor.text(), "main road");
+        assert_eq!(editor.caret(), 5);
+    }
+
+    #[test]
+    fn insert_replaces_selection() {
+        let mut editor = TextEditor::new("main road");
+
+        editor.set_caret(0, false);
+        editor.set_caret(4, true);
+        editor.insert("side", &TextConstraints::default());
+
+        assert_eq!(editor.text(), "side road");
+        assert_eq!(editor.selection(), None);
+    }
+
+    #[test]
+    fn insert_respects_constraints() {
+        let constraints = TextConstraints::default()
+            .with_max_length(5)
+            .with_character_filter(|character| character.is_ascii_digit());
+        let mut editor = TextEditor::new("12");
+
+        editor.insert("3a4b5678", &constraints);
+
+        assert_eq!(editor.text(), "12345");
+    }
+
+    #[test]
+    fn handles_multi_byte_characters() {
+        let mut editor = TextEditor::new("straße");
+
+        editor.delete(DeleteDirection::Backward, false);
+        editor.move_caret(CaretMovement::Left, false);
+        editor.delete(DeleteDirection::Forward, false);
+
+        assert_eq!(editor.text(), "stra");
+    }
+
+    #[test]
+    fn moves_and_deletes_by_word() {
+        let mut editor = TextEditor::new("two lane road");
+
+        editor.move_caret(CaretMovement::WordLeft, false);
+        assert_eq!(editor.caret(), 9);
+
+        editor.delete(DeleteDirection::Backward, true);
+        assert_eq!(editor.text(), "two road");
+
+        editor.move_caret(CaretMovement::Start, false);
+        editor.delete(DeleteDirection::Forward, true);
+        assert_eq!(editor.text(), " road");
+    }
+
+    #[test]
+    fn moving_without_shift_collapses_selection() {
+        let mut editor = TextEditor::new("road");
+
+        editor.select_all();
+        editor.move_caret(CaretMovement::Left, false);
+
+        assert_eq!(editor.caret(), 0);
+        assert_eq!(editor.selection(), None);
+    }
+
+    #[test]
+    fn cut_removes_selected_text() {
+        let mut editor = TextEditor::new("main road");
+
+        editor.move_caret(CaretMovement::WordLeft, true);
+
+        assert_eq!(editor.cut(), Some(String::from("road")));
+        assert_eq!(editor.text(), "main ");
+        assert_eq!(editor.cut(), None);
+    }
+}
@@ -0,0 +1,307 @@
+use bevy::prelude::*;
+
+use crate::{
+    keybindings::{
+        control_is_pressed, InputAction, InputBinding, InputButton, KeybindingChange, Keybindings,
+        OnKeybindingChangeRequested, OnKeybindingChanged, OnKeybindingsResetRequested,
+    },
+    utility::find_descendant_of_entity_mut,
+    GameRunningSet,
+};
+
+use super::{
+    components::{
+        buttons::TextButtonBuilder,
+        flexbox::{FlexboxBuilder, FlexboxConfig},
+        text::{TextBuilder, TextConfig},
+        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    },
+    modal::OnShowModalRequested,
+    theme::{ThemeColor, ThemeFontSize},
+};
+
+const DESCRIPTION_COLUMN_WIDTH: f32 = 340.0;
+const CAPTURING_TEXT: &str = "Press a key...";
+
+/// Keys that are only used together with another key, they can't be bound on their own.
+const MODIFIER_KEYS: [KeyCode; 8] = [
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+    KeyCode::SuperLeft,
+    KeyCode::SuperRight,
+];
+
+pub struct KeybindingsMenuPlugin;
+
+impl Plugin for KeybindingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BindingCapture>()
+            .add_event::<OnShowKeybindingsMenuRequested>()
+            .add_systems(
+                Update,
+                (
+                    (
+                        handle_keybindings_menu_buttons.run_if(not(binding_capture_is_active)),
+                        capture_binding_on_input.run_if(binding_capture_is_active),
+                    )
+                        .chain()
+                        .in_set(GameRunningSet::GetUserInput),
+                    handle_show_requests.in_set(GameRunningSet::HandleCommands),
+                    (update_binding_button_texts, update_conflict_text)
+                        .in_set(GameRunningSet::UpdateEntities),
+                ),
+            );
+    }
+}
+
+/// Opens the menu where the button of every [InputAction] can be changed, on top of the open modals.
+#[derive(Event)]
+pub struct OnShowKeybindingsMenuRequested;
+
+/// The action that will be bound to the next key or mouse button that is pressed.
+#[derive(Resource, Default, Debug)]
+pub struct BindingCapture {
+    action: Option<InputAction>,
+}
+
+/// Run condition for systems that react to keys the user might want to bind instead.
+pub fn binding_capture_is_active(capture: Res<BindingCapture>) -> bool {
+    capture.action.is_some()
+}
+
+#[derive(Component, Clone, Copy)]
+struct KeybindingButton {
+    action: InputAction,
+}
+
+#[derive(Component)]
+struct ResetKeybindingsButton;
+
+/// Explains what happened when a binding was already used by another action.
+#[derive(Component)]
+struct KeybindingConflictText;
+
+fn handle_show_requests(
+    mut requests: EventReader<OnShowKeybindingsMenuRequested>,
+    mut on_show_modal: EventWriter<OnShowModalRequested>,
+    mut commands: Commands,
+    keybindings: Res<Keybindings>,
+) {
+    for _ in requests.read() {
+        let menu_entity = spawn_keybindings_menu(&mut commands, &keybindings);
+
+        on_show_modal.send(OnShowModalRequested::new(menu_entity));
+    }
+}
+
+fn handle_keybindings_menu_buttons(
+    mut capture: ResMut<BindingCapture>,
+    mut on_reset: EventWriter<OnKeybindingsResetRequested>,
+    binding_button_query: Query<(&KeybindingButton, Ref<Interaction>)>,
+    reset_button_query: Query<Ref<Interaction>, With<ResetKeybindingsButton>>,
+) {
+    for (binding_button, _) in binding_button_query.iter().filter(|(_, interaction)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        capture.action = Some(binding_button.action);
+    }
+
+    if reset_button_query
+        .iter()
+        .any(|interaction| interaction.is_changed() && *interaction == Interaction::Pressed)
+    {
+        on_reset.send(OnKeybindingsResetRequested);
+    }
+}
+
+fn capture_binding_on_input(
+    mut capture: ResMut<BindingCapture>,
+    mut on_change: EventWriter<OnKeybindingChangeRequested>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mouse_input: Res<ButtonInput<MouseButton>>,
+    binding_button_query: Query<(), With<KeybindingButton>>,
+) {
+    // The click on the binding button that started capturing isn't captured itself
+    if capture.is_changed() {
+        return;
+    }
+
+    let Some(action) = capture.action else {
+        return;
+    };
+
+    if binding_button_query.is_empty() || keyboard_input.just_pressed(KeyCode::Escape) {
+        capture.action = None;
+        return;
+    }
+
+    let Some(button) = first_bindable_button(&keyboard_input, &mouse_input) else {
+        return;
+    };
+
+    if !action.accepts(button) {
+        return;
+    }
+
+    let binding = match button {
+        InputButton::Key(_) if control_is_pressed(&keyboard_input) => {
+            InputBinding::new(button).with_control()
+        }
+        _ => InputBinding::new(button),
+    };
+
+    on_change.send(OnKeybindingChangeRequested::new(action, binding));
+    capture.action = None;
+}
+
+fn update_binding_button_texts(
+    keybindings: Res<Keybindings>,
+    capture: Res<BindingCapture>,
+    binding_button_query: Query<(Entity, &KeybindingButton)>,
+    mut text_query: Query<(Entity, &mut Text)>,
+    children_query: Query<&Children>,
+) {
+    if !keybindings.is_changed() && !capture.is_changed() {
+        return;
+    }
+
+    for (button_entity, binding_button) in binding_button_query.iter() {
+        if let Some((_, mut button_text)) = find_descendant_of_entity_mut(
+            button_entity,
+            &mut text_query,
+            |item| item.0,
+            &children_query,
+        ) {
+            button_text.sections[0].value =
+                binding_button_text(binding_button.action, &keybindings, &capture);
+        }
+    }
+}
+
+fn update_conflict_text(
+    mut on_changed: EventReader<OnKeybindingChanged>,
+    mut conflict_text_query: Query<&mut Text, With<KeybindingConflictText>>,
+    keybindings: Res<Keybindings>,
+) {
+    for event in on_changed.read() {
+        let binding_label = event.binding().label();
+
+        let message = match event.change() {
+            KeybindingChange::Changed => String::new(),
+            KeybindingChange::Swapped(other_action) => format!(
+                "{binding_label} was used to \"{}\", that now uses {}",
+                other_action.description(),
+                keybindings.label(other_action)
+            ),
+            KeybindingChange::Rejected(other_action) => format!(
+                "{binding_label} is already used to \"{}\", {} is kept for \"{}\"",
+                other_action.description(),
+                keybindings.label(event.action()),
+                event.action().description()
+            ),
+        };
+
+        for mut conflict_text in conflict_text_query.iter_mut() {
+            conflict_text.sections[0].value.clone_from(&message);
+        }
+    }
+}
+
+fn first_bindable_button(
+    keyboard_input: &ButtonInput<KeyCode>,
+    mouse_input: &ButtonInput<MouseButton>,
+) -> Option<InputButton> {
+    keyboard_input
+        .get_just_pressed()
+        .find(|key_code| !MODIFIER_KEYS.contains(key_code))
+        .map(|key_code| InputButton::Key(*key_code))
+        .or_else(|| {
+            mouse_input
+                .get_just_pressed()
+                .next()
+                .map(|mouse_button| InputButton::Mouse(*mouse_button))
+        })
+}
+
+fn binding_button_text(
+    action: InputAction,
+    keybindings: &Keybindings,
+    capture: &BindingCapture,
+) -> String {
+    match capture.action == Some(action) {
+        true => CAPTURING_TEXT.to_string(),
+        false => keybindings.label(action),
+    }
+}
+
+fn spawn_keybindings_menu(commands: &mut Commands, keybindings: &Keybindings) -> Entity {
+    let flexbox_node =
+        FlexboxBuilder::new(FlexboxConfig::horizontally_centered_column().with_px_gap(16.0))
+            .build();
+
+    commands
+        .spawn(flexbox_node)
+        .with_children(|container| {
+            TextBuilder::default()
+                .with_text("Controls")
+                .spawn(container, ());
+
+            TextBuilder::new(TextConfig {
+                text: "Click a binding and press the new key or mouse button, Esc cancels"
+                    .to_string(),
+                color: ThemeColor::MutedText.into(),
+                font_size: ThemeFontSize::Small.into(),
+                ..TextConfig::centered()
+            })
+            .spawn(container, ());
+
+            TextBuilder::new(TextConfig {
+                color: ThemeColor::MutedText.into(),
+                font_size: ThemeFontSize::Small.into(),
+                ..TextConfig::centered()
+            })
+            .spawn(container, KeybindingConflictText);
+
+            FlexboxBuilder::new(FlexboxConfig::column().with_px_gap(4.0)).spawn(
+                container,
+                (),
+                |rows| {
+                    for action in InputAction::ALL {
+                        spawn_keybinding_row(rows, action, keybindings);
+                    }
+                },
+            );
+
+            TextButtonBuilder::default_with_text("Reset to defaults")
+                .spawn(container, ResetKeybindingsButton);
+        })
+        .id()
+}
+
+fn spawn_keybinding_row(
+    builder: &mut ChildBuilder,
+    action: InputAction,
+    keybindings: &Keybindings,
+) {
+    FlexboxBuilder::new(FlexboxConfig::vertically_centered_row()).spawn(builder, (), |row| {
+        row.spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(DESCRIPTION_COLUMN_WIDTH),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|description_column| {
+            TextBuilder::default()
+                .with_text(action.description())
+                .spawn(description_column, ());
+        });
+
+        TextButtonBuilder::default_with_text(keybindings.label(action))
+            .spawn(row, KeybindingButton { action });
+    });
+}
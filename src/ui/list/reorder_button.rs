@@ -62,7 +62,7 @@ impl ReorderButton {
     }
 }
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ReorderDirection {
     Next,
     Previous,
@@ -1,5 +1,6 @@
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::prelude::*;
 
+use crate::ui::theme::{ThemeColor, Themed};
 
 use super::{ReorderButton, ReorderDirection};
 
@@ -15,24 +16,26 @@ pub fn spawn_reorder_button(
         });
 }
 
-fn build_button_text_node(button_direction: ReorderDirection, size: f32) -> TextBundle {
+fn build_button_text_node(button_direction: ReorderDirection, size: f32) -> impl Bundle {
     let button_text = get_reorder_button_text(button_direction);
 
-    TextBundle {
-        text: Text {
-            sections: vec![TextSection {
-                value: button_text,
-                style: TextStyle {
-                    color: NEUTRAL_300.into(),
-                    font_size: size - 1.0,
-                    ..default()
-                },
-            }],
-            justify: JustifyText::Center,
+    (
+        TextBundle {
+            text: Text {
+                sections: vec![TextSection {
+                    value: button_text,
+                    style: TextStyle {
+                        font_size: size - 1.0,
+                        ..default()
+                    },
+                }],
+                justify: JustifyText::Center,
+                ..default()
+            },
             ..default()
         },
-        ..default()
-    }
+        Themed::default().with_text_color(ThemeColor::MutedText.into()),
+    )
 }
 
 fn build_button_node(
@@ -52,9 +55,9 @@ fn build_button_node(
                 border: UiRect::all(Val::Px(2.0)),
                 ..default()
             },
-            border_color: BorderColor(NEUTRAL_300.into()),
             ..default()
         },
+        Themed::default().with_border_color(ThemeColor::MutedText.into()),
     )
 }
 
@@ -1,14 +1,25 @@
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::prelude::*;
 
-use crate::{game_modes::GameMode, utility::despawn_component_recursive, GameRunningSet};
+use crate::{
+    game_modes::GameMode,
+    road::recovery::{recovery_snapshot::clear_recovery_snapshots, PendingRecovery, RecoveryOffer},
+    utility::despawn_component_recursive,
+    GameRunningSet,
+};
 
-use super::components::{
-    buttons::TextButtonBuilder,
-    content_size::ContentSizeConfig,
-    content_wrap::ContentWrapConfig,
-    flexbox::FlexboxConfig,
-    section::{SectionBuilder, SectionConfig},
-    UiComponentBuilder, UiComponentWithChildrenBuilder,
+use super::{
+    components::{
+        buttons::TextButtonBuilder,
+        content_size::ContentSizeConfig,
+        content_wrap::ContentWrapConfig,
+        flexbox::FlexboxConfig,
+        section::{SectionBuilder, SectionConfig},
+        text::TextBuilder,
+        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    },
+    keybindings_menu::OnShowKeybindingsMenuRequested,
+    settings_menu::OnShowSettingsMenuRequested,
+    theme::ThemeColor,
 };
 
 pub struct MainMenuPlugin;
@@ -22,7 +33,7 @@ impl Plugin for MainMenuPlugin {
             )
             .add_systems(
                 Update,
-                handle_main_menu_actions
+                (handle_main_menu_actions, handle_recovery_prompt_actions)
                     .in_set(GameRunningSet::GetUserInput)
                     .run_if(in_state(GameMode::MainMenu)),
             );
@@ -33,24 +44,36 @@ impl Plugin for MainMenuPlugin {
 #[derive(Component)]
 pub struct MainMenu;
 
+/// Marker component for the section that offers to restore work from a previous run.
+#[derive(Component)]
+struct RecoveryPrompt;
+
 #[derive(Component)]
 pub enum MainMenuAction {
     LoadEditorMode,
     LoadDrawerMode,
+    RestoreRecovery,
+    DiscardRecovery,
+    ShowKeybindings,
+    ShowSettings,
     ExitGame,
 }
 
-fn spawn_main_menu(mut commands: Commands) {
+fn spawn_main_menu(mut commands: Commands, recovery_offer: Option<Res<RecoveryOffer>>) {
     let section_node = SectionBuilder::new(SectionConfig {
         flexbox: FlexboxConfig::centered(),
         size: ContentSizeConfig::full(),
-        wrap: ContentWrapConfig::empty().with_background_color(TEAL_700),
+        wrap: ContentWrapConfig::empty().with_background_color(ThemeColor::Background),
     })
     .build();
 
     commands
         .spawn((section_node, MainMenu))
         .with_children(|container| {
+            if let Some(recovery_offer) = recovery_offer {
+                spawn_recovery_prompt(container, &recovery_offer);
+            }
+
             SectionBuilder::default().spawn(container, (), |section| {
                 TextButtonBuilder::default_with_text("Load editor")
                     .spawn(section, MainMenuAction::LoadEditorMode);
@@ -58,16 +81,44 @@ fn spawn_main_menu(mut commands: Commands) {
                 TextButtonBuilder::default_with_text("Load drawer")
                     .spawn(section, MainMenuAction::LoadDrawerMode);
 
+                TextButtonBuilder::default_with_text("Controls")
+                    .spawn(section, MainMenuAction::ShowKeybindings);
+
+                TextButtonBuilder::default_with_text("Settings")
+                    .spawn(section, MainMenuAction::ShowSettings);
+
                 TextButtonBuilder::default_with_text("Exit to desktop")
                     .spawn(section, MainMenuAction::ExitGame);
             });
         });
 }
 
+fn spawn_recovery_prompt(builder: &mut ChildBuilder, recovery_offer: &RecoveryOffer) {
+    let snapshot = recovery_offer.snapshot();
+
+    SectionBuilder::default().spawn(builder, RecoveryPrompt, |section| {
+        TextBuilder::default()
+            .with_text(format!(
+                "Found an unsaved {} from {}",
+                snapshot.content().description(),
+                snapshot.age_description()
+            ))
+            .spawn(section, ());
+
+        TextButtonBuilder::default_with_text("Restore")
+            .spawn(section, MainMenuAction::RestoreRecovery);
+
+        TextButtonBuilder::default_with_text("Discard")
+            .spawn(section, MainMenuAction::DiscardRecovery);
+    });
+}
+
 fn handle_main_menu_actions(
     button_query: Query<(&Interaction, &MainMenuAction), Changed<Interaction>>,
     mut next_game_mode: ResMut<NextState<GameMode>>,
     mut on_exit: EventWriter<AppExit>,
+    mut on_show_keybindings: EventWriter<OnShowKeybindingsMenuRequested>,
+    mut on_show_settings: EventWriter<OnShowSettingsMenuRequested>,
 ) {
     for (_, action) in button_query
         .iter()
@@ -80,9 +131,56 @@ fn handle_main_menu_actions(
             MainMenuAction::LoadDrawerMode => {
                 next_game_mode.set(GameMode::RoadDrawer);
             }
+            MainMenuAction::ShowKeybindings => {
+                on_show_keybindings.send(OnShowKeybindingsMenuRequested);
+            }
+            MainMenuAction::ShowSettings => {
+                on_show_settings.send(OnShowSettingsMenuRequested);
+            }
             MainMenuAction::ExitGame => {
                 on_exit.send(AppExit::Success);
             }
+            // Handled together with the rest of the recovery prompt
+            MainMenuAction::RestoreRecovery | MainMenuAction::DiscardRecovery => (),
+        }
+    }
+}
+
+fn handle_recovery_prompt_actions(
+    button_query: Query<(&Interaction, &MainMenuAction), Changed<Interaction>>,
+    recovery_prompt_query: Query<Entity, With<RecoveryPrompt>>,
+    mut next_game_mode: ResMut<NextState<GameMode>>,
+    mut commands: Commands,
+    recovery_offer: Option<Res<RecoveryOffer>>,
+) {
+    for (_, action) in button_query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        match action {
+            MainMenuAction::RestoreRecovery => {
+                let Some(recovery_offer) = &recovery_offer else {
+                    continue;
+                };
+
+                next_game_mode.set(recovery_offer.game_mode());
+                commands.insert_resource(PendingRecovery::new(
+                    recovery_offer.snapshot().content().clone(),
+                ));
+                commands.remove_resource::<RecoveryOffer>();
+            }
+            MainMenuAction::DiscardRecovery => {
+                if let Err(error) = clear_recovery_snapshots() {
+                    warn!("Failed to remove recovery snapshots: {error}");
+                }
+
+                commands.remove_resource::<RecoveryOffer>();
+
+                for prompt_entity in recovery_prompt_query.iter() {
+                    commands.entity(prompt_entity).despawn_recursive();
+                }
+            }
+            _ => (),
         }
     }
 }
@@ -1,20 +1,44 @@
-use bevy::{prelude::*, ui::RelativeCursorPosition};
+pub mod confirm_dialog;
+
+use bevy::{
+    prelude::*,
+    ui::{FocusPolicy, RelativeCursorPosition},
+};
+use confirm_dialog::ConfirmDialogPlugin;
 
 use crate::GameRunningSet;
 
-use super::components::{section::SectionBuilder, UiComponentWithChildrenBuilder};
+use super::{
+    components::{
+        inputs::{color_input::eyedropper::eyedropper_is_active, dropdown::dropdown_is_open},
+        section::SectionBuilder,
+        UiComponentWithChildrenBuilder,
+    },
+    keybindings_menu::binding_capture_is_active,
+};
+
+/// Modals are drawn above the rest of the UI, but below dropdown lists which use a z-index of 10.
+const MODAL_BASE_Z_INDEX: i32 = 1;
 
 pub struct ModalPlugin;
 
 impl Plugin for ModalPlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<OnShowModalRequested>()
+        app.add_plugins(ConfirmDialogPlugin)
+            .init_resource::<ModalStack>()
+            .add_event::<OnShowModalRequested>()
             .add_event::<OnHideModalRequested>()
-            .add_systems(Startup, spawn_centered_modal)
             .add_systems(
                 Update,
                 (
-                    (hide_modal_when_clicking_outside).in_set(GameRunningSet::GetUserInput),
+                    // Escape and clicks cancel picking a color, close a dropdown or are bound to an action instead
+                    (hide_top_modal_when_clicking_outside, hide_top_modal_on_esc)
+                        .in_set(GameRunningSet::GetUserInput)
+                        .run_if(
+                            not(eyedropper_is_active)
+                                .and_then(not(dropdown_is_open))
+                                .and_then(not(binding_capture_is_active)),
+                        ),
                     (handle_hide_requests, handle_show_requests)
                         .chain()
                         .in_set(GameRunningSet::DespawnEntities),
@@ -26,6 +50,43 @@ impl Plugin for ModalPlugin {
 #[derive(Component)]
 pub struct Modal;
 
+/// The open modals, the last one is shown on top and is the only one that reacts to closing input.
+#[derive(Resource, Default, Debug)]
+pub struct ModalStack {
+    layers: Vec<ModalLayer>,
+}
+
+impl ModalStack {
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    fn top(&self) -> Option<&ModalLayer> {
+        self.layers.last()
+    }
+
+    fn push(&mut self, layer: ModalLayer) {
+        self.layers.push(layer);
+    }
+
+    fn pop(&mut self) -> Option<ModalLayer> {
+        self.layers.pop()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ModalLayer {
+    /// The full screen backdrop that centers the modal, despawning it closes the modal.
+    container_entity: Entity,
+    modal_entity: Entity,
+}
+
+/// Run condition for systems that shouldn't run while a modal is open.
+pub fn modal_is_open(modal_stack: Res<ModalStack>) -> bool {
+    !modal_stack.is_empty()
+}
+
+/// Opens a new modal on top of the open ones, with the given entity as its content.
 #[derive(Event)]
 pub struct OnShowModalRequested {
     child_entity: Entity,
@@ -37,77 +98,129 @@ impl OnShowModalRequested {
     }
 }
 
+/// Closes the top modal, the modals below it stay open.
 #[derive(Event)]
 pub struct OnHideModalRequested;
 
-fn spawn_centered_modal(mut commands: Commands, mut on_hide: EventWriter<OnHideModalRequested>) {
-    commands
-        .spawn(build_centered_container_node())
-        .with_children(|container| {
-            SectionBuilder::spawn_default(
-                container,
-                (Modal, RelativeCursorPosition::default()),
-                |_| {},
-            );
-        });
-
-    on_hide.send(OnHideModalRequested);
-}
-
 fn handle_show_requests(
     mut requests: EventReader<OnShowModalRequested>,
     mut commands: Commands,
-    mut modal_query: Query<(Entity, &mut Visibility), With<Modal>>,
+    mut modal_stack: ResMut<ModalStack>,
 ) {
     for request in requests.read() {
-        let (modal_entity, mut modal_visibility) = modal_query.single_mut();
-
-        *modal_visibility = Visibility::Inherited;
+        let z_index = MODAL_BASE_Z_INDEX + modal_stack.layers.len() as i32;
+        let mut modal_entity = Entity::PLACEHOLDER;
+
+        let container_entity = commands
+            .spawn(build_centered_container_node(z_index))
+            .with_children(|container| {
+                modal_entity = SectionBuilder::spawn_default(
+                    container,
+                    (Modal, RelativeCursorPosition::default()),
+                    |_| {},
+                );
+            })
+            .id();
 
         commands
             .entity(modal_entity)
-            .despawn_descendants()
             .add_child(request.child_entity);
+
+        modal_stack.push(ModalLayer {
+            container_entity,
+            modal_entity,
+        });
     }
 }
 
 fn handle_hide_requests(
     mut requests: EventReader<OnHideModalRequested>,
     mut commands: Commands,
-    mut modal_query: Query<(Entity, &mut Visibility), With<Modal>>,
+    mut modal_stack: ResMut<ModalStack>,
 ) {
     for _ in requests.read() {
-        let (modal_entity, mut modal_visibility) = modal_query.single_mut();
+        let Some(layer) = modal_stack.pop() else {
+            continue;
+        };
 
-        *modal_visibility = Visibility::Hidden;
-
-        commands.entity(modal_entity).despawn_descendants();
+        if let Some(container) = commands.get_entity(layer.container_entity) {
+            container.despawn_recursive();
+        }
     }
 }
 
-fn hide_modal_when_clicking_outside(
+fn hide_top_modal_when_clicking_outside(
     mut on_request: EventWriter<OnHideModalRequested>,
     mouse_input: Res<ButtonInput<MouseButton>>,
+    modal_stack: Res<ModalStack>,
     modal_query: Query<&RelativeCursorPosition, With<Modal>>,
 ) {
-    if mouse_input.just_pressed(MouseButton::Left) {
-        let cursor_position = modal_query.single();
+    if !mouse_input.just_pressed(MouseButton::Left) {
+        return;
+    }
 
-        if !cursor_position.mouse_over() {
-            on_request.send(OnHideModalRequested);
-        }
+    let Some(top_layer) = modal_stack.top() else {
+        return;
+    };
+
+    let Ok(cursor_position) = modal_query.get(top_layer.modal_entity) else {
+        return;
+    };
+
+    if !cursor_position.mouse_over() {
+        on_request.send(OnHideModalRequested);
     }
 }
 
-fn build_centered_container_node() -> impl Bundle {
+fn hide_top_modal_on_esc(
+    mut on_request: EventWriter<OnHideModalRequested>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    modal_stack: Res<ModalStack>,
+) {
+    if keyboard_input.just_pressed(KeyCode::Escape) && !modal_stack.is_empty() {
+        on_request.send(OnHideModalRequested);
+    }
+}
+
+fn build_centered_container_node(z_index: i32) -> impl Bundle {
     NodeBundle {
         style: Style {
+            position_type: PositionType::Absolute,
             width: Val::Percent(100.0),
             height: Val::Percent(100.0),
             justify_content: JustifyContent::Center,
             align_items: AlignItems::Center,
             ..default()
         },
+        // Dims and blocks the UI below, so only the top modal can be interacted with
+        background_color: Color::BLACK.with_alpha(0.3).into(),
+        focus_policy: FocusPolicy::Block,
+        z_index: ZIndex::Global(z_index),
         ..default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(index: u32) -> ModalLayer {
+        ModalLayer {
+            container_entity: Entity::from_raw(index * 2),
+            modal_entity: Entity::from_raw(index * 2 + 1),
+        }
+    }
+
+    #[test]
+    fn stack_closes_the_last_opened_modal_first() {
+        let mut modal_stack = ModalStack::default();
+        modal_stack.push(layer(1));
+        modal_stack.push(layer(2));
+
+        assert_eq!(modal_stack.top(), Some(&layer(2)));
+        assert_eq!(modal_stack.pop(), Some(layer(2)));
+        assert_eq!(modal_stack.top(), Some(&layer(1)));
+        assert_eq!(modal_stack.pop(), Some(layer(1)));
+        assert!(modal_stack.is_empty());
+    }
+}
@@ -0,0 +1,307 @@
+use bevy::prelude::*;
+
+use crate::{
+    road_editor::exit_protection::LeaveEditorDestination,
+    ui::{
+        components::{
+            buttons::TextButtonBuilder,
+            flexbox::{FlexboxBuilder, FlexboxConfig},
+            text::{TextBuilder, TextConfig},
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        theme::{ThemeColor, ThemeFontSize},
+    },
+    utility::find_ancestor_of_entity,
+    GameRunningSet,
+};
+
+use super::{OnHideModalRequested, OnShowModalRequested};
+
+pub struct ConfirmDialogPlugin;
+
+impl Plugin for ConfirmDialogPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<OpenConfirmDialogs>()
+            .add_event::<OnConfirmDialogRequested>()
+            .add_event::<OnConfirmDialogClosed>()
+            .add_systems(
+                Update,
+                (
+                    send_choice_on_button_press.in_set(GameRunningSet::GetUserInput),
+                    cancel_dialogs_closed_without_choice.in_set(GameRunningSet::SendEvents),
+                    handle_confirm_dialog_requests.in_set(GameRunningSet::HandleCommands),
+                ),
+            );
+    }
+}
+
+/// What the user is asked to confirm, so the requester can recognize the answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfirmDialogPurpose {
+    DeleteRoadComponent,
+    DeleteComponentGroup,
+    OverwriteRoad,
+    LeaveEditor(LeaveEditorDestination),
+}
+
+/// The button the user chose to close a confirm dialog with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConfirmDialogChoice {
+    Confirm,
+    /// The optional middle button, like "Discard" next to "Save".
+    Alternative,
+    /// Also chosen when the dialog is closed with Esc or by clicking outside of it.
+    Cancel,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConfirmDialogConfig {
+    purpose: ConfirmDialogPurpose,
+    title: String,
+    message: String,
+    confirm_label: String,
+    alternative_label: Option<String>,
+    cancel_label: String,
+}
+
+impl ConfirmDialogConfig {
+    pub fn new(
+        purpose: ConfirmDialogPurpose,
+        title: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            purpose,
+            title: title.into(),
+            message: message.into(),
+            confirm_label: "Confirm".to_string(),
+            alternative_label: None,
+            cancel_label: "Cancel".to_string(),
+        }
+    }
+
+    pub fn with_confirm_label(mut self, label: impl Into<String>) -> Self {
+        self.confirm_label = label.into();
+        self
+    }
+
+    pub fn with_alternative_label(mut self, label: impl Into<String>) -> Self {
+        self.alternative_label = Some(label.into());
+        self
+    }
+
+    #[expect(unused)]
+    pub fn with_cancel_label(mut self, label: impl Into<String>) -> Self {
+        self.cancel_label = label.into();
+        self
+    }
+
+    /// The buttons of the dialog from left to right.
+    fn buttons(&self) -> Vec<(&str, ConfirmDialogChoice)> {
+        let mut buttons = vec![(self.confirm_label.as_str(), ConfirmDialogChoice::Confirm)];
+
+        if let Some(alternative_label) = &self.alternative_label {
+            buttons.push((alternative_label.as_str(), ConfirmDialogChoice::Alternative));
+        }
+
+        buttons.push((self.cancel_label.as_str(), ConfirmDialogChoice::Cancel));
+        buttons
+    }
+}
+
+/// Opens a confirm dialog on top of the open modals, the answer is sent as an [OnConfirmDialogClosed] event.
+#[derive(Event)]
+pub struct OnConfirmDialogRequested {
+    config: ConfirmDialogConfig,
+}
+
+impl OnConfirmDialogRequested {
+    pub fn new(config: ConfirmDialogConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[derive(Event, Debug)]
+pub struct OnConfirmDialogClosed {
+    purpose: ConfirmDialogPurpose,
+    choice: ConfirmDialogChoice,
+}
+
+impl OnConfirmDialogClosed {
+    pub fn new(purpose: ConfirmDialogPurpose, choice: ConfirmDialogChoice) -> Self {
+        Self { purpose, choice }
+    }
+
+    pub fn purpose(&self) -> ConfirmDialogPurpose {
+        self.purpose
+    }
+
+    pub fn choice(&self) -> ConfirmDialogChoice {
+        self.choice
+    }
+
+    /// Returns true if the user confirmed the dialog with the given purpose.
+    pub fn is_confirmed(&self, purpose: ConfirmDialogPurpose) -> bool {
+        self.purpose == purpose && self.choice == ConfirmDialogChoice::Confirm
+    }
+}
+
+/// The dialogs that haven't been answered yet, to notice the ones closed without choosing a button.
+#[derive(Resource, Default)]
+struct OpenConfirmDialogs {
+    dialogs: Vec<(Entity, ConfirmDialogPurpose)>,
+}
+
+impl OpenConfirmDialogs {
+    fn take(&mut self, dialog_entity: Entity) -> Option<ConfirmDialogPurpose> {
+        let index = self
+            .dialogs
+            .iter()
+            .position(|(entity, _)| *entity == dialog_entity)?;
+
+        Some(self.dialogs.remove(index).1)
+    }
+}
+
+/// Marker component for the content of a confirm dialog.
+#[derive(Component)]
+struct ConfirmDialog;
+
+#[derive(Component, Clone, Copy)]
+struct ConfirmDialogButton {
+    choice: ConfirmDialogChoice,
+}
+
+fn handle_confirm_dialog_requests(
+    mut requests: EventReader<OnConfirmDialogRequested>,
+    mut on_show_modal: EventWriter<OnShowModalRequested>,
+    mut open_dialogs: ResMut<OpenConfirmDialogs>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        let dialog_entity = spawn_confirm_dialog(&mut commands, &request.config);
+
+        open_dialogs
+            .dialogs
+            .push((dialog_entity, request.config.purpose));
+
+        on_show_modal.send(OnShowModalRequested::new(dialog_entity));
+    }
+}
+
+fn send_choice_on_button_press(
+    mut on_closed: EventWriter<OnConfirmDialogClosed>,
+    mut on_hide_modal: EventWriter<OnHideModalRequested>,
+    mut open_dialogs: ResMut<OpenConfirmDialogs>,
+    button_query: Query<(Entity, &ConfirmDialogButton, Ref<Interaction>)>,
+    dialog_query: Query<Entity, With<ConfirmDialog>>,
+    parent_query: Query<&Parent>,
+) {
+    for (button_entity, button, _) in button_query.iter().filter(|(_, _, interaction)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        let Some(dialog_entity) =
+            find_ancestor_of_entity(button_entity, &dialog_query, |item| *item, &parent_query)
+        else {
+            continue;
+        };
+
+        let Some(purpose) = open_dialogs.take(dialog_entity) else {
+            continue;
+        };
+
+        on_closed.send(OnConfirmDialogClosed::new(purpose, button.choice));
+
+        on_hide_modal.send(OnHideModalRequested);
+    }
+}
+
+/// Dialogs closed with Esc or by clicking outside of them count as cancelled.
+fn cancel_dialogs_closed_without_choice(
+    mut on_closed: EventWriter<OnConfirmDialogClosed>,
+    mut open_dialogs: ResMut<OpenConfirmDialogs>,
+    dialog_query: Query<(), With<ConfirmDialog>>,
+) {
+    open_dialogs.dialogs.retain(|(dialog_entity, purpose)| {
+        if dialog_query.contains(*dialog_entity) {
+            return true;
+        }
+
+        on_closed.send(OnConfirmDialogClosed::new(
+            *purpose,
+            ConfirmDialogChoice::Cancel,
+        ));
+
+        false
+    });
+}
+
+fn spawn_confirm_dialog(commands: &mut Commands, config: &ConfirmDialogConfig) -> Entity {
+    let flexbox_node =
+        FlexboxBuilder::new(FlexboxConfig::horizontally_centered_column().with_px_gap(16.0))
+            .build();
+
+    commands
+        .spawn((flexbox_node, ConfirmDialog))
+        .with_children(|container| {
+            TextBuilder::default()
+                .with_text(config.title.clone())
+                .spawn(container, ());
+
+            TextBuilder::new(TextConfig {
+                text: config.message.clone(),
+                color: ThemeColor::MutedText.into(),
+                font_size: ThemeFontSize::Small.into(),
+                ..TextConfig::centered()
+            })
+            .spawn(container, ());
+
+            FlexboxBuilder::new(FlexboxConfig::row().with_px_gap(8.0)).spawn(
+                container,
+                (),
+                |buttons| {
+                    for (label, choice) in config.buttons() {
+                        TextButtonBuilder::default_with_text(label)
+                            .spawn(buttons, ConfirmDialogButton { choice });
+                    }
+                },
+            );
+        })
+        .id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alternative_button_is_placed_between_confirm_and_cancel() {
+        let config = ConfirmDialogConfig::new(ConfirmDialogPurpose::OverwriteRoad, "Title", "")
+            .with_confirm_label("Save")
+            .with_alternative_label("Discard");
+
+        assert_eq!(
+            config.buttons(),
+            vec![
+                ("Save", ConfirmDialogChoice::Confirm),
+                ("Discard", ConfirmDialogChoice::Alternative),
+                ("Cancel", ConfirmDialogChoice::Cancel),
+            ]
+        );
+    }
+
+    #[test]
+    fn answered_dialogs_are_no_longer_open() {
+        let dialog_entity = Entity::from_raw(1);
+        let mut open_dialogs = OpenConfirmDialogs::default();
+        open_dialogs
+            .dialogs
+            .push((dialog_entity, ConfirmDialogPurpose::DeleteRoadComponent));
+
+        assert_eq!(
+            open_dialogs.take(dialog_entity),
+            Some(ConfirmDialogPurpose::DeleteRoadComponent)
+        );
+        assert_eq!(open_dialogs.take(dialog_entity), None);
+    }
+}
@@ -0,0 +1,254 @@
+use bevy::{prelude::*, ui::FocusPolicy};
+
+use crate::GameRunningSet;
+
+use super::{
+    components::{
+        text::{TextBuilder, TextConfig},
+        UiComponentBuilder,
+    },
+    theme::{ThemeColor, ThemeFontSize, Themed},
+};
+
+/// Older toasts are dismissed early when more than this amount are shown at once.
+const MAX_VISIBLE_TOASTS: usize = 5;
+const TOAST_WIDTH: f32 = 360.0;
+const TOAST_ACCENT_WIDTH: f32 = 4.0;
+
+pub struct NotificationsPlugin;
+
+impl Plugin for NotificationsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnNotificationRequested>()
+            .add_systems(Startup, spawn_toast_container)
+            .add_systems(
+                Update,
+                (
+                    dismiss_toast_on_press.in_set(GameRunningSet::GetUserInput),
+                    show_requested_notifications.in_set(GameRunningSet::UpdateEntities),
+                    (dismiss_expired_toasts, dismiss_overflowing_toasts)
+                        .chain()
+                        .in_set(GameRunningSet::DespawnEntities),
+                ),
+            );
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NotificationLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl NotificationLevel {
+    /// How long a toast of this level stays visible, problems get more time to be read.
+    fn duration_seconds(&self) -> f32 {
+        match self {
+            NotificationLevel::Info | NotificationLevel::Success => 3.0,
+            NotificationLevel::Warning => 5.0,
+            NotificationLevel::Error => 8.0,
+        }
+    }
+
+    /// The color of the stripe at the side of the toast.
+    fn accent_color(&self) -> ThemeColor {
+        match self {
+            NotificationLevel::Info => ThemeColor::Accent,
+            NotificationLevel::Success => ThemeColor::Success,
+            NotificationLevel::Warning => ThemeColor::Warning,
+            NotificationLevel::Error => ThemeColor::Danger,
+        }
+    }
+}
+
+/// Shows a short message as a toast that dismisses itself, can be sent by any plugin.
+#[derive(Event, Clone, Debug)]
+pub struct OnNotificationRequested {
+    level: NotificationLevel,
+    message: String,
+}
+
+impl OnNotificationRequested {
+    pub fn new(level: NotificationLevel, message: impl Into<String>) -> Self {
+        Self {
+            level,
+            message: message.into(),
+        }
+    }
+
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Info, message)
+    }
+
+    pub fn success(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Success, message)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Warning, message)
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(NotificationLevel::Error, message)
+    }
+}
+
+/// The column in which toasts are stacked, newest at the bottom.
+#[derive(Component)]
+struct ToastContainer;
+
+#[derive(Component)]
+struct Toast {
+    timer: Timer,
+}
+
+impl Toast {
+    fn new(level: NotificationLevel) -> Self {
+        Self {
+            timer: Timer::from_seconds(level.duration_seconds(), TimerMode::Once),
+        }
+    }
+}
+
+fn spawn_toast_container(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(20.0),
+                width: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            z_index: ZIndex::Global(90),
+            ..default()
+        },
+        ToastContainer,
+    ));
+}
+
+fn show_requested_notifications(
+    mut requests: EventReader<OnNotificationRequested>,
+    mut commands: Commands,
+    container_query: Query<Entity, With<ToastContainer>>,
+) {
+    let Ok(container_entity) = container_query.get_single() else {
+        return;
+    };
+
+    for request in requests.read() {
+        commands
+            .entity(container_entity)
+            .with_children(|container| {
+                container
+                    .spawn((
+                        NodeBundle {
+                            style: Style {
+                                width: Val::Px(TOAST_WIDTH),
+                                padding: UiRect::axes(Val::Px(12.0), Val::Px(8.0)),
+                                border: UiRect::left(Val::Px(TOAST_ACCENT_WIDTH)),
+                                ..default()
+                            },
+                            border_radius: BorderRadius::all(Val::Px(6.0)),
+                            focus_policy: FocusPolicy::Block,
+                            ..default()
+                        },
+                        Themed::default()
+                            .with_background_color(ThemeColor::RaisedSurface.into())
+                            .with_border_color(request.level.accent_color().into()),
+                        Interaction::default(),
+                        Toast::new(request.level),
+                    ))
+                    .with_children(|toast| {
+                        TextBuilder::new(TextConfig {
+                            text: request.message.clone(),
+                            font_size: ThemeFontSize::Small.into(),
+                            ..default()
+                        })
+                        .spawn(toast, ());
+                    });
+            });
+    }
+}
+
+fn dismiss_toast_on_press(
+    mut commands: Commands,
+    toast_query: Query<(Entity, Ref<Interaction>), With<Toast>>,
+) {
+    for (toast_entity, _) in toast_query.iter().filter(|(_, interaction)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        commands.entity(toast_entity).despawn_recursive();
+    }
+}
+
+fn dismiss_expired_toasts(
+    mut commands: Commands,
+    mut toast_query: Query<(Entity, &mut Toast)>,
+    time: Res<Time>,
+) {
+    for (toast_entity, mut toast) in toast_query.iter_mut() {
+        if toast.timer.tick(time.delta()).finished() {
+            commands.entity(toast_entity).despawn_recursive();
+        }
+    }
+}
+
+fn dismiss_overflowing_toasts(
+    mut commands: Commands,
+    container_query: Query<&Children, With<ToastContainer>>,
+    toast_query: Query<&Toast>,
+) {
+    let Ok(children) = container_query.get_single() else {
+        return;
+    };
+
+    // Expired toasts are already being dismissed
+    let visible_toasts: Vec<Entity> = children
+        .iter()
+        .copied()
+        .filter(|child| {
+            toast_query
+                .get(*child)
+                .is_ok_and(|toast| !toast.timer.finished())
+        })
+        .collect();
+
+    for toast_entity in visible_toasts
+        .iter()
+        .take(overflowing_toast_count(visible_toasts.len()))
+    {
+        commands.entity(*toast_entity).despawn_recursive();
+    }
+}
+
+fn overflowing_toast_count(toast_count: usize) -> usize {
+    toast_count.saturating_sub(MAX_VISIBLE_TOASTS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn problems_stay_visible_longer() {
+        assert!(
+            NotificationLevel::Error.duration_seconds()
+                > NotificationLevel::Warning.duration_seconds()
+        );
+        assert!(
+            NotificationLevel::Warning.duration_seconds()
+                > NotificationLevel::Info.duration_seconds()
+        );
+    }
+
+    #[test]
+    fn only_toasts_above_the_limit_overflow() {
+        assert_eq!(overflowing_toast_count(MAX_VISIBLE_TOASTS - 1), 0);
+        assert_eq!(overflowing_toast_count(MAX_VISIBLE_TOASTS + 2), 2);
+    }
+}
@@ -1,15 +1,22 @@
 use bevy::prelude::*;
 
-use crate::{game_modes::GameMode, GameRunningSet};
+use crate::{
+    game_modes::GameMode,
+    road_editor::exit_protection::{LeaveEditorDestination, OnLeaveEditorRequested},
+    GameRunningSet,
+};
 
 use super::{
     components::{
         buttons::TextButtonBuilder,
         flexbox::{FlexboxBuilder, FlexboxConfig},
+        inputs::{color_input::eyedropper::eyedropper_is_active, dropdown::dropdown_is_open},
         text::TextBuilder,
         UiComponentBuilder, UiComponentWithChildrenBuilder,
     },
-    modal::{OnHideModalRequested, OnShowModalRequested},
+    keybindings_menu::OnShowKeybindingsMenuRequested,
+    modal::{modal_is_open, OnHideModalRequested, OnShowModalRequested},
+    settings_menu::OnShowSettingsMenuRequested,
 };
 
 pub struct PauseMenuPlugin;
@@ -18,17 +25,25 @@ impl Plugin for PauseMenuPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             Update,
-            (show_pause_menu_on_esc, handle_pause_menu_actions)
+            (
+                // Escape cancels picking a color, closes a dropdown or closes the top modal instead
+                show_pause_menu_on_esc.run_if(
+                    not(eyedropper_is_active)
+                        .and_then(not(dropdown_is_open))
+                        .and_then(not(modal_is_open)),
+                ),
+                handle_pause_menu_actions,
+            )
                 .in_set(GameRunningSet::GetUserInput)
                 .run_if(in_state(GameMode::RoadEditor).or_else(in_state(GameMode::RoadDrawer))),
         );
     }
 }
 
-// todo: hide pause menu when pressing esc while it's open
-
 #[derive(Component, Clone, Copy)]
 enum PauseMenuAction {
+    ShowKeybindings,
+    ShowSettings,
     ExitToMainMenu,
 }
 
@@ -49,6 +64,12 @@ fn show_pause_menu_on_esc(
                     .with_text("Pause menu")
                     .spawn(container, ());
 
+                TextButtonBuilder::default_with_text("Controls")
+                    .spawn(container, PauseMenuAction::ShowKeybindings);
+
+                TextButtonBuilder::default_with_text("Settings")
+                    .spawn(container, PauseMenuAction::ShowSettings);
+
                 TextButtonBuilder::default_with_text("Exit to main menu")
                     .spawn(container, PauseMenuAction::ExitToMainMenu);
             })
@@ -60,7 +81,11 @@ fn show_pause_menu_on_esc(
 
 fn handle_pause_menu_actions(
     mut on_hide_modal: EventWriter<OnHideModalRequested>,
+    mut on_leave_editor: EventWriter<OnLeaveEditorRequested>,
+    mut on_show_keybindings: EventWriter<OnShowKeybindingsMenuRequested>,
+    mut on_show_settings: EventWriter<OnShowSettingsMenuRequested>,
     mut next_game_mode: ResMut<NextState<GameMode>>,
+    game_mode: Res<State<GameMode>>,
     button_query: Query<(&Interaction, &PauseMenuAction), Changed<Interaction>>,
 ) {
     for (_, action) in button_query
@@ -68,7 +93,24 @@ fn handle_pause_menu_actions(
         .filter(|(interaction, _)| **interaction == Interaction::Pressed)
     {
         match action {
-            PauseMenuAction::ExitToMainMenu => next_game_mode.set(GameMode::MainMenu),
+            // Opens on top of the pause menu, which stays open to return to
+            PauseMenuAction::ShowKeybindings => {
+                on_show_keybindings.send(OnShowKeybindingsMenuRequested);
+                continue;
+            }
+            PauseMenuAction::ShowSettings => {
+                on_show_settings.send(OnShowSettingsMenuRequested);
+                continue;
+            }
+            // The road editor first asks what to do with unsaved changes
+            PauseMenuAction::ExitToMainMenu => match game_mode.get() {
+                GameMode::RoadEditor => {
+                    on_leave_editor.send(OnLeaveEditorRequested::new(
+                        LeaveEditorDestination::MainMenu,
+                    ));
+                }
+                _ => next_game_mode.set(GameMode::MainMenu),
+            },
         }
 
         on_hide_modal.send(OnHideModalRequested);
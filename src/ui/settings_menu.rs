@@ -0,0 +1,353 @@
+use std::ops::Range;
+
+use bevy::prelude::*;
+
+use crate::{
+    settings::{OnSettingChangeRequested, SettingChange, Settings},
+    utility::distance::LengthUnit,
+    GameRunningSet,
+};
+
+use super::{
+    components::{
+        flexbox::{FlexboxBuilder, FlexboxConfig},
+        inputs::{
+            checkbox::{CheckboxConfig, OnCheckboxValueChanged, ToggleBuilder},
+            dropdown::{DropdownBuilder, DropdownConfig, OnDropdownValueChanged},
+            number_input::{NumberInputBuilder, OnNumberInputValueChanged},
+        },
+        text::{TextBuilder, TextConfig},
+        UiComponentBuilder, UiComponentWithChildrenBuilder,
+    },
+    cross_section_view::CrossSectionViewMode,
+    modal::OnShowModalRequested,
+    theme::{ThemeColor, ThemeFontSize, UiThemeKind},
+};
+
+const LABEL_COLUMN_WIDTH: f32 = 300.0;
+const INPUT_COLUMN_WIDTH: f32 = 240.0;
+
+pub struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OnShowSettingsMenuRequested>().add_systems(
+            Update,
+            (
+                (
+                    handle_number_input_changed_events,
+                    handle_dropdown_changed_events,
+                    handle_toggle_changed_events,
+                )
+                    .in_set(GameRunningSet::SendCommands),
+                handle_show_requests.in_set(GameRunningSet::HandleCommands),
+            ),
+        );
+    }
+}
+
+/// Opens the menu to change the [Settings], on top of the open modals.
+#[derive(Event)]
+pub struct OnShowSettingsMenuRequested;
+
+/// The setting that an input in the settings menu changes.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+enum SettingField {
+    CameraMovementSpeed,
+    CameraRotationSpeed,
+    CameraZoomSpeed,
+    MaxInteractionDistance,
+    RoadNodeSnapDistance,
+    PreviewRoadLength,
+    PreviewCurveAngle,
+    LengthUnit,
+    DarkTheme,
+    CrossSectionView,
+}
+
+impl SettingField {
+    fn number_change(&self, value: f32) -> Option<SettingChange> {
+        match self {
+            SettingField::CameraMovementSpeed => Some(SettingChange::CameraMovementSpeed(value)),
+            SettingField::CameraRotationSpeed => Some(SettingChange::CameraRotationSpeed(value)),
+            SettingField::CameraZoomSpeed => Some(SettingChange::CameraZoomSpeed(value)),
+            SettingField::MaxInteractionDistance => {
+                Some(SettingChange::MaxInteractionDistance(value))
+            }
+            SettingField::RoadNodeSnapDistance => Some(SettingChange::RoadNodeSnapDistance(value)),
+            SettingField::PreviewRoadLength => Some(SettingChange::PreviewRoadLength(value)),
+            SettingField::PreviewCurveAngle => Some(SettingChange::PreviewCurveAngle(value)),
+            SettingField::LengthUnit | SettingField::DarkTheme | SettingField::CrossSectionView => {
+                None
+            }
+        }
+    }
+}
+
+/// The value, allowed range and step of a setting that is changed with a number input.
+struct NumberSetting {
+    label: &'static str,
+    field: SettingField,
+    value: f32,
+    range: Range<f32>,
+    step: f32,
+    /// Whether the setting is a length in meters, shown in the chosen [LengthUnit].
+    is_length: bool,
+}
+
+fn handle_show_requests(
+    mut requests: EventReader<OnShowSettingsMenuRequested>,
+    mut on_show_modal: EventWriter<OnShowModalRequested>,
+    mut commands: Commands,
+    settings: Res<Settings>,
+) {
+    for _ in requests.read() {
+        let menu_entity = spawn_settings_menu(&mut commands, &settings);
+
+        on_show_modal.send(OnShowModalRequested::new(menu_entity));
+    }
+}
+
+fn handle_number_input_changed_events(
+    mut on_input_changed: EventReader<OnNumberInputValueChanged>,
+    mut on_change_request: EventWriter<OnSettingChangeRequested>,
+    field_query: Query<&SettingField>,
+) {
+    for event in on_input_changed.read() {
+        let Some(change) = field_query
+            .get(event.number_input_entity())
+            .ok()
+            .and_then(|field| field.number_change(event.new_value()))
+        else {
+            continue;
+        };
+
+        on_change_request.send(OnSettingChangeRequested::new(change));
+    }
+}
+
+fn handle_dropdown_changed_events(
+    mut on_dropdown_changed: EventReader<OnDropdownValueChanged>,
+    mut on_change_request: EventWriter<OnSettingChangeRequested>,
+    field_query: Query<&SettingField>,
+) {
+    for event in on_dropdown_changed.read() {
+        let change = match field_query.get(event.dropdown_entity()) {
+            Ok(SettingField::LengthUnit) => LengthUnit::ALL
+                .get(event.selected_index())
+                .map(|length_unit| SettingChange::LengthUnit(*length_unit)),
+            Ok(SettingField::CrossSectionView) => CrossSectionViewMode::ALL
+                .get(event.selected_index())
+                .map(|mode| SettingChange::CrossSectionView(*mode)),
+            _ => None,
+        };
+
+        let Some(change) = change else {
+            continue;
+        };
+
+        on_change_request.send(OnSettingChangeRequested::new(change));
+    }
+}
+
+fn handle_toggle_changed_events(
+    mut on_checkbox_changed: EventReader<OnCheckboxValueChanged>,
+    mut on_change_request: EventWriter<OnSettingChangeRequested>,
+    field_query: Query<&SettingField>,
+) {
+    for event in on_checkbox_changed.read() {
+        if field_query.get(event.checkbox_entity()) != Ok(&SettingField::DarkTheme) {
+            continue;
+        }
+
+        let theme = match event.is_checked() {
+            true => UiThemeKind::Dark,
+            false => UiThemeKind::Light,
+        };
+
+        on_change_request.send(OnSettingChangeRequested::new(SettingChange::Theme(theme)));
+    }
+}
+
+fn spawn_settings_menu(commands: &mut Commands, settings: &Settings) -> Entity {
+    let flexbox_node =
+        FlexboxBuilder::new(FlexboxConfig::horizontally_centered_column().with_px_gap(16.0))
+            .build();
+
+    commands
+        .spawn(flexbox_node)
+        .with_children(|container| {
+            TextBuilder::default()
+                .with_text("Settings")
+                .spawn(container, ());
+
+            spawn_setting_group(container, "Camera", |group| {
+                for number_setting in [
+                    NumberSetting {
+                        label: "Movement speed",
+                        field: SettingField::CameraMovementSpeed,
+                        value: settings.camera_movement_speed,
+                        range: 0.1..5.0,
+                        step: 0.1,
+                        is_length: false,
+                    },
+                    NumberSetting {
+                        label: "Rotation speed",
+                        field: SettingField::CameraRotationSpeed,
+                        value: settings.camera_rotation_speed,
+                        range: 0.1..5.0,
+                        step: 0.1,
+                        is_length: false,
+                    },
+                    NumberSetting {
+                        label: "Zoom speed",
+                        field: SettingField::CameraZoomSpeed,
+                        value: settings.camera_zoom_speed,
+                        range: 0.1..5.0,
+                        step: 0.1,
+                        is_length: false,
+                    },
+                ] {
+                    spawn_number_setting_row(group, number_setting);
+                }
+            });
+
+            spawn_setting_group(container, "Roads", |group| {
+                for number_setting in [
+                    NumberSetting {
+                        label: "Drawing distance",
+                        field: SettingField::MaxInteractionDistance,
+                        value: settings.max_interaction_distance,
+                        range: 50.0..2000.0,
+                        step: 50.0,
+                        is_length: true,
+                    },
+                    NumberSetting {
+                        label: "Snap distance",
+                        field: SettingField::RoadNodeSnapDistance,
+                        value: settings.road_node_snap_distance,
+                        range: 0.0..20.0,
+                        step: 0.5,
+                        is_length: true,
+                    },
+                    NumberSetting {
+                        label: "Preview length",
+                        field: SettingField::PreviewRoadLength,
+                        value: settings.preview_road_length,
+                        range: 5.0..200.0,
+                        step: 5.0,
+                        is_length: true,
+                    },
+                    NumberSetting {
+                        label: "Preview curve (degrees)",
+                        field: SettingField::PreviewCurveAngle,
+                        value: settings.preview_curve_angle,
+                        range: -180.0..180.0,
+                        step: 15.0,
+                        is_length: false,
+                    },
+                ] {
+                    spawn_number_setting_row(group, number_setting);
+                }
+            });
+
+            spawn_setting_group(container, "Display", |group| {
+                spawn_setting_row(group, "Units", |input_column| {
+                    let selected_index = LengthUnit::ALL
+                        .iter()
+                        .position(|length_unit| *length_unit == settings.length_unit)
+                        .unwrap_or_default();
+
+                    DropdownBuilder::new(
+                        DropdownConfig::new(LengthUnit::ALL.map(|length_unit| length_unit.name()))
+                            .with_selected_index(selected_index),
+                    )
+                    .spawn(input_column, SettingField::LengthUnit);
+                });
+
+                spawn_setting_row(group, "Cross-section", |input_column| {
+                    let selected_index = CrossSectionViewMode::ALL
+                        .iter()
+                        .position(|mode| *mode == settings.cross_section_view)
+                        .unwrap_or_default();
+
+                    DropdownBuilder::new(
+                        DropdownConfig::new(CrossSectionViewMode::ALL.map(|mode| mode.name()))
+                            .with_selected_index(selected_index),
+                    )
+                    .spawn(input_column, SettingField::CrossSectionView);
+                });
+
+                spawn_setting_row(group, "Dark theme", |input_column| {
+                    ToggleBuilder::new(
+                        CheckboxConfig::default().with_checked(settings.theme == UiThemeKind::Dark),
+                    )
+                    .spawn(input_column, SettingField::DarkTheme);
+                });
+            });
+        })
+        .id()
+}
+
+fn spawn_setting_group(
+    builder: &mut ChildBuilder,
+    title: &str,
+    rows: impl FnOnce(&mut ChildBuilder),
+) {
+    FlexboxBuilder::new(FlexboxConfig::column().with_px_gap(4.0)).spawn(builder, (), |group| {
+        TextBuilder::new(TextConfig {
+            text: title.to_string(),
+            color: ThemeColor::Accent.into(),
+            font_size: ThemeFontSize::Small.into(),
+            ..default()
+        })
+        .spawn(group, ());
+
+        rows(group);
+    });
+}
+
+fn spawn_number_setting_row(builder: &mut ChildBuilder, number_setting: NumberSetting) {
+    spawn_setting_row(builder, number_setting.label, |input_column| {
+        let mut number_input_builder = NumberInputBuilder::default();
+        number_input_builder
+            .with_values(number_setting.value, number_setting.range)
+            .with_step(number_setting.step);
+
+        if number_setting.is_length {
+            number_input_builder.as_length();
+        }
+
+        number_input_builder.spawn(input_column, number_setting.field);
+    });
+}
+
+fn spawn_setting_row(
+    builder: &mut ChildBuilder,
+    label: &str,
+    input: impl FnOnce(&mut ChildBuilder),
+) {
+    FlexboxBuilder::new(FlexboxConfig::vertically_centered_row()).spawn(builder, (), |row| {
+        row.spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(LABEL_COLUMN_WIDTH),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|label_column| {
+            TextBuilder::default()
+                .with_text(label)
+                .spawn(label_column, ());
+        });
+
+        row.spawn(NodeBundle {
+            style: Style {
+                width: Val::Px(INPUT_COLUMN_WIDTH),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(input);
+    });
+}
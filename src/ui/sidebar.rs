@@ -1,22 +1,29 @@
+mod component_group_config;
 mod road_component_config;
 
 use bevy::prelude::*;
+use component_group_config::ComponentGroupConfigPlugin;
 use road_component_config::RoadComponentConfigPlugin;
 
 use crate::{game_modes::GameMode, GameRunningSet};
 
-use super::components::{section::SectionBuilder, UiComponentWithChildrenBuilder};
+use super::components::{
+    content_size::ContentSizeConfig,
+    section::{SectionBuilder, SectionConfig},
+    UiComponentWithChildrenBuilder,
+};
 
 pub struct SidebarPlugin;
 
 impl Plugin for SidebarPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(RoadComponentConfigPlugin).add_systems(
-            Update,
-            toggle_sidebar_visibility_based_on_content
-                .after(GameRunningSet::DespawnEntities)
-                .run_if(in_state(GameMode::RoadEditor)),
-        );
+        app.add_plugins((RoadComponentConfigPlugin, ComponentGroupConfigPlugin))
+            .add_systems(
+                Update,
+                toggle_sidebar_visibility_based_on_content
+                    .after(GameRunningSet::DespawnEntities)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            );
     }
 }
 
@@ -24,7 +31,11 @@ impl Plugin for SidebarPlugin {
 pub struct Sidebar;
 
 pub fn spawn_sidebar(builder: &mut ChildBuilder) {
-    SectionBuilder::spawn_default(builder, Sidebar, |_| {});
+    SectionBuilder::new(
+        SectionConfig::default()
+            .with_content_size_config(ContentSizeConfig::default().with_max_height(100.0)),
+    )
+    .spawn(builder, Sidebar, |_| {});
 }
 
 fn toggle_sidebar_visibility_based_on_content(
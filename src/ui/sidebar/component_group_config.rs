@@ -0,0 +1,356 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{
+        active_road::{
+            active_road_events::road_component_group_change::{
+                OnRoadComponentGroupChangeRequested, OnRoadComponentGroupChanged,
+                RoadComponentGroupChange,
+            },
+            ActiveRoad,
+        },
+        road_component_group::{OnSaveComponentGroupPresetRequested, RoadComponentGroupPreset},
+        road_data::RoadData,
+    },
+    ui::{
+        components::{
+            buttons::{ButtonAction, OnButtonPressed, TextButtonBuilder},
+            collapsible_section::CollapsibleSectionBuilder,
+            flexbox::{FlexboxBuilder, FlexboxConfig},
+            section::SectionConfig,
+            tooltip::Tooltip,
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        inputs::text_input::{spawn_text_input_node, OnTextInputValueChanged},
+        list::{reorder_button::ReorderDirection, ListItem},
+        modal::confirm_dialog::{
+            ConfirmDialogConfig, ConfirmDialogPurpose, OnConfirmDialogClosed,
+            OnConfirmDialogRequested,
+        },
+        theme::ThemeColor,
+    },
+    GameRunningSet,
+};
+
+use super::road_component_config::RoadComponentConfig;
+
+pub struct ComponentGroupConfigPlugin;
+
+impl Plugin for ComponentGroupConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                (
+                    handle_group_button_pressed_events,
+                    handle_group_name_changed_events,
+                    delete_group_when_confirmed,
+                )
+                    .in_set(GameRunningSet::SendCommands),
+                regenerate_group_config_on_group_changed.in_set(GameRunningSet::DespawnEntities),
+            )
+                .run_if(in_state(GameMode::RoadEditor)),
+        );
+    }
+}
+
+/// The content of the "Group" section in the config of the selected road component.
+#[derive(Component)]
+struct ComponentGroupConfig;
+
+#[derive(Component)]
+struct ComponentGroupNameInput;
+
+/// Spawns the section with the group actions of the road component at `component_index`.
+pub fn spawn_component_group_config(
+    builder: &mut ChildBuilder,
+    road_data: &RoadData,
+    component_index: usize,
+) {
+    CollapsibleSectionBuilder::new(
+        SectionConfig::default().with_background_color(ThemeColor::RaisedSurface),
+        "Group",
+    )
+    .with_expanded(true)
+    .spawn(builder, (), |group_section| {
+        FlexboxBuilder::new(FlexboxConfig::horizontally_centered_column().with_px_gap(8.0)).spawn(
+            group_section,
+            ComponentGroupConfig,
+            |group_config| {
+                spawn_component_group_config_content(group_config, road_data, component_index);
+            },
+        );
+    });
+}
+
+fn spawn_component_group_config_content(
+    builder: &mut ChildBuilder,
+    road_data: &RoadData,
+    component_index: usize,
+) {
+    if let Some(group_index) = road_data.component_group_index_of(component_index) {
+        let group = &road_data.component_groups()[group_index];
+
+        spawn_text_input_node(
+            builder,
+            (
+                ComponentGroupNameInput,
+                Tooltip::new("The name of the group this component is in"),
+            ),
+            group.name.clone(),
+        );
+
+        spawn_button_row(
+            builder,
+            &[
+                (
+                    "Move left",
+                    ButtonAction::MoveComponentGroupLeft,
+                    "Move the group past the component or group to its left",
+                ),
+                (
+                    "Move right",
+                    ButtonAction::MoveComponentGroupRight,
+                    "Move the group past the component or group to its right",
+                ),
+            ],
+        );
+
+        spawn_button_row(
+            builder,
+            &[
+                (
+                    "Duplicate",
+                    ButtonAction::DuplicateComponentGroup,
+                    "Add a copy of the group and its markings right after it",
+                ),
+                (
+                    "Mirror",
+                    ButtonAction::MirrorComponentGroup,
+                    "Reverse the order of the group's components",
+                ),
+            ],
+        );
+
+        spawn_button_row(
+            builder,
+            &[
+                (
+                    "Ungroup",
+                    ButtonAction::UngroupComponents,
+                    "Remove the group, but keep its components",
+                ),
+                (
+                    "Delete group",
+                    ButtonAction::DeleteComponentGroup,
+                    "Remove the group with its components and markings",
+                ),
+            ],
+        );
+
+        TextButtonBuilder::default_with_text("Save as preset").spawn(
+            builder,
+            (
+                ButtonAction::SaveComponentGroupPreset,
+                Tooltip::new("Save the group so it can be added to other road designs"),
+            ),
+        );
+    }
+
+    let unit_range = road_data.component_unit_range(component_index);
+
+    if unit_range.end < road_data.component_count() {
+        TextButtonBuilder::default_with_text("Group with next").spawn(
+            builder,
+            (
+                ButtonAction::GroupWithNextComponent,
+                Tooltip::new("Group this component with the component or group to its right"),
+            ),
+        );
+    }
+}
+
+fn spawn_button_row(builder: &mut ChildBuilder, buttons: &[(&str, ButtonAction, &str)]) {
+    FlexboxBuilder::new(FlexboxConfig::row().with_px_gap(4.0)).spawn(builder, (), |row| {
+        for (label, action, tooltip) in buttons {
+            TextButtonBuilder::default_with_text(*label)
+                .spawn(row, (*action, Tooltip::new(*tooltip)));
+        }
+    });
+}
+
+/// Regenerates the group section when only the groups changed, other group changes rebuild the whole config.
+fn regenerate_group_config_on_group_changed(
+    mut on_group_changed: EventReader<OnRoadComponentGroupChanged>,
+    mut commands: Commands,
+    group_config_query: Query<Entity, With<ComponentGroupConfig>>,
+    component_config_query: Query<&RoadComponentConfig>,
+    list_item_query: Query<&ListItem>,
+) {
+    for event in on_group_changed.read() {
+        // Renaming keeps the name input, so it doesn't lose focus while typing
+        if event.changed_components()
+            || matches!(event.change, RoadComponentGroupChange::Rename { .. })
+        {
+            continue;
+        }
+
+        let (Ok(group_config_entity), Some(component_index)) = (
+            group_config_query.get_single(),
+            selected_component_index(&component_config_query, &list_item_query),
+        ) else {
+            continue;
+        };
+
+        commands
+            .entity(group_config_entity)
+            .despawn_descendants()
+            .with_children(|group_config| {
+                spawn_component_group_config_content(
+                    group_config,
+                    event.new_road_data(),
+                    component_index,
+                );
+            });
+    }
+}
+
+fn handle_group_button_pressed_events(
+    mut on_pressed: EventReader<OnButtonPressed>,
+    mut on_change_request: EventWriter<OnRoadComponentGroupChangeRequested>,
+    mut on_save_preset: EventWriter<OnSaveComponentGroupPresetRequested>,
+    mut on_confirm_request: EventWriter<OnConfirmDialogRequested>,
+    component_config_query: Query<&RoadComponentConfig>,
+    list_item_query: Query<&ListItem>,
+    active_road: Res<ActiveRoad>,
+) {
+    for event in on_pressed.read() {
+        let Some(component_index) =
+            selected_component_index(&component_config_query, &list_item_query)
+        else {
+            continue;
+        };
+
+        let road_data = active_road.road_data();
+
+        if event.is_action(ButtonAction::GroupWithNextComponent) {
+            on_change_request.send(OnRoadComponentGroupChangeRequested::new(
+                RoadComponentGroupChange::GroupWithNext(component_index),
+            ));
+            continue;
+        }
+
+        let Some(group_index) = road_data.component_group_index_of(component_index) else {
+            continue;
+        };
+
+        let change = match event.action() {
+            ButtonAction::MoveComponentGroupLeft => RoadComponentGroupChange::Move {
+                group_index,
+                direction: ReorderDirection::Previous,
+            },
+            ButtonAction::MoveComponentGroupRight => RoadComponentGroupChange::Move {
+                group_index,
+                direction: ReorderDirection::Next,
+            },
+            ButtonAction::DuplicateComponentGroup => {
+                RoadComponentGroupChange::Duplicate(group_index)
+            }
+            ButtonAction::MirrorComponentGroup => RoadComponentGroupChange::Mirror(group_index),
+            ButtonAction::UngroupComponents => RoadComponentGroupChange::Ungroup(group_index),
+            ButtonAction::DeleteComponentGroup => {
+                let group_name = &road_data.component_groups()[group_index].name;
+
+                on_confirm_request.send(OnConfirmDialogRequested::new(
+                    ConfirmDialogConfig::new(
+                        ConfirmDialogPurpose::DeleteComponentGroup,
+                        "Delete group",
+                        format!("Remove \"{group_name}\" and its components from the road?"),
+                    )
+                    .with_confirm_label("Delete"),
+                ));
+                continue;
+            }
+            ButtonAction::SaveComponentGroupPreset => {
+                on_save_preset.send(OnSaveComponentGroupPresetRequested::new(
+                    RoadComponentGroupPreset::from_group(road_data, group_index),
+                ));
+                continue;
+            }
+            _ => continue,
+        };
+
+        on_change_request.send(OnRoadComponentGroupChangeRequested::new(change));
+    }
+}
+
+fn handle_group_name_changed_events(
+    mut on_input_changed: EventReader<OnTextInputValueChanged>,
+    mut on_change_request: EventWriter<OnRoadComponentGroupChangeRequested>,
+    name_input_query: Query<(), With<ComponentGroupNameInput>>,
+    component_config_query: Query<&RoadComponentConfig>,
+    list_item_query: Query<&ListItem>,
+    active_road: Res<ActiveRoad>,
+) {
+    for event in on_input_changed
+        .read()
+        .filter(|event| name_input_query.contains(event.text_input_entity()))
+    {
+        let Some(group_index) = selected_component_index(&component_config_query, &list_item_query)
+            .and_then(|component_index| {
+                active_road
+                    .road_data()
+                    .component_group_index_of(component_index)
+            })
+        else {
+            continue;
+        };
+
+        on_change_request.send(OnRoadComponentGroupChangeRequested::new(
+            RoadComponentGroupChange::Rename {
+                group_index,
+                name: event.text().to_string(),
+            },
+        ));
+    }
+}
+
+fn delete_group_when_confirmed(
+    mut on_closed: EventReader<OnConfirmDialogClosed>,
+    mut on_change_request: EventWriter<OnRoadComponentGroupChangeRequested>,
+    component_config_query: Query<&RoadComponentConfig>,
+    list_item_query: Query<&ListItem>,
+    active_road: Res<ActiveRoad>,
+) {
+    for _ in on_closed
+        .read()
+        .filter(|event| event.is_confirmed(ConfirmDialogPurpose::DeleteComponentGroup))
+    {
+        let Some(group_index) = selected_component_index(&component_config_query, &list_item_query)
+            .and_then(|component_index| {
+                active_road
+                    .road_data()
+                    .component_group_index_of(component_index)
+            })
+        else {
+            continue;
+        };
+
+        on_change_request.send(OnRoadComponentGroupChangeRequested::new(
+            RoadComponentGroupChange::Delete(group_index),
+        ));
+    }
+}
+
+fn selected_component_index(
+    component_config_query: &Query<&RoadComponentConfig>,
+    list_item_query: &Query<&ListItem>,
+) -> Option<usize> {
+    let component_config = component_config_query.get_single().ok()?;
+
+    list_item_query
+        .get(component_config.component_entity())
+        .ok()
+        .map(ListItem::index)
+}
@@ -17,18 +17,30 @@ use crate::{
     ui::{
         components::{
             buttons::{ButtonAction, OnButtonPressed, TextButtonBuilder},
-            flexbox::{FlexboxBuilder, FlexboxConfig},
+            collapsible_section::CollapsibleSectionBuilder,
+            content_size::ContentSizeConfig,
+            content_wrap::ContentWrapConfig,
+            flexbox::FlexboxConfig,
             inputs::{
+                checkbox::{CheckboxBuilder, CheckboxConfig, OnCheckboxValueChanged},
                 color_input::{
                     ColorInput, ColorInputBuilder, ColorInputConfig, OnColorInputValueChanged,
                 },
                 labeled_element::LabeledElementBuilder,
                 number_input::{NumberInput, NumberInputBuilder, OnNumberInputValueChanged},
             },
+            scroll_container::ScrollContainerBuilder,
+            section::{SectionBuilder, SectionConfig},
+            tooltip::Tooltip,
             UiComponentBuilder, UiComponentWithChildrenBuilder,
         },
         inputs::text_input::{spawn_text_input_node, OnTextInputValueChanged, TextInput},
         list::ListItem,
+        modal::confirm_dialog::{
+            ConfirmDialogConfig, ConfirmDialogPurpose, OnConfirmDialogClosed,
+            OnConfirmDialogRequested,
+        },
+        theme::ThemeColor,
         toolbar::components::selected_road_component::{
             OnRoadComponentDeselected, OnRoadComponentSelected,
         },
@@ -36,7 +48,7 @@ use crate::{
     GameRunningSet,
 };
 
-use super::Sidebar;
+use super::{component_group_config::spawn_component_group_config, Sidebar};
 
 pub struct RoadComponentConfigPlugin;
 
@@ -49,7 +61,9 @@ impl Plugin for RoadComponentConfigPlugin {
                     handle_number_input_changed_events,
                     handle_text_input_changed_events,
                     handle_color_input_changed_events,
+                    handle_checkbox_changed_events,
                     handle_delete_button_pressed_events,
+                    delete_component_when_confirmed,
                 )
                     .in_set(GameRunningSet::SendCommands),
                 (
@@ -73,6 +87,10 @@ impl RoadComponentConfig {
     pub fn new(component_entity: Entity) -> Self {
         Self { component_entity }
     }
+
+    pub fn component_entity(&self) -> Entity {
+        self.component_entity
+    }
 }
 
 fn generate_config_section_for_selected_component(
@@ -91,9 +109,14 @@ fn generate_config_section_for_selected_component(
             .entity(sidebar)
             .despawn_descendants()
             .with_children(|sidebar| {
-                FlexboxBuilder::new(
-                    FlexboxConfig::horizontally_centered_column().with_px_gap(12.0),
-                )
+                // Can shrink below its content height, so the scroll container gets the remaining space
+                SectionBuilder::new(SectionConfig {
+                    wrap: ContentWrapConfig::empty(),
+                    flexbox: FlexboxConfig::horizontally_centered_column().with_px_gap(12.0),
+                    size: ContentSizeConfig::empty()
+                        .with_min_height(0.0)
+                        .with_max_height(100.0),
+                })
                 .spawn(
                     sidebar,
                     RoadComponentConfig::new(event.component_item_entity()),
@@ -101,45 +124,179 @@ fn generate_config_section_for_selected_component(
                         // TODO: replace with text input UiComponent
                         spawn_text_input_node(
                             config_container,
-                            RoadComponentField::Name,
+                            (
+                                RoadComponentField::Name,
+                                Tooltip::new("The name of this component"),
+                            ),
                             component_data.name.clone(),
                         );
 
-                        LabeledElementBuilder::centered_top_label("Width").spawn(
-                            config_container,
-                            (),
-                            |width_label| {
-                                NumberInputBuilder::default()
-                                    .with_values(component_data.size.x, 0.0..10.0)
-                                    .spawn(width_label, RoadComponentField::Width);
-                            },
-                        );
+                        ScrollContainerBuilder::new(
+                            FlexboxConfig::horizontally_centered_column().with_px_gap(12.0),
+                        )
+                        .spawn(config_container, (), |scroll_container| {
+                            CollapsibleSectionBuilder::new(
+                                SectionConfig::default()
+                                    .with_background_color(ThemeColor::RaisedSurface),
+                                "Dimensions",
+                            )
+                            .with_expanded(true)
+                            .spawn(
+                                scroll_container,
+                                (),
+                                |dimensions| {
+                                    LabeledElementBuilder::centered_top_label("Width").spawn(
+                                        dimensions,
+                                        Tooltip::new("The width of this component"),
+                                        |width_label| {
+                                            NumberInputBuilder::default()
+                                                .with_values(component_data.size.x, 0.0..10.0)
+                                                .as_length()
+                                                .spawn(width_label, RoadComponentField::Width);
+                                        },
+                                    );
 
-                        LabeledElementBuilder::centered_top_label("Height").spawn(
-                            config_container,
-                            (),
-                            |height_label| {
-                                NumberInputBuilder::default()
-                                    .with_values(component_data.size.y, 0.0..10.0)
-                                    .spawn(height_label, RoadComponentField::Height);
-                            },
-                        );
+                                    LabeledElementBuilder::centered_top_label("Height").spawn(
+                                        dimensions,
+                                        Tooltip::new("The height of this component"),
+                                        |height_label| {
+                                            NumberInputBuilder::default()
+                                                .with_values(component_data.size.y, 0.0..10.0)
+                                                .as_length()
+                                                .spawn(height_label, RoadComponentField::Height);
+                                        },
+                                    );
+
+                                    LabeledElementBuilder::centered_top_label("Flex").spawn(
+                                        dimensions,
+                                        Tooltip::new("How much of the road's target width this component gets, 0 keeps its own width"),
+                                        |flex_label| {
+                                            NumberInputBuilder::default()
+                                                .with_values(component_data.flex, 0.0..10.0)
+                                                .with_step(0.5)
+                                                .spawn(flex_label, RoadComponentField::Flex);
+                                        },
+                                    );
+
+                                    LabeledElementBuilder::centered_top_label("Min width").spawn(
+                                        dimensions,
+                                        Tooltip::new("The narrowest this component gets from the target width, 0 for no limit"),
+                                        |min_width_label| {
+                                            NumberInputBuilder::default()
+                                                .with_values(
+                                                    component_data.min_width.unwrap_or(0.0),
+                                                    0.0..10.0,
+                                                )
+                                                .as_length()
+                                                .spawn(min_width_label, RoadComponentField::MinWidth);
+                                        },
+                                    );
+
+                                    LabeledElementBuilder::centered_top_label("Max width").spawn(
+                                        dimensions,
+                                        Tooltip::new("The widest this component gets from the target width, 0 for no limit"),
+                                        |max_width_label| {
+                                            NumberInputBuilder::default()
+                                                .with_values(
+                                                    component_data.max_width.unwrap_or(0.0),
+                                                    0.0..10.0,
+                                                )
+                                                .as_length()
+                                                .spawn(max_width_label, RoadComponentField::MaxWidth);
+                                        },
+                                    );
+                                },
+                            );
+
+                            CollapsibleSectionBuilder::new(
+                                SectionConfig::default()
+                                    .with_background_color(ThemeColor::RaisedSurface),
+                                "Appearance",
+                            )
+                            .with_expanded(true)
+                            .spawn(
+                                scroll_container,
+                                (),
+                                |appearance| {
+                                    LabeledElementBuilder::centered_top_label("Color").spawn(
+                                        appearance,
+                                        Tooltip::new("The surface color of this component"),
+                                        |color_label| {
+                                            ColorInputBuilder::new(
+                                                ColorInputConfig::default()
+                                                    .with_start_color(component_data.color),
+                                                &mut images,
+                                            )
+                                            .spawn(color_label, RoadComponentField::Color);
+                                        },
+                                    );
+                                },
+                            );
+
+                            let track = component_data.track.unwrap_or_default();
+
+                            CollapsibleSectionBuilder::new(
+                                SectionConfig::default()
+                                    .with_background_color(ThemeColor::RaisedSurface),
+                                "Rail track",
+                            )
+                            .with_expanded(component_data.track.is_some())
+                            .spawn(
+                                scroll_container,
+                                (),
+                                |rail_track| {
+                                    LabeledElementBuilder::centered_top_label("Track").spawn(
+                                        rail_track,
+                                        Tooltip::new("Embed rails in this component, for tram lanes and other rail lanes"),
+                                        |track_label| {
+                                            CheckboxBuilder::new(
+                                                CheckboxConfig::default()
+                                                    .with_checked(component_data.track.is_some()),
+                                            )
+                                            .spawn(track_label, RoadComponentField::HasTrack);
+                                        },
+                                    );
+
+                                    LabeledElementBuilder::centered_top_label("Gauge").spawn(
+                                        rail_track,
+                                        Tooltip::new("The distance between the inner faces of the rails, the track is left out if it doesn't fit the component"),
+                                        |gauge_label| {
+                                            NumberInputBuilder::default()
+                                                .with_values(track.gauge, 0.5..2.0)
+                                                .with_step(0.001)
+                                                .as_length()
+                                                .spawn(gauge_label, RoadComponentField::TrackGauge);
+                                        },
+                                    );
+
+                                    LabeledElementBuilder::centered_top_label("Sleepers").spawn(
+                                        rail_track,
+                                        Tooltip::new("Show sleepers across the track, spaced evenly along the road"),
+                                        |sleepers_label| {
+                                            CheckboxBuilder::new(
+                                                CheckboxConfig::default()
+                                                    .with_checked(track.has_sleepers),
+                                            )
+                                            .spawn(sleepers_label, RoadComponentField::HasSleepers);
+                                        },
+                                    );
+                                },
+                            );
 
-                        LabeledElementBuilder::centered_top_label("Color").spawn(
+                            spawn_component_group_config(
+                                scroll_container,
+                                active_road.road_data(),
+                                component_index,
+                            );
+                        });
+
+                        TextButtonBuilder::default_with_text("Delete").spawn(
                             config_container,
-                            (),
-                            |color_label| {
-                                ColorInputBuilder::new(
-                                    ColorInputConfig::default()
-                                        .with_start_color(component_data.color),
-                                    &mut images,
-                                )
-                                .spawn(color_label, RoadComponentField::Color);
-                            },
+                            (
+                                ButtonAction::DeleteComponent,
+                                Tooltip::new("Remove this component from the road"),
+                            ),
                         );
-
-                        TextButtonBuilder::default_with_text("Delete")
-                            .spawn(config_container, ButtonAction::DeleteComponent);
                     },
                 );
             });
@@ -164,6 +321,7 @@ fn handle_number_input_changed_events(
     component_config_query: Query<&RoadComponentConfig>,
     number_input_query: Query<&RoadComponentField, With<NumberInput>>,
     list_item_query: Query<&ListItem>,
+    active_road: Res<ActiveRoad>,
 ) {
     for event in on_input_changed.read() {
         let Ok(config_action) = number_input_query.get(event.number_input_entity()) else {
@@ -171,19 +329,32 @@ fn handle_number_input_changed_events(
         };
 
         let component_config = component_config_query.single();
+        let component_index =
+            list_item_index_from_entity(&list_item_query, component_config.component_entity);
         let new_value = event.new_value();
 
+        // A width limit of 0 means the component has no limit
+        let width_limit = (new_value > 0.0).then_some(new_value);
+
         let field_to_change = match config_action {
             RoadComponentField::Width => RoadComponentFieldChange::Width(new_value),
             RoadComponentField::Height => RoadComponentFieldChange::Height(new_value),
+            RoadComponentField::MinWidth => RoadComponentFieldChange::MinWidth(width_limit),
+            RoadComponentField::MaxWidth => RoadComponentFieldChange::MaxWidth(width_limit),
+            RoadComponentField::Flex => RoadComponentFieldChange::Flex(new_value),
+            RoadComponentField::TrackGauge => {
+                // The gauge is kept in the input while the component has no track
+                let Some(track) = active_road.component_at_index(component_index).track else {
+                    continue;
+                };
+
+                RoadComponentFieldChange::Track(Some(track.with_gauge(new_value)))
+            }
             _ => continue,
         };
 
         on_change_request.send(OnRoadComponentChangeRequested::new(
-            RoadComponentChange::new(
-                field_to_change,
-                list_item_index_from_entity(&list_item_query, component_config.component_entity),
-            ),
+            RoadComponentChange::new(field_to_change, component_index),
         ));
     }
 }
@@ -245,23 +416,87 @@ fn handle_color_input_changed_events(
     }
 }
 
+fn handle_checkbox_changed_events(
+    mut on_checkbox_changed: EventReader<OnCheckboxValueChanged>,
+    mut on_change_request: EventWriter<OnRoadComponentChangeRequested>,
+    component_config_query: Query<&RoadComponentConfig>,
+    checkbox_query: Query<&RoadComponentField>,
+    list_item_query: Query<&ListItem>,
+    active_road: Res<ActiveRoad>,
+) {
+    for event in on_checkbox_changed.read() {
+        let Ok(config_action) = checkbox_query.get(event.checkbox_entity()) else {
+            continue;
+        };
+
+        let component_config = component_config_query.single();
+        let component_index =
+            list_item_index_from_entity(&list_item_query, component_config.component_entity);
+        let track = active_road.component_at_index(component_index).track;
+
+        let new_track = match config_action {
+            RoadComponentField::HasTrack => event.is_checked().then(|| track.unwrap_or_default()),
+            RoadComponentField::HasSleepers => {
+                let Some(track) = track else {
+                    continue;
+                };
+
+                Some(track.with_sleepers(event.is_checked()))
+            }
+            _ => continue,
+        };
+
+        on_change_request.send(OnRoadComponentChangeRequested::new(
+            RoadComponentChange::new(RoadComponentFieldChange::Track(new_track), component_index),
+        ));
+    }
+}
+
 fn handle_delete_button_pressed_events(
     mut on_pressed: EventReader<OnButtonPressed>,
-    mut on_deletion_request: EventWriter<OnRoadComponentDeletionRequested>,
-    mut on_deselect: EventWriter<OnRoadComponentDeselected>,
+    mut on_confirm_request: EventWriter<OnConfirmDialogRequested>,
     component_config_query: Query<&RoadComponentConfig>,
     list_item_query: Query<&ListItem>,
+    active_road: Res<ActiveRoad>,
 ) {
     for _ in on_pressed
         .read()
         .filter(|event| event.is_action(ButtonAction::DeleteComponent))
     {
         let component_config = component_config_query.single();
-        let list_item = list_item_query
-            .get(component_config.component_entity)
-            .unwrap();
+        let component_index =
+            list_item_index_from_entity(&list_item_query, component_config.component_entity);
+        let component_name = &active_road.component_at_index(component_index).name;
 
-        on_deletion_request.send(OnRoadComponentDeletionRequested::new(list_item.index()));
+        on_confirm_request.send(OnConfirmDialogRequested::new(
+            ConfirmDialogConfig::new(
+                ConfirmDialogPurpose::DeleteRoadComponent,
+                "Delete component",
+                format!("Remove \"{component_name}\" from the road?"),
+            )
+            .with_confirm_label("Delete"),
+        ));
+    }
+}
+
+fn delete_component_when_confirmed(
+    mut on_closed: EventReader<OnConfirmDialogClosed>,
+    mut on_deletion_request: EventWriter<OnRoadComponentDeletionRequested>,
+    mut on_deselect: EventWriter<OnRoadComponentDeselected>,
+    component_config_query: Query<&RoadComponentConfig>,
+    list_item_query: Query<&ListItem>,
+) {
+    for _ in on_closed
+        .read()
+        .filter(|event| event.is_confirmed(ConfirmDialogPurpose::DeleteRoadComponent))
+    {
+        let Ok(component_config) = component_config_query.get_single() else {
+            continue;
+        };
+
+        on_deletion_request.send(OnRoadComponentDeletionRequested::new(
+            list_item_index_from_entity(&list_item_query, component_config.component_entity),
+        ));
 
         on_deselect.send(OnRoadComponentDeselected);
     }
@@ -0,0 +1,512 @@
+use std::{fs, io};
+
+use bevy::{color::palettes::tailwind::*, prelude::*, ui::UiSystem};
+use serde::{Deserialize, Serialize};
+
+use crate::GameRunningSet;
+
+const THEMES_PATH: &str = "assets/themes";
+
+pub struct UiThemePlugin;
+
+impl Plugin for UiThemePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(load_ui_theme_or_built_in(UiThemeKind::default()))
+            .add_event::<OnUiThemeChangeRequested>()
+            // Font sizes and padding change the layout, so the theme is applied before it is computed
+            .configure_sets(PostUpdate, UiThemeSet.before(UiSystem::Layout))
+            .add_systems(
+                Update,
+                handle_theme_change_requests.in_set(GameRunningSet::HandleCommands),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    apply_theme_background_colors,
+                    apply_theme_border_colors,
+                    apply_theme_text_styles,
+                    apply_theme_padding,
+                )
+                    .in_set(UiThemeSet),
+            );
+    }
+}
+
+/// The systems that apply the [UiTheme] to [Themed] nodes, they run in [PostUpdate].
+#[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+pub struct UiThemeSet;
+
+/// The built-in themes, each can be customized with a RON file in `assets/themes`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UiThemeKind {
+    #[default]
+    Dark,
+    Light,
+}
+
+impl UiThemeKind {
+    fn file_path(&self) -> String {
+        let file_name = match self {
+            UiThemeKind::Dark => "dark",
+            UiThemeKind::Light => "light",
+        };
+
+        format!("{THEMES_PATH}/{file_name}.ron")
+    }
+
+    fn built_in_theme(&self) -> UiTheme {
+        match self {
+            UiThemeKind::Dark => UiTheme::dark(),
+            UiThemeKind::Light => UiTheme::light(),
+        }
+    }
+}
+
+/// The colors, font sizes and spacing that all UiComponents are drawn with.
+#[derive(Resource, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UiTheme {
+    pub colors: UiThemeColors,
+    pub font_sizes: UiThemeFontSizes,
+    pub spacing: UiThemeSpacing,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UiThemeColors {
+    /// Behind full screen menus, like the main menu.
+    pub background: Color,
+    /// Panels, modals and popups.
+    pub surface: Color,
+    /// Sections inside of a panel.
+    pub raised_surface: Color,
+    /// Tracks, wells and overlays that sit below the content around them.
+    pub sunken_surface: Color,
+    /// Buttons and other elements that can be clicked.
+    pub control: Color,
+    /// Checked, selected and focused elements.
+    pub accent: Color,
+    pub text: Color,
+    pub muted_text: Color,
+    pub border: Color,
+    pub success: Color,
+    pub warning: Color,
+    pub danger: Color,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UiThemeFontSizes {
+    pub small: f32,
+    pub normal: f32,
+    pub large: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct UiThemeSpacing {
+    pub small: f32,
+    pub medium: f32,
+    pub large: f32,
+}
+
+impl UiTheme {
+    pub fn dark() -> Self {
+        Self {
+            colors: UiThemeColors {
+                background: TEAL_700.into(),
+                surface: NEUTRAL_700.into(),
+                raised_surface: NEUTRAL_600.into(),
+                sunken_surface: NEUTRAL_800.into(),
+                control: NEUTRAL_500.into(),
+                accent: SKY_500.into(),
+                text: Color::WHITE,
+                muted_text: NEUTRAL_300.into(),
+                border: NEUTRAL_900.into(),
+                success: GREEN_400.into(),
+                warning: AMBER_300.into(),
+                danger: RED_400.into(),
+            },
+            // Only the colors differ between the built-in themes
+            ..Self::light()
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            colors: UiThemeColors {
+                background: TEAL_100.into(),
+                surface: NEUTRAL_200.into(),
+                raised_surface: NEUTRAL_100.into(),
+                sunken_surface: NEUTRAL_300.into(),
+                control: NEUTRAL_50.into(),
+                accent: SKY_600.into(),
+                text: NEUTRAL_900.into(),
+                muted_text: NEUTRAL_600.into(),
+                border: NEUTRAL_400.into(),
+                success: GREEN_700.into(),
+                warning: AMBER_700.into(),
+                danger: RED_600.into(),
+            },
+            font_sizes: UiThemeFontSizes {
+                small: 18.0,
+                normal: 24.0,
+                large: 30.0,
+            },
+            spacing: UiThemeSpacing {
+                small: 4.0,
+                medium: 8.0,
+                large: 12.0,
+            },
+        }
+    }
+
+    pub fn color(&self, color: ThemeColor) -> Color {
+        match color {
+            ThemeColor::Background => self.colors.background,
+            ThemeColor::Surface => self.colors.surface,
+            ThemeColor::RaisedSurface => self.colors.raised_surface,
+            ThemeColor::SunkenSurface => self.colors.sunken_surface,
+            ThemeColor::Control => self.colors.control,
+            ThemeColor::Accent => self.colors.accent,
+            ThemeColor::Text => self.colors.text,
+            ThemeColor::MutedText => self.colors.muted_text,
+            ThemeColor::Border => self.colors.border,
+            ThemeColor::Success => self.colors.success,
+            ThemeColor::Warning => self.colors.warning,
+            ThemeColor::Danger => self.colors.danger,
+        }
+    }
+
+    pub fn font_size(&self, font_size: ThemeFontSize) -> f32 {
+        match font_size {
+            ThemeFontSize::Small => self.font_sizes.small,
+            ThemeFontSize::Normal => self.font_sizes.normal,
+            ThemeFontSize::Large => self.font_sizes.large,
+        }
+    }
+
+    pub fn spacing(&self, spacing: ThemeSpacing) -> f32 {
+        match spacing {
+            ThemeSpacing::Small => self.spacing.small,
+            ThemeSpacing::Medium => self.spacing.medium,
+            ThemeSpacing::Large => self.spacing.large,
+        }
+    }
+}
+
+/// Refers to one of the colors of the [UiTheme].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThemeColor {
+    Background,
+    Surface,
+    RaisedSurface,
+    SunkenSurface,
+    Control,
+    Accent,
+    Text,
+    MutedText,
+    Border,
+    Success,
+    Warning,
+    Danger,
+}
+
+/// Refers to one of the font sizes of the [UiTheme].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThemeFontSize {
+    Small,
+    Normal,
+    Large,
+}
+
+/// Refers to one of the spacing sizes of the [UiTheme].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThemeSpacing {
+    Small,
+    Medium,
+    Large,
+}
+
+/// A color in a UiComponent config, either taken from the theme or fixed, like the color of a road component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThemedColor {
+    Theme(ThemeColor),
+    Fixed(Color),
+}
+
+impl ThemedColor {
+    fn theme_color(&self) -> Option<ThemeColor> {
+        match self {
+            ThemedColor::Theme(color) => Some(*color),
+            ThemedColor::Fixed(_) => None,
+        }
+    }
+
+    /// The color a node is spawned with, theme colors are filled in by [UiThemeSet] before the node is drawn.
+    pub fn initial_color(&self) -> Color {
+        match self {
+            ThemedColor::Theme(_) => Color::NONE,
+            ThemedColor::Fixed(color) => *color,
+        }
+    }
+}
+
+impl From<ThemeColor> for ThemedColor {
+    fn from(value: ThemeColor) -> Self {
+        ThemedColor::Theme(value)
+    }
+}
+
+impl From<Color> for ThemedColor {
+    fn from(value: Color) -> Self {
+        ThemedColor::Fixed(value)
+    }
+}
+
+impl From<Srgba> for ThemedColor {
+    fn from(value: Srgba) -> Self {
+        ThemedColor::Fixed(value.into())
+    }
+}
+
+/// A font size in a UiComponent config, either taken from the theme or fixed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThemedFontSize {
+    Theme(ThemeFontSize),
+    Fixed(f32),
+}
+
+impl ThemedFontSize {
+    fn theme_font_size(&self) -> Option<ThemeFontSize> {
+        match self {
+            ThemedFontSize::Theme(font_size) => Some(*font_size),
+            ThemedFontSize::Fixed(_) => None,
+        }
+    }
+
+    /// The font size a node is spawned with, theme font sizes are filled in by [UiThemeSet] before the layout is computed.
+    pub fn initial_font_size(&self) -> f32 {
+        match self {
+            ThemedFontSize::Theme(_) => 1.0,
+            ThemedFontSize::Fixed(font_size) => *font_size,
+        }
+    }
+}
+
+impl From<ThemeFontSize> for ThemedFontSize {
+    fn from(value: ThemeFontSize) -> Self {
+        ThemedFontSize::Theme(value)
+    }
+}
+
+impl From<f32> for ThemedFontSize {
+    fn from(value: f32) -> Self {
+        ThemedFontSize::Fixed(value)
+    }
+}
+
+/// The padding in a UiComponent config, either the same theme spacing on all sides or fixed.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ThemedPadding {
+    Theme(ThemeSpacing),
+    Fixed(UiRect),
+}
+
+impl ThemedPadding {
+    fn theme_spacing(&self) -> Option<ThemeSpacing> {
+        match self {
+            ThemedPadding::Theme(spacing) => Some(*spacing),
+            ThemedPadding::Fixed(_) => None,
+        }
+    }
+
+    /// The padding a node is spawned with, theme spacing is filled in by [UiThemeSet] before the layout is computed.
+    pub fn initial_padding(&self) -> UiRect {
+        match self {
+            ThemedPadding::Theme(_) => UiRect::ZERO,
+            ThemedPadding::Fixed(padding) => *padding,
+        }
+    }
+}
+
+impl From<ThemeSpacing> for ThemedPadding {
+    fn from(value: ThemeSpacing) -> Self {
+        ThemedPadding::Theme(value)
+    }
+}
+
+impl From<UiRect> for ThemedPadding {
+    fn from(value: UiRect) -> Self {
+        ThemedPadding::Fixed(value)
+    }
+}
+
+/// The parts of a node that follow the [UiTheme], they're updated whenever the theme changes.
+///
+/// - Parts that are fixed or changed by the systems of a UiComponent itself are left empty.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Themed {
+    background_color: Option<ThemeColor>,
+    border_color: Option<ThemeColor>,
+    text_color: Option<ThemeColor>,
+    font_size: Option<ThemeFontSize>,
+    padding: Option<ThemeSpacing>,
+}
+
+impl Themed {
+    pub fn with_background_color(mut self, background_color: ThemedColor) -> Self {
+        self.background_color = background_color.theme_color();
+        self
+    }
+
+    pub fn with_border_color(mut self, border_color: ThemedColor) -> Self {
+        self.border_color = border_color.theme_color();
+        self
+    }
+
+    pub fn with_text_color(mut self, text_color: ThemedColor) -> Self {
+        self.text_color = text_color.theme_color();
+        self
+    }
+
+    pub fn with_font_size(mut self, font_size: ThemedFontSize) -> Self {
+        self.font_size = font_size.theme_font_size();
+        self
+    }
+
+    pub fn with_padding(mut self, padding: ThemedPadding) -> Self {
+        self.padding = padding.theme_spacing();
+        self
+    }
+}
+
+/// Switches the UI to the given theme, loaded from its RON file if there is one.
+#[derive(Event)]
+pub struct OnUiThemeChangeRequested {
+    kind: UiThemeKind,
+}
+
+impl OnUiThemeChangeRequested {
+    pub fn new(kind: UiThemeKind) -> Self {
+        Self { kind }
+    }
+}
+
+fn handle_theme_change_requests(
+    mut requests: EventReader<OnUiThemeChangeRequested>,
+    mut theme: ResMut<UiTheme>,
+) {
+    let Some(request) = requests.read().last() else {
+        return;
+    };
+
+    let requested_theme = load_ui_theme_or_built_in(request.kind);
+
+    if *theme != requested_theme {
+        *theme = requested_theme;
+    }
+}
+
+fn apply_theme_background_colors(
+    theme: Res<UiTheme>,
+    mut node_query: Query<(Ref<Themed>, &mut BackgroundColor)>,
+) {
+    for (themed, mut background_color) in node_query
+        .iter_mut()
+        .filter(|(themed, _)| theme.is_changed() || themed.is_changed())
+    {
+        if let Some(color) = themed.background_color {
+            background_color.0 = theme.color(color);
+        }
+    }
+}
+
+fn apply_theme_border_colors(
+    theme: Res<UiTheme>,
+    mut node_query: Query<(Ref<Themed>, &mut BorderColor)>,
+) {
+    for (themed, mut border_color) in node_query
+        .iter_mut()
+        .filter(|(themed, _)| theme.is_changed() || themed.is_changed())
+    {
+        if let Some(color) = themed.border_color {
+            border_color.0 = theme.color(color);
+        }
+    }
+}
+
+fn apply_theme_text_styles(theme: Res<UiTheme>, mut text_query: Query<(Ref<Themed>, &mut Text)>) {
+    for (themed, mut text) in text_query
+        .iter_mut()
+        .filter(|(themed, _)| theme.is_changed() || themed.is_changed())
+    {
+        for section in text.sections.iter_mut() {
+            if let Some(color) = themed.text_color {
+                section.style.color = theme.color(color);
+            }
+
+            if let Some(font_size) = themed.font_size {
+                section.style.font_size = theme.font_size(font_size);
+            }
+        }
+    }
+}
+
+fn apply_theme_padding(theme: Res<UiTheme>, mut node_query: Query<(Ref<Themed>, &mut Style)>) {
+    for (themed, mut style) in node_query
+        .iter_mut()
+        .filter(|(themed, _)| theme.is_changed() || themed.is_changed())
+    {
+        if let Some(spacing) = themed.padding {
+            style.padding = UiRect::all(Val::Px(theme.spacing(spacing)));
+        }
+    }
+}
+
+fn load_ui_theme_or_built_in(kind: UiThemeKind) -> UiTheme {
+    match load_ui_theme(kind) {
+        Ok(Some(loaded_theme)) => loaded_theme,
+        Ok(None) => kind.built_in_theme(),
+        Err(error) => {
+            warn!("Failed to load UI theme: {error}");
+            kind.built_in_theme()
+        }
+    }
+}
+
+/// Returns the theme from the RON file of the given kind, or `None` if the theme hasn't been customized.
+fn load_ui_theme(kind: UiThemeKind) -> Result<Option<UiTheme>, ron::Error> {
+    match fs::read_to_string(kind.file_path()) {
+        Ok(serialized_theme) => Ok(Some(deserialize_ui_theme(&serialized_theme)?)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn deserialize_ui_theme(serialized_theme: &str) -> Result<UiTheme, ron::Error> {
+    Ok(ron::from_str::<UiTheme>(serialized_theme)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use ron::ser::PrettyConfig;
+
+    use super::*;
+
+    #[test]
+    fn theme_survives_ron_round_trip() {
+        let theme = UiTheme::light();
+
+        let serialized_theme =
+            ron::ser::to_string_pretty(&theme, PrettyConfig::new().struct_names(true)).unwrap();
+
+        assert_eq!(deserialize_ui_theme(&serialized_theme).unwrap(), theme);
+    }
+
+    #[test]
+    fn fixed_colors_are_not_changed_by_the_theme() {
+        let themed = Themed::default()
+            .with_background_color(ThemeColor::Surface.into())
+            .with_border_color(Color::BLACK.into());
+
+        assert_eq!(themed.background_color, Some(ThemeColor::Surface));
+        assert_eq!(themed.border_color, None);
+    }
+}
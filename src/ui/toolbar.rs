@@ -1,12 +1,20 @@
 mod add_road_component;
+mod component_group_presets;
 pub mod components;
 mod load;
-mod save;
+mod reference_line;
+mod road_status;
+mod road_width;
+pub mod save;
 
 use add_road_component::AddRoadComponentPlugin;
 use bevy::prelude::*;
+use component_group_presets::ComponentGroupPresetsPlugin;
 use components::ToolbarComponentsPlugin;
 use load::LoadPlugin;
+use reference_line::{spawn_reference_line_config, ReferenceLinePlugin};
+use road_status::{spawn_road_status_text, RoadStatusPlugin};
+use road_width::{spawn_road_width_config, RoadWidthPlugin};
 use save::SavePlugin;
 
 use super::{
@@ -15,6 +23,7 @@ use super::{
         content_wrap::ContentWrapConfig,
         flexbox::{FlexboxBuilder, FlexboxConfig},
         section::{SectionBuilder, SectionConfig},
+        tooltip::Tooltip,
         UiComponentBuilder, UiComponentWithChildrenBuilder,
     },
     list::List,
@@ -29,6 +38,10 @@ impl Plugin for ToolbarPlugin {
             SavePlugin,
             LoadPlugin,
             AddRoadComponentPlugin,
+            ComponentGroupPresetsPlugin,
+            RoadStatusPlugin,
+            RoadWidthPlugin,
+            ReferenceLinePlugin,
         ));
     }
 }
@@ -62,15 +75,60 @@ pub fn spawn_toolbar(builder: &mut ChildBuilder) {
 
 fn spawn_action_buttons(builder: &mut ChildBuilder) {
     FlexboxBuilder::new(FlexboxConfig::column().with_px_gap(4.0)).spawn(builder, (), |container| {
-        TextButtonBuilder::default_with_text("Markings")
-            .spawn(container, ButtonAction::ShowRoadMarkingConfig);
-        TextButtonBuilder::default_with_text("Save").spawn(container, ButtonAction::SaveRoad);
-        TextButtonBuilder::default_with_text("Load").spawn(container, ButtonAction::LoadRoad);
+        spawn_road_status_text(container);
+        spawn_road_width_config(container);
+        spawn_reference_line_config(container);
+        TextButtonBuilder::default_with_text("Markings").spawn(
+            container,
+            (
+                ButtonAction::ShowRoadMarkingConfig,
+                Tooltip::new("Configure the lines and other markings painted on the road"),
+            ),
+        );
+        TextButtonBuilder::default_with_text("Structures").spawn(
+            container,
+            (
+                ButtonAction::ShowRoadStructureConfig,
+                Tooltip::new("Configure the barriers, walls and fences along the road"),
+            ),
+        );
+        TextButtonBuilder::default_with_text("Save").spawn(
+            container,
+            (
+                ButtonAction::SaveRoad,
+                Tooltip::new("Save this road design to a file"),
+            ),
+        );
+        TextButtonBuilder::default_with_text("Load").spawn(
+            container,
+            (
+                ButtonAction::LoadRoad,
+                Tooltip::new("Load a road design from a file"),
+            ),
+        );
     });
 }
 
 fn spawn_add_component_button(builder: &mut ChildBuilder) {
-    FlexboxBuilder::new(FlexboxConfig::centered()).spawn(builder, (), |container| {
-        TextButtonBuilder::default_with_text("+").spawn(container, ButtonAction::AddComponent);
+    let flexbox_config = FlexboxConfig {
+        flex_direction: FlexDirection::Column,
+        ..FlexboxConfig::centered()
+    };
+
+    FlexboxBuilder::new(flexbox_config.with_px_gap(4.0)).spawn(builder, (), |container| {
+        TextButtonBuilder::default_with_text("+").spawn(
+            container,
+            (
+                ButtonAction::AddComponent,
+                Tooltip::new("Add a new component to the road"),
+            ),
+        );
+        TextButtonBuilder::default_with_text("Presets").spawn(
+            container,
+            (
+                ButtonAction::ShowComponentGroupPresets,
+                Tooltip::new("Add a saved group of components to the road"),
+            ),
+        );
     });
 }
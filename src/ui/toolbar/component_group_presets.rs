@@ -0,0 +1,106 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{
+        active_road::active_road_events::road_component_group_change::{
+            OnRoadComponentGroupChangeRequested, RoadComponentGroupChange,
+        },
+        persistance::{load_component_group_preset, saved_component_group_preset_names},
+    },
+    ui::{
+        components::{
+            buttons::{ButtonAction, OnButtonPressed, TextButtonBuilder},
+            flexbox::{FlexboxBuilder, FlexboxConfig},
+            text::TextBuilder,
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        modal::{OnHideModalRequested, OnShowModalRequested},
+        notifications::OnNotificationRequested,
+    },
+    GameRunningSet,
+};
+
+pub struct ComponentGroupPresetsPlugin;
+
+impl Plugin for ComponentGroupPresetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                show_modal_on_presets_button_pressed,
+                send_insert_preset_requests,
+            )
+                .in_set(GameRunningSet::SendCommands)
+                .run_if(in_state(GameMode::RoadEditor)),
+        );
+    }
+}
+
+/// A button in the presets modal that adds the preset with the given name to the road.
+#[derive(Component)]
+struct ComponentGroupPresetItem {
+    preset_name: String,
+}
+
+fn show_modal_on_presets_button_pressed(
+    mut on_pressed: EventReader<OnButtonPressed>,
+    mut on_request: EventWriter<OnShowModalRequested>,
+    mut commands: Commands,
+) {
+    for _ in on_pressed
+        .read()
+        .filter(|event| event.is_action(ButtonAction::ShowComponentGroupPresets))
+    {
+        let preset_names = saved_component_group_preset_names().unwrap_or_default();
+
+        let modal_content_entity = commands
+            .spawn(
+                FlexboxBuilder::new(FlexboxConfig::horizontally_centered_column().with_px_gap(4.0))
+                    .build(),
+            )
+            .with_children(|container| {
+                if preset_names.is_empty() {
+                    TextBuilder::default()
+                        .with_text("No group presets saved yet")
+                        .spawn(container, ());
+                }
+
+                for preset_name in preset_names {
+                    TextButtonBuilder::default_with_text(preset_name.clone())
+                        .spawn(container, ComponentGroupPresetItem { preset_name });
+                }
+            })
+            .id();
+
+        on_request.send(OnShowModalRequested::new(modal_content_entity));
+    }
+}
+
+fn send_insert_preset_requests(
+    mut on_change_request: EventWriter<OnRoadComponentGroupChangeRequested>,
+    mut on_notification: EventWriter<OnNotificationRequested>,
+    mut on_hide_request: EventWriter<OnHideModalRequested>,
+    preset_item_query: Query<(&Interaction, &ComponentGroupPresetItem), Changed<Interaction>>,
+) {
+    for (_, preset_item) in preset_item_query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+    {
+        match load_component_group_preset(&preset_item.preset_name) {
+            Ok(preset) => {
+                on_change_request.send(OnRoadComponentGroupChangeRequested::new(
+                    RoadComponentGroupChange::InsertPreset(preset),
+                ));
+            }
+            Err(error) => {
+                on_notification.send(OnNotificationRequested::error(format!(
+                    "Failed to load group preset \"{}\": {error}",
+                    preset_item.preset_name
+                )));
+            }
+        };
+
+        on_hide_request.send(OnHideModalRequested);
+    }
+}
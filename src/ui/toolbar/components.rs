@@ -1,20 +1,29 @@
+pub mod collapsed_groups;
 mod reorder;
 pub mod selected_road_component;
 
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::{ecs::system::SystemParam, prelude::*, ui::FocusPolicy};
+use collapsed_groups::{CollapsedGroups, CollapsedGroupsPlugin};
 use reorder::ReorderPlugin;
 use selected_road_component::{
     OnRoadComponentDeselected, OnRoadComponentSelected, SelectedRoadComponentPlugin,
 };
 
 use crate::{
+    game_modes::GameMode,
     road::{
-        active_road::active_road_events::{
-            new_road_component::OnRoadComponentAdded,
-            road_component_change::{OnRoadComponentChanged, RoadComponentFieldChange},
-            OnActiveRoadSet,
+        active_road::{
+            active_road_events::{
+                new_road_component::OnRoadComponentAdded,
+                road_component_change::{OnRoadComponentChanged, RoadComponentFieldChange},
+                road_component_group_change::OnRoadComponentGroupChanged,
+                OnActiveRoadSet,
+            },
+            ActiveRoad,
         },
         road_component::RoadComponent,
+        road_data::RoadData,
+        road_validation::RoadDiagnostics,
     },
     ui::{
         components::{
@@ -22,10 +31,12 @@ use crate::{
             text::{TextBuilder, TextConfig},
             UiComponentBuilder, UiComponentWithChildrenBuilder,
         },
+        diagnostics_panel::severity_color,
         list::{
             reorder_button::{spawn_reorder_button, ReorderDirection},
             ListItem,
         },
+        theme::{ThemeColor, ThemeFontSize, Themed, UiTheme},
     },
     utility::find_descendant_of_entity_mut,
     GameRunningSet,
@@ -34,21 +45,40 @@ use crate::{
 use super::RoadComponentsList;
 
 const COMPONENT_DISPLAY_SCALE: f32 = 50.0;
+const COMPONENT_DISPLAY_BORDER_COLOR: ThemeColor = ThemeColor::MutedText;
+const COMPONENT_GROUP_TAG_COLOR: ThemeColor = ThemeColor::Border;
+const COMPONENT_GROUP_TAG_HEIGHT: f32 = 20.0;
 
 pub struct ToolbarComponentsPlugin;
 
 impl Plugin for ToolbarComponentsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((ReorderPlugin, SelectedRoadComponentPlugin))
-            .add_systems(
-                Update,
+        app.add_plugins((
+            CollapsedGroupsPlugin,
+            ReorderPlugin,
+            SelectedRoadComponentPlugin,
+        ))
+        .add_systems(
+            Update,
+            (
+                toggle_component_group_on_tag_pressed.in_set(GameRunningSet::GetUserInput),
+                (add_road_component_on_event, update_road_component_on_change)
+                    .in_set(GameRunningSet::UpdateEntities),
                 (
-                    (add_road_component_on_event, update_road_component_on_change)
-                        .in_set(GameRunningSet::UpdateEntities),
-                    (rebuild_road_components_on_active_road_set,)
-                        .in_set(GameRunningSet::DespawnEntities),
-                ),
-            );
+                    rebuild_road_components_on_active_road_set,
+                    rebuild_road_components_on_group_changed,
+                )
+                    .in_set(GameRunningSet::DespawnEntities),
+                (
+                    highlight_road_components_with_diagnostics,
+                    update_road_component_group_tags,
+                    update_component_display_widths
+                        .run_if(resource_exists_and_changed::<ActiveRoad>),
+                )
+                    .after(GameRunningSet::DespawnEntities)
+                    .run_if(in_state(GameMode::RoadEditor)),
+            ),
+        );
     }
 }
 
@@ -57,12 +87,29 @@ pub struct RoadComponentItem {
     is_selected: bool,
 }
 
+impl RoadComponentItem {
+    pub fn is_selected(&self) -> bool {
+        self.is_selected
+    }
+}
+
 #[derive(Component)]
 struct RoadComponentName;
 
 #[derive(Component)]
 struct RoadComponentDisplay;
 
+/// Holds the name and buttons of a road component item, which are hidden while its group is collapsed.
+#[derive(Component)]
+struct RoadComponentDetails;
+
+/// The bar under the road components of a group, the first component's bar shows the group name.
+#[derive(Component)]
+struct RoadComponentGroupTag;
+
+#[derive(Component)]
+struct RoadComponentGroupLabel;
+
 fn rebuild_road_components_on_active_road_set(
     mut on_road_set: EventReader<OnActiveRoadSet>,
     mut on_deselect: EventWriter<OnRoadComponentDeselected>,
@@ -70,17 +117,32 @@ fn rebuild_road_components_on_active_road_set(
     components_list_query: Query<Entity, With<RoadComponentsList>>,
 ) {
     for event in on_road_set.read() {
-        let components_list_entity = components_list_query.single();
-        let road_components = event.road_data().components();
+        rebuild_road_components_list(
+            &mut commands,
+            components_list_query.single(),
+            event.road_data(),
+        );
 
-        commands
-            .entity(components_list_entity)
-            .despawn_descendants()
-            .with_children(|components_list| {
-                for (index, road_component) in road_components.iter().enumerate() {
-                    spawn_road_component_item(components_list, index, road_component);
-                }
-            });
+        on_deselect.send(OnRoadComponentDeselected);
+    }
+}
+
+/// Group changes can move many components at once, so the list is rebuilt instead of updated item by item.
+fn rebuild_road_components_on_group_changed(
+    mut on_group_changed: EventReader<OnRoadComponentGroupChanged>,
+    mut on_deselect: EventWriter<OnRoadComponentDeselected>,
+    mut commands: Commands,
+    components_list_query: Query<Entity, With<RoadComponentsList>>,
+) {
+    for event in on_group_changed
+        .read()
+        .filter(|event| event.changed_components())
+    {
+        rebuild_road_components_list(
+            &mut commands,
+            components_list_query.single(),
+            event.new_road_data(),
+        );
 
         on_deselect.send(OnRoadComponentDeselected);
     }
@@ -162,8 +224,195 @@ fn update_road_component_on_change(
     }
 }
 
+/// Keeps the width of each road component display up to date, with a target width one change can resize all flexible components.
+fn update_component_display_widths(
+    road_component_item_query: Query<(Entity, &ListItem), With<RoadComponentItem>>,
+    mut component_display_query: Query<(Entity, &mut Style), With<RoadComponentDisplay>>,
+    children_query: Query<&Children>,
+    active_road: Res<ActiveRoad>,
+) {
+    for (road_component_entity, list_item) in road_component_item_query.iter() {
+        let Some(road_component) = active_road.road_data().components().get(list_item.index())
+        else {
+            continue;
+        };
+
+        let Some((_, mut style)) = find_descendant_of_entity_mut(
+            road_component_entity,
+            &mut component_display_query,
+            |item| item.0,
+            &children_query,
+        ) else {
+            continue;
+        };
+
+        let target_width = build_component_display_style(road_component).width;
+
+        if style.width != target_width {
+            style.width = target_width;
+        }
+    }
+}
+
+/// Colors the border of each road component display to match the most severe diagnostic about that component.
+fn highlight_road_components_with_diagnostics(
+    road_diagnostics: Res<RoadDiagnostics>,
+    road_component_item_query: Query<(Entity, &ListItem), With<RoadComponentItem>>,
+    mut component_display_query: Query<(Entity, &mut BorderColor), With<RoadComponentDisplay>>,
+    children_query: Query<&Children>,
+    theme: Res<UiTheme>,
+) {
+    for (road_component_entity, list_item) in road_component_item_query.iter() {
+        let Some((_, mut border_color)) = find_descendant_of_entity_mut(
+            road_component_entity,
+            &mut component_display_query,
+            |item| item.0,
+            &children_query,
+        ) else {
+            continue;
+        };
+
+        let target_color = theme.color(
+            match road_diagnostics.component_severity(list_item.index()) {
+                Some(severity) => severity_color(severity),
+                None => COMPONENT_DISPLAY_BORDER_COLOR,
+            },
+        );
+
+        if border_color.0 != target_color {
+            border_color.0 = target_color;
+        }
+    }
+}
+
+/// The parts of the road component items that show their group.
+#[derive(SystemParam)]
+struct GroupTagPartQueries<'w, 's> {
+    group_tags: Query<'w, 's, (Entity, &'static mut BackgroundColor), With<RoadComponentGroupTag>>,
+    group_labels: Query<'w, 's, (Entity, &'static mut Text), With<RoadComponentGroupLabel>>,
+    details: Query<'w, 's, (Entity, &'static mut Style), With<RoadComponentDetails>>,
+}
+
+/// Shows which road components are grouped, and hides the details of the components in collapsed groups.
+fn update_road_component_group_tags(
+    road_component_item_query: Query<(Entity, &ListItem), With<RoadComponentItem>>,
+    mut part_queries: GroupTagPartQueries,
+    children_query: Query<&Children>,
+    active_road: Res<ActiveRoad>,
+    collapsed_groups: Res<CollapsedGroups>,
+    theme: Res<UiTheme>,
+) {
+    let road_data = active_road.road_data();
+
+    for (road_component_entity, list_item) in road_component_item_query.iter() {
+        let group_index = road_data.component_group_index_of(list_item.index());
+        let group = group_index.map(|group_index| &road_data.component_groups()[group_index]);
+        let is_collapsed =
+            group_index.is_some_and(|group_index| collapsed_groups.is_collapsed(group_index));
+
+        if let Some((_, mut background_color)) = find_descendant_of_entity_mut(
+            road_component_entity,
+            &mut part_queries.group_tags,
+            |item| item.0,
+            &children_query,
+        ) {
+            let target_color = match group {
+                Some(_) => theme.color(COMPONENT_GROUP_TAG_COLOR),
+                None => Color::NONE,
+            };
+
+            if background_color.0 != target_color {
+                background_color.0 = target_color;
+            }
+        }
+
+        if let Some((_, mut text)) = find_descendant_of_entity_mut(
+            road_component_entity,
+            &mut part_queries.group_labels,
+            |item| item.0,
+            &children_query,
+        ) {
+            let label = match group {
+                Some(group) if group.first_index == list_item.index() => {
+                    let indicator = match is_collapsed {
+                        true => '+',
+                        false => '-',
+                    };
+
+                    format!("{indicator} {}", group.name)
+                }
+                _ => String::new(),
+            };
+
+            if text.sections[0].value != label {
+                text.sections[0].value = label;
+            }
+        }
+
+        if let Some((_, mut style)) = find_descendant_of_entity_mut(
+            road_component_entity,
+            &mut part_queries.details,
+            |item| item.0,
+            &children_query,
+        ) {
+            let display = match is_collapsed {
+                true => Display::None,
+                false => Display::Flex,
+            };
+
+            if style.display != display {
+                style.display = display;
+            }
+        }
+    }
+}
+
+/// Pressing the tag under the first component of a group collapses or expands the group.
+fn toggle_component_group_on_tag_pressed(
+    mut collapsed_groups: ResMut<CollapsedGroups>,
+    group_tag_query: Query<(Ref<Interaction>, &Parent), With<RoadComponentGroupTag>>,
+    list_item_query: Query<&ListItem, With<RoadComponentItem>>,
+    active_road: Res<ActiveRoad>,
+) {
+    for (_, tag_parent) in group_tag_query.iter().filter(|(interaction, _)| {
+        interaction.is_changed() && **interaction == Interaction::Pressed
+    }) {
+        let Ok(list_item) = list_item_query.get(tag_parent.get()) else {
+            continue;
+        };
+
+        let road_data = active_road.road_data();
+
+        let Some(group_index) = road_data
+            .component_group_index_of(list_item.index())
+            .filter(|group_index| {
+                road_data.component_groups()[*group_index].first_index == list_item.index()
+            })
+        else {
+            continue;
+        };
+
+        collapsed_groups.toggle(group_index);
+    }
+}
+
 // Utility
 
+fn rebuild_road_components_list(
+    commands: &mut Commands,
+    components_list_entity: Entity,
+    road_data: &RoadData,
+) {
+    commands
+        .entity(components_list_entity)
+        .despawn_descendants()
+        .with_children(|components_list| {
+            for (index, road_component) in road_data.enumerate_components() {
+                spawn_road_component_item(components_list, index, road_component);
+            }
+        });
+}
+
 fn spawn_road_component_item(
     components_list: &mut ChildBuilder,
     index: usize,
@@ -173,6 +422,8 @@ fn spawn_road_component_item(
         ListItem::new(index),
         RoadComponentItem::default(),
         Interaction::default(),
+        // Highlights the item while it's selected
+        Themed::default(),
     );
 
     FlexboxBuilder::new(
@@ -186,21 +437,58 @@ fn spawn_road_component_item(
         |road_component_container| {
             road_component_container.spawn(build_component_display_node(road_component));
 
-            spawn_road_component_name(road_component_container, road_component);
+            FlexboxBuilder::new(FlexboxConfig::horizontally_centered_column().with_px_gap(4.0))
+                .spawn(road_component_container, RoadComponentDetails, |details| {
+                    spawn_road_component_name(details, road_component);
 
-            FlexboxBuilder::new(FlexboxConfig::row().with_px_gap(8.0)).spawn(
-                road_component_container,
-                (),
-                |button_container| {
-                    spawn_reorder_button(button_container, ReorderDirection::Previous, 26.0);
+                    FlexboxBuilder::new(FlexboxConfig::row().with_px_gap(8.0)).spawn(
+                        details,
+                        (),
+                        |button_container| {
+                            spawn_reorder_button(
+                                button_container,
+                                ReorderDirection::Previous,
+                                26.0,
+                            );
 
-                    spawn_reorder_button(button_container, ReorderDirection::Next, 26.0);
-                },
-            );
+                            spawn_reorder_button(button_container, ReorderDirection::Next, 26.0);
+                        },
+                    );
+                });
+
+            spawn_road_component_group_tag(road_component_container);
         },
     )
 }
 
+/// The tag is always spawned, update_road_component_group_tags shows it when the component is grouped.
+fn spawn_road_component_group_tag(builder: &mut ChildBuilder) {
+    builder
+        .spawn((
+            RoadComponentGroupTag,
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(COMPONENT_GROUP_TAG_HEIGHT),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                // Pressing the tag shouldn't also select the component
+                focus_policy: FocusPolicy::Block,
+                ..default()
+            },
+            Interaction::default(),
+        ))
+        .with_children(|tag| {
+            TextBuilder::new(TextConfig {
+                font_size: ThemeFontSize::Small.into(),
+                ..TextConfig::centered()
+            })
+            .spawn(tag, RoadComponentGroupLabel);
+        });
+}
+
 fn spawn_road_component_name(builder: &mut ChildBuilder, road_component: &RoadComponent) -> Entity {
     TextBuilder::new(
         TextConfig::centered()
@@ -229,7 +517,7 @@ fn build_component_display_node(road_component: &RoadComponent) -> impl Bundle {
         NodeBundle {
             style: build_component_display_style(road_component),
             background_color: road_component.color.into(),
-            border_color: BorderColor::from(NEUTRAL_400),
+            // The border color is set by highlight_road_components_with_diagnostics
             ..default()
         },
     )
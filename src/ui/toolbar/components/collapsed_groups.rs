@@ -0,0 +1,172 @@
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    road::{
+        active_road::active_road_events::{
+            road_component_deletion::OnRoadComponentDeleted,
+            road_component_group_change::{OnRoadComponentGroupChanged, RoadComponentGroupChange},
+            OnActiveRoadSet,
+        },
+        road_data::RoadData,
+    },
+    GameRunningSet,
+};
+
+pub struct CollapsedGroupsPlugin;
+
+impl Plugin for CollapsedGroupsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CollapsedGroups>().add_systems(
+            Update,
+            (
+                expand_groups_on_active_road_set,
+                update_collapsed_groups_on_group_changed,
+                update_collapsed_groups_on_component_deleted,
+            )
+                .in_set(GameRunningSet::UpdateEntities),
+        );
+    }
+}
+
+/// The indices of the component groups that are shown collapsed in the toolbar.
+///
+/// Collapsing is only how the toolbar shows a group, so it's kept apart from the road data and isn't saved with it.
+#[derive(Resource, Default, Debug)]
+pub struct CollapsedGroups {
+    group_indices: BTreeSet<usize>,
+}
+
+impl CollapsedGroups {
+    pub fn is_collapsed(&self, group_index: usize) -> bool {
+        self.group_indices.contains(&group_index)
+    }
+
+    pub fn toggle(&mut self, group_index: usize) {
+        if !self.group_indices.remove(&group_index) {
+            self.group_indices.insert(group_index);
+        }
+    }
+
+    /// Keeps the collapsed groups in step with the groups of the road data after the change is applied.
+    ///
+    /// - `previous_road_data` is the road data before the change.
+    fn apply_group_change(
+        &mut self,
+        change: &RoadComponentGroupChange,
+        previous_road_data: &RoadData,
+    ) {
+        match change {
+            RoadComponentGroupChange::GroupWithNext(component_index) => {
+                let first_unit = previous_road_data.component_unit_range(*component_index);
+
+                // The merged group replaces the groups it's made of, and is added after the other groups
+                let mut merged_group_indices = [first_unit.start, first_unit.end]
+                    .into_iter()
+                    .filter_map(|index| previous_road_data.component_group_index_of(index))
+                    .collect::<Vec<_>>();
+                merged_group_indices.sort_unstable();
+
+                for group_index in merged_group_indices.into_iter().rev() {
+                    self.remove_group(group_index);
+                }
+            }
+            RoadComponentGroupChange::Ungroup(group_index)
+            | RoadComponentGroupChange::Delete(group_index) => self.remove_group(*group_index),
+            RoadComponentGroupChange::Rename { .. }
+            | RoadComponentGroupChange::Move { .. }
+            | RoadComponentGroupChange::Duplicate(_)
+            | RoadComponentGroupChange::Mirror(_)
+            | RoadComponentGroupChange::InsertPreset(_) => {}
+        }
+    }
+
+    /// Forgets the removed group, the groups after it move down one index.
+    fn remove_group(&mut self, group_index: usize) {
+        self.group_indices = self
+            .group_indices
+            .iter()
+            .filter(|index| **index != group_index)
+            .map(|index| match *index > group_index {
+                true => index - 1,
+                false => *index,
+            })
+            .collect();
+    }
+}
+
+fn expand_groups_on_active_road_set(
+    mut on_road_set: EventReader<OnActiveRoadSet>,
+    mut collapsed_groups: ResMut<CollapsedGroups>,
+) {
+    if on_road_set.read().count() > 0 {
+        collapsed_groups.group_indices.clear();
+    }
+}
+
+fn update_collapsed_groups_on_group_changed(
+    mut on_group_changed: EventReader<OnRoadComponentGroupChanged>,
+    mut collapsed_groups: ResMut<CollapsedGroups>,
+) {
+    for event in on_group_changed.read() {
+        collapsed_groups.apply_group_change(&event.change, &event.changed_road_data.previous_value);
+    }
+}
+
+/// A group is removed when its last component is deleted.
+fn update_collapsed_groups_on_component_deleted(
+    mut on_deleted: EventReader<OnRoadComponentDeleted>,
+    mut collapsed_groups: ResMut<CollapsedGroups>,
+) {
+    for event in on_deleted.read() {
+        let previous_road_data = &event.changed_road_data.previous_value;
+
+        if let Some(group_index) = previous_road_data
+            .component_group_index_of(event.deleted_index)
+            .filter(|group_index| {
+                previous_road_data.component_groups()[*group_index].component_count == 1
+            })
+        {
+            collapsed_groups.remove_group(group_index);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::color::palettes::tailwind::*;
+
+    use crate::road::{road_component::RoadComponent, road_component_group::RoadComponentGroup};
+
+    use super::*;
+
+    fn collapsed_groups(group_indices: impl IntoIterator<Item = usize>) -> CollapsedGroups {
+        CollapsedGroups {
+            group_indices: group_indices.into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn collapsed_groups_follow_removed_and_merged_groups() {
+        let mut road_data = RoadData::new(
+            String::from("Grouped road"),
+            vec![RoadComponent::new("Lane", Vec2::new(3.0, 0.1), GRAY_800); 6],
+            Vec::new(),
+        );
+        *road_data.component_groups_mut() = vec![
+            RoadComponentGroup::new("Left", 0, 2),
+            RoadComponentGroup::new("Middle", 2, 2),
+            RoadComponentGroup::new("Right", 4, 2),
+        ];
+
+        let mut ungrouped = collapsed_groups([1, 2]);
+        ungrouped.apply_group_change(&RoadComponentGroupChange::Ungroup(0), &road_data);
+        assert_eq!(ungrouped.group_indices, BTreeSet::from([0, 1]));
+
+        // Merging the left and middle group leaves the right group as the first one
+        let mut merged = collapsed_groups([1, 2]);
+        merged.apply_group_change(&RoadComponentGroupChange::GroupWithNext(0), &road_data);
+        assert_eq!(merged.group_indices, BTreeSet::from([0]));
+    }
+}
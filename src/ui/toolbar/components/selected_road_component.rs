@@ -1,6 +1,16 @@
-use bevy::{color::palettes::tailwind::NEUTRAL_200, prelude::*};
+use bevy::prelude::*;
 
-use crate::{ui::list::ListItem, GameRunningSet};
+use crate::{
+    road::active_road::ActiveRoad,
+    ui::{
+        components::inputs::color_input::eyedropper::{
+            eyedropper_is_active, OnEyedropperColorPicked,
+        },
+        list::ListItem,
+        theme::{ThemeColor, Themed},
+    },
+    GameRunningSet,
+};
 
 use super::RoadComponentItem;
 
@@ -14,8 +24,12 @@ impl Plugin for SelectedRoadComponentPlugin {
                 Update,
                 (
                     (
-                        send_road_component_selected_events,
-                        send_deselected_when_selected_component_pressed,
+                        (
+                            send_road_component_selected_events,
+                            send_deselected_when_selected_component_pressed,
+                        )
+                            .run_if(not(eyedropper_is_active)),
+                        pick_road_component_color_with_eyedropper.run_if(eyedropper_is_active),
                     )
                         .in_set(GameRunningSet::GetUserInput),
                     (
@@ -86,32 +100,64 @@ fn send_deselected_when_selected_component_pressed(
     }
 }
 
+/// Clicking a road component while picking a color picks its color, instead of selecting it.
+fn pick_road_component_color_with_eyedropper(
+    mut on_picked: EventWriter<OnEyedropperColorPicked>,
+    road_component_item_query: Query<(&ListItem, Ref<Interaction>), With<RoadComponentItem>>,
+    active_road: Res<ActiveRoad>,
+) {
+    if let Some((list_item, _)) = road_component_item_query
+        .iter()
+        .find(|(_, interaction)| interaction.is_changed() && **interaction == Interaction::Pressed)
+    {
+        let color = active_road.component_at_index(list_item.index()).color;
+
+        on_picked.send(OnEyedropperColorPicked::new(color));
+    }
+}
+
 fn update_selected_road_component(
     mut on_selected: EventReader<OnRoadComponentSelected>,
-    mut road_component_item_query: Query<(&mut RoadComponentItem, &mut BackgroundColor, Entity)>,
+    mut road_component_item_query: Query<(
+        &mut RoadComponentItem,
+        &mut Themed,
+        &mut BackgroundColor,
+        Entity,
+    )>,
 ) {
     for event in on_selected.read() {
-        for (mut item, mut background_color, entity) in road_component_item_query.iter_mut() {
+        for (mut item, mut themed, mut background_color, entity) in
+            road_component_item_query.iter_mut()
+        {
             item.is_selected = event.component_item_entity == entity;
 
-            background_color.0 = if item.is_selected {
-                NEUTRAL_200.into()
-            } else {
-                Color::NONE
-            };
+            match item.is_selected {
+                true => *themed = themed.with_background_color(ThemeColor::Accent.into()),
+                false => deselect_road_component_item(&mut themed, &mut background_color),
+            }
         }
     }
 }
 
 fn deselect_all_road_components_on_event(
     mut on_deselected: EventReader<OnRoadComponentDeselected>,
-    mut road_component_item_query: Query<(&mut RoadComponentItem, &mut BackgroundColor)>,
+    mut road_component_item_query: Query<(
+        &mut RoadComponentItem,
+        &mut Themed,
+        &mut BackgroundColor,
+    )>,
 ) {
     for _ in on_deselected.read() {
-        for (mut item, mut background_color) in road_component_item_query.iter_mut() {
+        for (mut item, mut themed, mut background_color) in road_component_item_query.iter_mut() {
             item.is_selected = false;
 
-            background_color.0 = Color::NONE;
+            deselect_road_component_item(&mut themed, &mut background_color);
         }
     }
 }
+
+/// Removes the highlight, the background of an unselected item isn't part of the theme.
+fn deselect_road_component_item(themed: &mut Themed, background_color: &mut BackgroundColor) {
+    *themed = themed.with_background_color(Color::NONE.into());
+    background_color.0 = Color::NONE;
+}
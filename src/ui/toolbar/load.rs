@@ -3,7 +3,7 @@ use std::{
     io,
 };
 
-use bevy::{color::palettes::tailwind::*, prelude::*};
+use bevy::prelude::*;
 
 use crate::{
     game_modes::GameMode,
@@ -12,6 +12,7 @@ use crate::{
     ui::{
         components::buttons::{ButtonAction, OnButtonPressed},
         modal::{OnHideModalRequested, OnShowModalRequested},
+        theme::{ThemeColor, ThemeFontSize, Themed},
     },
     GameRunningSet,
 };
@@ -51,7 +52,7 @@ fn show_modal_on_load_button_pressed(
                 container
                     .spawn(build_road_item_node())
                     .with_children(|road_item| {
-                        road_item.spawn(build_road_item_text_node(&name, 24.0));
+                        road_item.spawn(build_road_item_text_node(&name, ThemeFontSize::Normal));
                     });
             }
         });
@@ -126,25 +127,26 @@ fn build_road_item_node() -> impl Bundle {
                 padding: UiRect::axes(Val::Px(4.0), Val::Px(2.0)),
                 ..default()
             },
-            background_color: EMERALD_300.into(),
             ..default()
         },
+        Themed::default().with_background_color(ThemeColor::Control.into()),
     )
 }
 
-fn build_road_item_text_node(text: &str, font_size: f32) -> impl Bundle {
-    TextBundle {
-        text: Text {
-            sections: vec![TextSection {
-                value: String::from(text),
-                style: TextStyle {
-                    color: NEUTRAL_900.into(),
-                    font_size,
+fn build_road_item_text_node(text: &str, font_size: ThemeFontSize) -> impl Bundle {
+    (
+        TextBundle {
+            text: Text {
+                sections: vec![TextSection {
+                    value: String::from(text),
                     ..default()
-                },
-            }],
+                }],
+                ..default()
+            },
             ..default()
         },
-        ..default()
-    }
+        Themed::default()
+            .with_text_color(ThemeColor::Text.into())
+            .with_font_size(font_size.into()),
+    )
 }
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::{
+        active_road::{
+            active_road_events::road_reference_line_change::OnRoadReferenceLineChangeRequested,
+            ActiveRoad,
+        },
+        road_data::RoadData,
+        road_reference_line::RoadReferenceLine,
+    },
+    ui::{
+        components::{
+            flexbox::{FlexboxBuilder, FlexboxConfig},
+            inputs::dropdown::{DropdownBuilder, DropdownConfig, OnDropdownValueChanged},
+            text::{TextBuilder, TextConfig},
+            tooltip::Tooltip,
+            UiComponentBuilder, UiComponentWithChildrenBuilder,
+        },
+        theme::ThemeFontSize,
+    },
+    GameRunningSet,
+};
+
+pub struct ReferenceLinePlugin;
+
+impl Plugin for ReferenceLinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                send_reference_line_change_requests.in_set(GameRunningSet::SendCommands),
+                regenerate_reference_line_dropdown.in_set(GameRunningSet::DespawnEntities),
+            )
+                .run_if(in_state(GameMode::RoadEditor)),
+        );
+    }
+}
+
+/// Holds the dropdown to pick the reference line, which is spawned again when its options change.
+#[derive(Component)]
+struct ReferenceLineConfig;
+
+/// The options of the reference line dropdown, in the order they're shown.
+#[derive(Component, PartialEq)]
+struct ReferenceLineDropdown {
+    options: Vec<(String, RoadReferenceLine)>,
+    selected_index: usize,
+}
+
+impl ReferenceLineDropdown {
+    fn from_road_data(road_data: &RoadData) -> Self {
+        let components = road_data.components();
+
+        let options: Vec<(String, RoadReferenceLine)> = [
+            (String::from("Center"), RoadReferenceLine::Center),
+            (String::from("Left edge"), RoadReferenceLine::LeftEdge),
+            (String::from("Right edge"), RoadReferenceLine::RightEdge),
+        ]
+        .into_iter()
+        .chain((1..components.len()).map(|component_index| {
+            (
+                format!(
+                    "{} | {}",
+                    components[component_index - 1].name,
+                    components[component_index].name
+                ),
+                RoadReferenceLine::ComponentBoundary(component_index),
+            )
+        }))
+        .collect();
+
+        let selected_index = options
+            .iter()
+            .position(|(_, reference_line)| *reference_line == road_data.reference_line())
+            .unwrap_or_default();
+
+        Self {
+            options,
+            selected_index,
+        }
+    }
+}
+
+/// Spawns the reference line config, its dropdown is added once there's an active road.
+pub fn spawn_reference_line_config(builder: &mut ChildBuilder) {
+    TextBuilder::new(TextConfig {
+        text: String::from("Reference line"),
+        font_size: ThemeFontSize::Small.into(),
+        ..default()
+    })
+    .spawn(builder, ());
+
+    FlexboxBuilder::new(FlexboxConfig::column()).spawn(builder, ReferenceLineConfig, |_| {});
+}
+
+fn spawn_reference_line_dropdown(builder: &mut ChildBuilder, road_data: &RoadData) {
+    let reference_line_dropdown = ReferenceLineDropdown::from_road_data(road_data);
+
+    DropdownBuilder::new(
+        DropdownConfig::new(
+            reference_line_dropdown
+                .options
+                .iter()
+                .map(|(label, _)| label.clone()),
+        )
+        .with_selected_index(reference_line_dropdown.selected_index),
+    )
+    .spawn(
+        builder,
+        (
+            reference_line_dropdown,
+            Tooltip::new(
+                "The line the road is drawn along, which positions on the road are measured from",
+            ),
+        ),
+    );
+}
+
+fn send_reference_line_change_requests(
+    mut on_dropdown_changed: EventReader<OnDropdownValueChanged>,
+    mut on_change_request: EventWriter<OnRoadReferenceLineChangeRequested>,
+    reference_line_dropdown_query: Query<&ReferenceLineDropdown>,
+) {
+    for event in on_dropdown_changed.read() {
+        let Some((_, reference_line)) = reference_line_dropdown_query
+            .get(event.dropdown_entity())
+            .ok()
+            .and_then(|dropdown| dropdown.options.get(event.selected_index()))
+        else {
+            continue;
+        };
+
+        on_change_request.send(OnRoadReferenceLineChangeRequested::new(*reference_line));
+    }
+}
+
+/// Spawns the dropdown when there is none yet, or again when the components or the reference line of the road
+/// changed. Only then, so an open dropdown doesn't close on unrelated changes.
+fn regenerate_reference_line_dropdown(
+    mut commands: Commands,
+    reference_line_config_query: Query<Entity, With<ReferenceLineConfig>>,
+    reference_line_dropdown_query: Query<&ReferenceLineDropdown>,
+    active_road: Res<ActiveRoad>,
+) {
+    let Ok(reference_line_config_entity) = reference_line_config_query.get_single() else {
+        return;
+    };
+
+    let existing_dropdown = reference_line_dropdown_query.get_single();
+
+    if existing_dropdown.is_ok() && !active_road.is_changed() {
+        return;
+    }
+
+    let road_data = active_road.road_data();
+
+    if existing_dropdown
+        .is_ok_and(|dropdown| *dropdown == ReferenceLineDropdown::from_road_data(road_data))
+    {
+        return;
+    }
+
+    commands
+        .entity(reference_line_config_entity)
+        .despawn_descendants()
+        .with_children(|reference_line_config| {
+            spawn_reference_line_dropdown(reference_line_config, road_data);
+        });
+}
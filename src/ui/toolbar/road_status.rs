@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::active_road::ActiveRoad,
+    ui::{
+        components::{
+            text::{TextBuilder, TextConfig},
+            UiComponentBuilder,
+        },
+        theme::ThemeFontSize,
+    },
+    GameRunningSet,
+};
+
+pub struct RoadStatusPlugin;
+
+impl Plugin for RoadStatusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            update_road_status_text
+                .in_set(GameRunningSet::UpdateEntities)
+                .run_if(in_state(GameMode::RoadEditor))
+                .run_if(resource_exists_and_changed::<ActiveRoad>),
+        );
+    }
+}
+
+/// Marker component for the text that shows the name of the active road and whether it has unsaved changes.
+#[derive(Component)]
+struct RoadStatusText;
+
+pub fn spawn_road_status_text(builder: &mut ChildBuilder) {
+    TextBuilder::new(TextConfig {
+        font_size: ThemeFontSize::Small.into(),
+        ..default()
+    })
+    .spawn(builder, RoadStatusText);
+}
+
+fn update_road_status_text(
+    mut text_query: Query<&mut Text, With<RoadStatusText>>,
+    active_road: Res<ActiveRoad>,
+) {
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+
+    let road_name = active_road.road_data().name();
+    let status = match active_road.has_unsaved_changes() {
+        true => format!("{road_name} *"),
+        false => road_name.to_string(),
+    };
+
+    if text.sections[0].value != status {
+        text.sections[0].value = status;
+    }
+}
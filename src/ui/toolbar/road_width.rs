@@ -0,0 +1,151 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_modes::GameMode,
+    road::active_road::{
+        active_road_events::{
+            road_target_width_change::OnRoadTargetWidthChangeRequested, OnActiveRoadSet,
+        },
+        ActiveRoad,
+    },
+    settings::Settings,
+    ui::{
+        components::{
+            inputs::number_input::{NumberInput, NumberInputBuilder, OnNumberInputValueChanged},
+            text::{TextBuilder, TextConfig},
+            tooltip::Tooltip,
+            UiComponentBuilder,
+        },
+        theme::ThemeFontSize,
+    },
+    GameRunningSet,
+};
+
+const MAX_TARGET_WIDTH: f32 = 200.0;
+
+pub struct RoadWidthPlugin;
+
+impl Plugin for RoadWidthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (
+                send_target_width_change_requests.in_set(GameRunningSet::SendCommands),
+                (
+                    update_target_width_input_on_road_set,
+                    update_road_width_status_text.run_if(
+                        resource_exists_and_changed::<ActiveRoad>
+                            .or_else(resource_changed::<Settings>),
+                    ),
+                )
+                    .in_set(GameRunningSet::UpdateEntities),
+            )
+                .run_if(in_state(GameMode::RoadEditor)),
+        );
+    }
+}
+
+#[derive(Component)]
+struct RoadTargetWidthInput;
+
+/// Marker component for the text that shows the width of the active road and how much of its target width is left.
+///
+/// Rail lanes are counted separately, since they aren't part of the road's regular lanes.
+#[derive(Component)]
+struct RoadWidthStatusText;
+
+pub fn spawn_road_width_config(builder: &mut ChildBuilder) {
+    TextBuilder::new(TextConfig {
+        text: String::from("Target width"),
+        font_size: ThemeFontSize::Small.into(),
+        ..default()
+    })
+    .spawn(builder, ());
+
+    NumberInputBuilder::default()
+        .with_values(0.0, 0.0..MAX_TARGET_WIDTH)
+        .with_step(0.5)
+        .as_length()
+        .spawn(
+            builder,
+            (
+                RoadTargetWidthInput,
+                Tooltip::new(
+                    "The total width of the road, flexible components fill what's left. 0 for none",
+                ),
+            ),
+        );
+
+    TextBuilder::new(TextConfig {
+        font_size: ThemeFontSize::Small.into(),
+        ..default()
+    })
+    .spawn(builder, RoadWidthStatusText);
+}
+
+fn send_target_width_change_requests(
+    mut on_input_changed: EventReader<OnNumberInputValueChanged>,
+    mut on_change_request: EventWriter<OnRoadTargetWidthChangeRequested>,
+    target_width_input_query: Query<(), With<RoadTargetWidthInput>>,
+) {
+    for event in on_input_changed
+        .read()
+        .filter(|event| target_width_input_query.contains(event.number_input_entity()))
+    {
+        let new_value = event.new_value();
+
+        on_change_request.send(OnRoadTargetWidthChangeRequested::new(
+            (new_value > 0.0).then_some(new_value),
+        ));
+    }
+}
+
+/// A loaded road brings its own target width, so the input is set to match it.
+fn update_target_width_input_on_road_set(
+    mut on_road_set: EventReader<OnActiveRoadSet>,
+    mut target_width_input_query: Query<&mut NumberInput, With<RoadTargetWidthInput>>,
+) {
+    for event in on_road_set.read() {
+        let Ok(mut number_input) = target_width_input_query.get_single_mut() else {
+            continue;
+        };
+
+        number_input.set_value(event.road_data().target_width().unwrap_or(0.0));
+    }
+}
+
+fn update_road_width_status_text(
+    mut text_query: Query<&mut Text, With<RoadWidthStatusText>>,
+    active_road: Res<ActiveRoad>,
+    settings: Res<Settings>,
+) {
+    let Ok(mut text) = text_query.get_single_mut() else {
+        return;
+    };
+
+    let road_data = active_road.road_data();
+    let length_unit = settings.length_unit;
+    let total_width = length_unit.format_meters(road_data.total_width());
+
+    let mut status = match road_data.remaining_width() {
+        Some(remaining_width) if remaining_width < 0.0 => format!(
+            "Width {total_width}, {} too wide",
+            length_unit.format_meters(-remaining_width)
+        ),
+        Some(remaining_width) => format!(
+            "Width {total_width}, {} left",
+            length_unit.format_meters(remaining_width)
+        ),
+        None => format!("Width {total_width}"),
+    };
+
+    match road_data.rail_track_count() {
+        0 => {}
+        1 => status.push_str(", 1 rail track"),
+        rail_track_count => status.push_str(&format!(", {rail_track_count} rail tracks")),
+    }
+
+    if text.sections[0].value != status {
+        text.sections[0].value = status;
+    }
+}
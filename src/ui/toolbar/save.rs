@@ -2,15 +2,30 @@ use bevy::prelude::*;
 
 use crate::{
     game_modes::GameMode,
-    road::{active_road::ActiveRoad, save::OnSaveRoadRequested},
+    road::{
+        active_road::ActiveRoad, persistance::road_data_exists, road_validation::RoadDiagnostics,
+        save::OnSaveRoadRequested,
+    },
     ui::{
         components::{
             buttons::{ButtonAction, OnButtonPressed, TextButtonBuilder},
             flexbox::{FlexboxBuilder, FlexboxConfig},
+            text::{TextBuilder, TextConfig},
             UiComponentBuilder, UiComponentWithChildrenBuilder,
         },
-        inputs::text_input::{spawn_text_input_node, TextInput},
-        modal::{OnHideModalRequested, OnShowModalRequested},
+        diagnostics_panel::severity_color,
+        inputs::text_input::{
+            default_text_config, spawn_configured_text_input_node, text_editor::TextConstraints,
+            TextInput, TextInputConfig,
+        },
+        modal::{
+            confirm_dialog::{
+                ConfirmDialogConfig, ConfirmDialogPurpose, OnConfirmDialogClosed,
+                OnConfirmDialogRequested,
+            },
+            OnHideModalRequested, OnShowModalRequested,
+        },
+        theme::ThemeFontSize,
     },
     GameRunningSet,
 };
@@ -19,15 +34,30 @@ pub struct SavePlugin;
 
 impl Plugin for SavePlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.add_event::<OnShowSaveModalRequested>().add_systems(
             Update,
-            (send_save_requests, show_modal_on_save_button_pressed)
+            (
+                send_save_requests,
+                save_road_when_overwrite_is_confirmed,
+                show_modal_on_save_button_pressed,
+            )
                 .in_set(GameRunningSet::SendCommands)
                 .run_if(in_state(GameMode::RoadEditor)),
         );
     }
 }
 
+/// The road name is used as file name, so it's kept short enough for all file systems.
+const MAX_ROAD_NAME_LENGTH: usize = 64;
+
+/// Opens the save modal, just like pressing the save button.
+#[derive(Event)]
+pub struct OnShowSaveModalRequested;
+
+/// Marker component for the content of the save modal, it exists while the save modal is open.
+#[derive(Component)]
+pub struct SaveModal;
+
 #[derive(Component)]
 struct SaveConfirmButton;
 
@@ -36,24 +66,63 @@ struct RoadNameInput;
 
 fn show_modal_on_save_button_pressed(
     mut on_pressed: EventReader<OnButtonPressed>,
+    mut on_show_save_modal: EventReader<OnShowSaveModalRequested>,
     mut on_request: EventWriter<OnShowModalRequested>,
     mut commands: Commands,
+    road_diagnostics: Res<RoadDiagnostics>,
+    active_road: Res<ActiveRoad>,
 ) {
-    for _ in on_pressed
+    let save_button_presses = on_pressed
         .read()
         .filter(|event| event.is_action(ButtonAction::SaveRoad))
-    {
-        let mut modal_content_container = commands.spawn(
+        .count();
+
+    for _ in 0..save_button_presses + on_show_save_modal.read().count() {
+        let has_errors = road_diagnostics.has_errors();
+
+        let mut modal_content_container = commands.spawn((
+            SaveModal,
             FlexboxBuilder::new(FlexboxConfig::horizontally_centered_column().with_px_gap(8.0))
                 .build(),
-        );
+        ));
         let modal_content_entity = modal_content_container.id();
 
         modal_content_container.with_children(|container| {
             // TODO: replace with UiComponent
-            spawn_text_input_node(container, RoadNameInput, "New road");
+            spawn_configured_text_input_node(
+                container,
+                RoadNameInput,
+                TextInputConfig {
+                    text: default_text_config(active_road.road_data().name()),
+                    constraints: TextConstraints::default()
+                        .with_max_length(MAX_ROAD_NAME_LENGTH)
+                        .with_character_filter(is_allowed_in_file_name),
+                },
+            );
+
+            // Designs with errors can still be saved, but the user has to confirm it
+            if has_errors {
+                TextBuilder::default()
+                    .with_text("This road design has errors:")
+                    .spawn(container, ());
 
-            TextButtonBuilder::default_with_text("Confirm").spawn(container, SaveConfirmButton);
+                for error in road_diagnostics.errors() {
+                    TextBuilder::new(TextConfig {
+                        text: error.message.clone(),
+                        color: severity_color(error.severity).into(),
+                        font_size: ThemeFontSize::Small.into(),
+                        ..default()
+                    })
+                    .spawn(container, ());
+                }
+            }
+
+            let confirm_text = match has_errors {
+                true => "Save anyway",
+                false => "Confirm",
+            };
+
+            TextButtonBuilder::default_with_text(confirm_text).spawn(container, SaveConfirmButton);
         });
 
         on_request.send(OnShowModalRequested::new(modal_content_entity));
@@ -63,20 +132,76 @@ fn show_modal_on_save_button_pressed(
 fn send_save_requests(
     mut on_save_request: EventWriter<OnSaveRoadRequested>,
     mut on_hide_request: EventWriter<OnHideModalRequested>,
+    mut on_confirm_request: EventWriter<OnConfirmDialogRequested>,
     active_road: Res<ActiveRoad>,
     road_name_input_query: Query<&TextInput, With<RoadNameInput>>,
     button_query: Query<&Interaction, (With<SaveConfirmButton>, Changed<Interaction>)>,
 ) {
     for interaction in button_query.iter() {
         if *interaction == Interaction::Pressed {
-            let road_name_input = road_name_input_query.single();
+            let road_name = road_name_input_query.single().current_text();
 
-            let mut road_data = active_road.road_data().clone();
-            road_data.set_name(road_name_input.current_text().into());
+            // Saving the loaded road again is expected, saving over another road is asked first
+            if road_name != active_road.road_data().name() && road_data_exists(road_name) {
+                on_confirm_request.send(OnConfirmDialogRequested::new(
+                    ConfirmDialogConfig::new(
+                        ConfirmDialogPurpose::OverwriteRoad,
+                        "Overwrite road",
+                        format!("A road named \"{road_name}\" already exists, replace it?"),
+                    )
+                    .with_confirm_label("Overwrite"),
+                ));
+                continue;
+            }
 
-            on_save_request.send(OnSaveRoadRequested::new(road_data));
+            save_road_with_name(&mut on_save_request, &active_road, road_name);
 
             on_hide_request.send(OnHideModalRequested);
         }
     }
 }
+
+/// The save modal stays open below the overwrite dialog, so the name can be changed when cancelled.
+fn save_road_when_overwrite_is_confirmed(
+    mut on_closed: EventReader<OnConfirmDialogClosed>,
+    mut on_save_request: EventWriter<OnSaveRoadRequested>,
+    mut on_hide_request: EventWriter<OnHideModalRequested>,
+    active_road: Res<ActiveRoad>,
+    road_name_input_query: Query<&TextInput, With<RoadNameInput>>,
+) {
+    for _ in on_closed
+        .read()
+        .filter(|event| event.is_confirmed(ConfirmDialogPurpose::OverwriteRoad))
+    {
+        let Ok(road_name_input) = road_name_input_query.get_single() else {
+            continue;
+        };
+
+        save_road_with_name(
+            &mut on_save_request,
+            &active_road,
+            road_name_input.current_text(),
+        );
+
+        on_hide_request.send(OnHideModalRequested);
+    }
+}
+
+fn save_road_with_name(
+    on_save_request: &mut EventWriter<OnSaveRoadRequested>,
+    active_road: &ActiveRoad,
+    road_name: &str,
+) {
+    let mut road_data = active_road.road_data().clone();
+    road_data.set_name(road_name.into());
+
+    on_save_request.send(OnSaveRoadRequested::new(road_data));
+}
+
+/// Returns false for characters that aren't allowed in file names on common file systems.
+fn is_allowed_in_file_name(character: char) -> bool {
+    !matches!(
+        character,
+        '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|'
+    )
+}
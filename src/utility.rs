@@ -14,32 +14,52 @@ use bevy::{
 use mesh_builder::MeshBuilderPlugin;
 use mouse_on_ui::MouseOnUiPlugin;
 
+use crate::{
+    game_modes::GameMode,
+    keybindings::{ActionInput, InputAction},
+    ui::keybindings_menu::binding_capture_is_active,
+};
+
 pub struct UtilityPlugin;
 
 impl Plugin for UtilityPlugin {
     fn build(&self, app: &mut App) {
         app.add_plugins((MouseOnUiPlugin, MeshBuilderPlugin))
-            .add_systems(Update, shutdown_on_control_q);
+            .add_systems(
+                Update,
+                // The road editor asks to save unsaved changes before shutting down
+                shutdown_on_quit_input.run_if(
+                    not(in_state(GameMode::RoadEditor)).and_then(not(binding_capture_is_active)),
+                ),
+            );
     }
 }
 
 // Utility systems
 
-fn shutdown_on_control_q(
-    input: Res<ButtonInput<KeyCode>>,
+fn shutdown_on_quit_input(
+    action_input: ActionInput,
     mut window_query: Query<&mut Window, With<PrimaryWindow>>,
     mut on_exit: EventWriter<AppExit>,
 ) {
-    if input.pressed(KeyCode::ControlLeft) && input.just_pressed(KeyCode::KeyQ) {
-        let Ok(mut window) = window_query.get_single_mut() else {
-            return;
-        };
+    if action_input.just_pressed(InputAction::Quit) {
+        shutdown(&mut window_query, &mut on_exit);
+    }
+}
+
+/// Releases the cursor and sends an [`AppExit`] event.
+pub fn shutdown(
+    window_query: &mut Query<&mut Window, With<PrimaryWindow>>,
+    on_exit: &mut EventWriter<AppExit>,
+) {
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
 
-        window.cursor.visible = true;
-        window.cursor.grab_mode = CursorGrabMode::None;
+    window.cursor.visible = true;
+    window.cursor.grab_mode = CursorGrabMode::None;
 
-        on_exit.send(AppExit::Success);
-    }
+    on_exit.send(AppExit::Success);
 }
 
 pub fn despawn_component_recursive<T: Component>(
@@ -179,6 +199,7 @@ where
 /// Returns all entities in `entities_to_search` that are descendants of `start_entity`.
 ///
 /// - In this case we have the parent entity, and are searching for all matching descendants in the given search query.
+#[expect(unused)]
 pub fn filter_descendants_of_entity<'a, Data, Filter, Closure: Fn(&Data::Item<'a>) -> Entity>(
     start_entity: Entity,
     entities_to_search: &'a Query<Data, Filter>,
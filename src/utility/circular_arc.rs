@@ -1,6 +1,7 @@
 use std::f32::consts::TAU;
 
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use super::{delta_rotation, line_intersection::calculate_line_line_intersection_3d};
 
@@ -8,7 +9,7 @@ use super::{delta_rotation, line_intersection::calculate_line_line_intersection_
 ///
 /// - Can go either clockwise or counter-clockwise
 /// - The delta angle will be positive for clockwise (looking along the Y axis), which is CurveDirection::Left (looking from above).
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub struct CircularArc {
     /// The position of the center of the circle.
     pub position: Vec3,
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Represents a distance in meters.
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Default)]
 pub struct Distance(pub f32);
@@ -7,3 +9,92 @@ impl Distance {
         self.0
     }
 }
+
+/// The unit that lengths are shown in, lengths are always stored in meters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LengthUnit {
+    #[default]
+    Meters,
+    Centimeters,
+    Feet,
+    Inches,
+}
+
+impl LengthUnit {
+    pub const ALL: [LengthUnit; 4] = [
+        LengthUnit::Meters,
+        LengthUnit::Centimeters,
+        LengthUnit::Feet,
+        LengthUnit::Inches,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LengthUnit::Meters => "Meters",
+            LengthUnit::Centimeters => "Centimeters",
+            LengthUnit::Feet => "Feet",
+            LengthUnit::Inches => "Inches",
+        }
+    }
+
+    /// The abbreviation shown next to lengths in this unit.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            LengthUnit::Meters => "m",
+            LengthUnit::Centimeters => "cm",
+            LengthUnit::Feet => "ft",
+            LengthUnit::Inches => "in",
+        }
+    }
+
+    fn meters_per_unit(&self) -> f32 {
+        match self {
+            LengthUnit::Meters => 1.0,
+            LengthUnit::Centimeters => 0.01,
+            LengthUnit::Feet => 0.3048,
+            LengthUnit::Inches => 0.0254,
+        }
+    }
+
+    /// Converts a length in meters to this unit.
+    pub fn convert_from_meters(&self, meters: f32) -> f32 {
+        meters / self.meters_per_unit()
+    }
+
+    /// Converts a length in this unit to meters.
+    pub fn convert_to_meters(&self, length: f32) -> f32 {
+        length * self.meters_per_unit()
+    }
+
+    /// Formats a length in meters as a label in this unit, like `3.50 m`.
+    pub fn format_meters(&self, meters: f32) -> String {
+        format!("{:.2} {}", self.convert_from_meters(meters), self.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_between_units_and_meters() {
+        assert_eq!(LengthUnit::Centimeters.convert_from_meters(3.5), 350.0);
+        assert!((LengthUnit::Feet.convert_from_meters(3.6576) - 12.0).abs() < 1e-4);
+        assert!((LengthUnit::Inches.convert_to_meters(12.0) - 0.3048).abs() < 1e-6);
+    }
+
+    #[test]
+    fn formats_lengths_with_the_unit_symbol() {
+        assert_eq!(LengthUnit::Meters.format_meters(3.5), "3.50 m");
+        assert_eq!(LengthUnit::Centimeters.format_meters(0.125), "12.50 cm");
+    }
+
+    #[test]
+    fn converting_back_and_forth_keeps_the_length() {
+        for length_unit in LengthUnit::ALL {
+            let meters = length_unit.convert_to_meters(length_unit.convert_from_meters(2.75));
+
+            assert!((meters - 2.75).abs() < 1e-5, "{}", length_unit.name());
+        }
+    }
+}
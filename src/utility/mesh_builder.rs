@@ -91,6 +91,13 @@ impl MeshBuilder {
         self.vertex_count - 1
     }
 
+    /// Returns the number of vertices in the mesh
+    ///
+    /// Used to offset the triangle indices of a shape that's added after other shapes
+    pub fn vertex_count(&self) -> u32 {
+        self.vertex_count
+    }
+
     /// Returns if this MeshBuilder contains any vertices or triangles
     pub fn empty(&self) -> bool {
         self.vertex_count == 0 && self.triangle_count == 0
@@ -4,12 +4,19 @@ mod rotation;
 mod zoom;
 
 use bevy::prelude::*;
-use config::TopDownCameraConfig;
+use config::{
+    TopDownCameraConfig, TopDownCameraMovementConfig, TopDownCameraRotationConfig,
+    TopDownCameraZoomConfig,
+};
 use movement::CameraMovementPlugin;
 use rotation::CameraRotationPlugin;
 use zoom::CameraZoomPlugin;
 
-use crate::game_modes::GameMode;
+use crate::{
+    game_modes::GameMode,
+    settings::{OnSettingChanged, SettingChange},
+    GameRunningSet,
+};
 
 pub struct EditorCameraPlugin;
 
@@ -24,7 +31,11 @@ impl Plugin for EditorCameraPlugin {
                 OnEnter(GameMode::RoadDrawer),
                 reset_camera_transforms_to_drawer_defaults,
             )
-            .add_systems(Startup, spawn_top_down_camera);
+            .add_systems(Startup, spawn_top_down_camera)
+            .add_systems(
+                Update,
+                apply_camera_speed_settings.in_set(GameRunningSet::UpdateEntities),
+            );
     }
 }
 
@@ -51,6 +62,34 @@ fn spawn_top_down_camera(mut commands: Commands) {
         });
 }
 
+/// The speed settings multiply the default speeds of the camera.
+fn apply_camera_speed_settings(
+    mut on_changed: EventReader<OnSettingChanged>,
+    mut camera_query: Query<&mut TopDownCamera>,
+) {
+    let Ok(mut camera) = camera_query.get_single_mut() else {
+        return;
+    };
+
+    for event in on_changed.read() {
+        match event.change() {
+            SettingChange::CameraMovementSpeed(multiplier) => {
+                camera.config.movement.movement_speed =
+                    TopDownCameraMovementConfig::default().movement_speed * multiplier;
+            }
+            SettingChange::CameraRotationSpeed(multiplier) => {
+                camera.config.rotation.rotation_speed =
+                    TopDownCameraRotationConfig::default().rotation_speed * multiplier;
+            }
+            SettingChange::CameraZoomSpeed(multiplier) => {
+                camera.config.zoom.zoom_speed =
+                    TopDownCameraZoomConfig::default().zoom_speed * multiplier;
+            }
+            _ => (),
+        }
+    }
+}
+
 fn reset_camera_transforms_to_editor_defaults(
     mut camera_query: Query<&mut Transform, (With<TopDownCamera>, Without<TopDownCameraAnchor>)>,
     mut anchor_query: Query<&mut Transform, With<TopDownCameraAnchor>>,
@@ -1,25 +1,11 @@
 use std::ops::Range;
 
-use bevy::prelude::{KeyCode, MouseButton};
-
-#[derive(Clone, Debug)]
+/// The buttons that move and rotate the camera are configured in [crate::keybindings::Keybindings].
+#[derive(Clone, Debug, Default)]
 pub struct TopDownCameraConfig {
     pub movement: TopDownCameraMovementConfig,
     pub rotation: TopDownCameraRotationConfig,
     pub zoom: TopDownCameraZoomConfig,
-    /// The MouseButton the user needs to hold to activate camera movement and rotation.
-    pub activate_button: MouseButton,
-}
-
-impl Default for TopDownCameraConfig {
-    fn default() -> Self {
-        Self {
-            movement: Default::default(),
-            rotation: Default::default(),
-            zoom: Default::default(),
-            activate_button: MouseButton::Right,
-        }
-    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -29,8 +15,6 @@ pub struct TopDownCameraMovementConfig {
 
     /// A modifier for how fast the camera anchor moves.
     pub movement_speed: f32,
-
-    pub keybinds: MovementKeybinds,
 }
 
 impl Default for TopDownCameraMovementConfig {
@@ -38,7 +22,6 @@ impl Default for TopDownCameraMovementConfig {
         Self {
             enable_input: true,
             movement_speed: 1.0,
-            keybinds: MovementKeybinds::default(),
         }
     }
 }
@@ -82,22 +65,3 @@ impl Default for TopDownCameraZoomConfig {
         }
     }
 }
-
-#[derive(Clone, Copy, Debug)]
-pub struct MovementKeybinds {
-    pub forward: KeyCode,
-    pub back: KeyCode,
-    pub left: KeyCode,
-    pub right: KeyCode,
-}
-
-impl Default for MovementKeybinds {
-    fn default() -> Self {
-        Self {
-            forward: KeyCode::KeyW,
-            back: KeyCode::KeyS,
-            left: KeyCode::KeyA,
-            right: KeyCode::KeyD,
-        }
-    }
-}
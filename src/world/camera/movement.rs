@@ -1,12 +1,13 @@
 use bevy::prelude::*;
 
-use crate::{game_modes::GameMode, GameRunningSet};
-
-use super::{
-    config::{MovementKeybinds, TopDownCameraConfig},
-    TopDownCamera, TopDownCameraAnchor,
+use crate::{
+    game_modes::GameMode,
+    keybindings::{ActionInput, InputAction},
+    GameRunningSet,
 };
 
+use super::{config::TopDownCameraConfig, TopDownCamera, TopDownCameraAnchor};
+
 pub struct CameraMovementPlugin;
 
 impl Plugin for CameraMovementPlugin {
@@ -36,16 +37,13 @@ impl OnCameraMovementRequested {
 fn listen_to_movement_input(
     mut on_movement_request: EventWriter<OnCameraMovementRequested>,
     camera_query: Query<&TopDownCamera>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mouse_input: Res<ButtonInput<MouseButton>>,
+    action_input: ActionInput,
 ) {
     let Ok(camera) = camera_query.get_single() else {
         return;
     };
 
-    let Some(movement_input) =
-        calculate_movement_input(&camera.config, &keyboard_input, &mouse_input)
-    else {
+    let Some(movement_input) = calculate_movement_input(&camera.config, &action_input) else {
         return;
     };
 
@@ -70,17 +68,16 @@ fn handle_movement_requests(
 
 fn calculate_movement_input(
     config: &TopDownCameraConfig,
-    keyboard_input: &ButtonInput<KeyCode>,
-    mouse_input: &ButtonInput<MouseButton>,
+    action_input: &ActionInput,
 ) -> Option<Vec3> {
     if !config.movement.enable_input
-        || !mouse_input.pressed(config.activate_button)
+        || !action_input.pressed(InputAction::ActivateCamera)
         || config.movement.movement_speed <= 0.0
     {
         return None;
     }
 
-    let movement_input = movement_input_as_vector(config.movement.keybinds, keyboard_input);
+    let movement_input = movement_input_as_vector(action_input);
     if movement_input == Vec3::ZERO {
         return None;
     }
@@ -95,25 +92,22 @@ fn calculate_delta_movement(movement_input: Vec3, multiplier: f32) -> Vec3 {
     movement_input.normalize_or_zero() * multiplier
 }
 
-fn movement_input_as_vector(
-    keybinds: MovementKeybinds,
-    keyboard_input: &ButtonInput<KeyCode>,
-) -> Vec3 {
+fn movement_input_as_vector(action_input: &ActionInput) -> Vec3 {
     let mut direction = Vec3::ZERO;
 
-    if keyboard_input.pressed(keybinds.forward) {
+    if action_input.pressed(InputAction::MoveCameraForward) {
         direction.z -= 1.0;
     }
 
-    if keyboard_input.pressed(keybinds.back) {
+    if action_input.pressed(InputAction::MoveCameraBack) {
         direction.z += 1.0;
     }
 
-    if keyboard_input.pressed(keybinds.left) {
+    if action_input.pressed(InputAction::MoveCameraLeft) {
         direction.x -= 1.0;
     }
 
-    if keyboard_input.pressed(keybinds.right) {
+    if action_input.pressed(InputAction::MoveCameraRight) {
         direction.x += 1.0;
     }
 
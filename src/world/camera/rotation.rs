@@ -6,7 +6,12 @@ use bevy::{
     window::{CursorGrabMode, PrimaryWindow},
 };
 
-use crate::{game_modes::GameMode, utility::add_rotations_as_eulers, GameRunningSet};
+use crate::{
+    game_modes::GameMode,
+    keybindings::{ActionInput, InputAction},
+    utility::add_rotations_as_eulers,
+    GameRunningSet,
+};
 
 use super::{config::TopDownCameraConfig, TopDownCamera, TopDownCameraAnchor};
 
@@ -32,23 +37,17 @@ struct OnCameraRotationRequested {
 }
 
 fn grab_cursor_on_rotation_input(
-    mouse_input: Res<ButtonInput<MouseButton>>,
+    action_input: ActionInput,
     mut window_query: Query<&mut Window, With<PrimaryWindow>>,
-    camera_query: Query<&TopDownCamera>,
 ) {
-    let Ok(camera) = camera_query.get_single() else {
-        return;
-    };
-    let activate_button = camera.config.activate_button;
-
-    if mouse_input.just_pressed(activate_button) {
+    if action_input.just_pressed(InputAction::ActivateCamera) {
         let mut window = window_query.single_mut();
 
         window.cursor.visible = false;
         window.cursor.grab_mode = CursorGrabMode::Locked;
     }
 
-    if mouse_input.just_released(activate_button) {
+    if action_input.just_released(InputAction::ActivateCamera) {
         let mut window = window_query.single_mut();
 
         window.cursor.visible = true;
@@ -57,7 +56,7 @@ fn grab_cursor_on_rotation_input(
 }
 
 fn listen_to_rotation_input(
-    mouse_input: Res<ButtonInput<MouseButton>>,
+    action_input: ActionInput,
     mut mouse_motion: EventReader<MouseMotion>,
     mut rotation_request: EventWriter<OnCameraRotationRequested>,
     camera_query: Query<&TopDownCamera>,
@@ -67,7 +66,7 @@ fn listen_to_rotation_input(
     };
 
     let Some(rotation_input) =
-        calculate_rotation_input(&camera.config, &mut mouse_motion, &mouse_input)
+        calculate_rotation_input(&camera.config, &mut mouse_motion, &action_input)
     else {
         return;
     };
@@ -111,11 +110,11 @@ fn handle_rotation_requests(
 fn calculate_rotation_input(
     config: &TopDownCameraConfig,
     mouse_motion: &mut EventReader<MouseMotion>,
-    mouse_input: &ButtonInput<MouseButton>,
+    action_input: &ActionInput,
 ) -> Option<Quat> {
     if mouse_motion.is_empty()
         || !config.rotation.enable_input
-        || !mouse_input.pressed(config.activate_button)
+        || !action_input.pressed(InputAction::ActivateCamera)
         || config.rotation.rotation_speed <= 0.0
     {
         return None;
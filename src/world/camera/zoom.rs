@@ -1,6 +1,6 @@
 use bevy::{input::mouse::MouseWheel, prelude::*};
 
-use crate::{game_modes::GameMode, GameRunningSet};
+use crate::{game_modes::GameMode, utility::mouse_on_ui::MouseOnUi, GameRunningSet};
 
 use super::TopDownCamera;
 
@@ -28,11 +28,18 @@ fn listen_to_zoom_input(
     mut mouse_wheel: EventReader<MouseWheel>,
     mut zoom_request: EventWriter<OnCameraZoomRequested>,
     camera_query: Query<&TopDownCamera>,
+    mouse_on_ui: Res<MouseOnUi>,
 ) {
     let Ok(camera) = camera_query.get_single() else {
         return;
     };
 
+    // Scrolling over UI is used by UI elements like number inputs
+    if mouse_on_ui.is_on_ui() {
+        mouse_wheel.clear();
+        return;
+    }
+
     if !camera.config.zoom.enable_input || camera.config.zoom.zoom_speed <= 0.0 {
         return;
     }
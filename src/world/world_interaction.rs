@@ -7,7 +7,11 @@ use gizmos::WorldInteractionGizmosPlugin;
 use interaction_target::{InteractionTarget, InteractionTargetPlugin};
 use mouse_interaction_events::MouseInteractionEventsPlugin;
 
-use crate::{utility::distance::Distance, GameRunningSet};
+use crate::{
+    settings::{OnSettingChanged, SettingChange},
+    utility::distance::Distance,
+    GameRunningSet,
+};
 
 pub struct WorldInteractionPlugin;
 
@@ -22,7 +26,10 @@ impl Plugin for WorldInteractionPlugin {
         .insert_resource(WorldInteraction::default())
         .add_systems(
             Update,
-            update_interaction_ray.in_set(GameRunningSet::FetchData),
+            (
+                update_interaction_ray.in_set(GameRunningSet::FetchData),
+                apply_interaction_distance_setting.in_set(GameRunningSet::UpdateEntities),
+            ),
         );
     }
 }
@@ -35,6 +42,10 @@ pub struct WorldInteraction {
 }
 
 impl WorldInteraction {
+    pub fn interaction_ray(&self) -> Option<Ray3d> {
+        self.interaction_ray
+    }
+
     pub fn interaction_target(&self) -> Option<InteractionTarget> {
         self.interaction_target
     }
@@ -74,6 +85,17 @@ impl OnWorldInteractionRayUpdated {
     }
 }
 
+fn apply_interaction_distance_setting(
+    mut on_changed: EventReader<OnSettingChanged>,
+    mut world_interaction: ResMut<WorldInteraction>,
+) {
+    for event in on_changed.read() {
+        if let SettingChange::MaxInteractionDistance(distance) = event.change() {
+            world_interaction.config.max_interaction_distance = Distance(distance);
+        }
+    }
+}
+
 fn update_interaction_ray(
     mut on_updated: EventWriter<OnWorldInteractionRayUpdated>,
     mut world_interaction: ResMut<WorldInteraction>,